@@ -8,6 +8,7 @@ use xrpl_wasm_stdlib::core::current_tx::traits::TransactionCommonFields;
 use xrpl_wasm_stdlib::core::ledger_objects::current_escrow::{CurrentEscrow, get_current_escrow};
 use xrpl_wasm_stdlib::core::ledger_objects::traits::CurrentEscrowFields;
 use xrpl_wasm_stdlib::core::locator::Locator;
+use xrpl_wasm_stdlib::core::types::amount::Amount;
 use xrpl_wasm_stdlib::host::Result;
 use xrpl_wasm_stdlib::host::error_codes::{
     match_result_code, match_result_code_optional, match_result_code_with_expected_bytes,
@@ -28,7 +29,7 @@ const ITERATIONS: usize = 100;
 /// - Locator operations (pack single, pack nested, repack_last)
 /// - Transaction field access (get_account, get_fee)
 /// - Blob operations (creation and access)
-/// - Result type operations (is_ok, is_err, ok, err)
+/// - Result type operations (is_ok, is_err, ok, err, ?)
 /// - Error code matching (match_result_code, match_result_code_optional, etc.)
 /// - Hex decoding (decode_hex_32, decode_hex_20)
 #[unsafe(no_mangle)]
@@ -118,6 +119,9 @@ pub extern "C" fn finish() -> i32 {
     let _ = trace("BENCHMARK: result_err");
     accumulator = accumulator.wrapping_add(benchmark_result_err(&escrow_finish));
 
+    let _ = trace("BENCHMARK: question_mark");
+    accumulator = accumulator.wrapping_add(benchmark_question_mark(&escrow_finish));
+
     // Hex decoding benchmarks
     let _ = trace("BENCHMARK_SECTION: HEX_DECODING");
     let _ = trace("BENCHMARK: decode_hex_32");
@@ -215,6 +219,7 @@ fn benchmark_is_ok(escrow_finish: &EscrowFinish) -> u64 {
 }
 
 /// Benchmark is_err() checks
+#[allow(clippy::nonminimal_bool)] // <-- Deliberately calls is_err() rather than is_ok()
 fn benchmark_is_err(escrow_finish: &EscrowFinish) -> u64 {
     let mut count = 0u64;
     for _ in 0..ITERATIONS {
@@ -247,6 +252,23 @@ fn benchmark_result_err(escrow_finish: &EscrowFinish) -> u64 {
     count
 }
 
+/// Benchmark error propagation with the `?` operator
+fn benchmark_question_mark(escrow_finish: &EscrowFinish) -> u64 {
+    fn account_and_fee(escrow_finish: &EscrowFinish) -> Result<u64> {
+        let account = escrow_finish.get_account()?;
+        let fee = escrow_finish.get_fee()?;
+        Ok(account.0[0] as u64 + matches!(fee, Amount::XRP { .. }) as u64)
+    }
+
+    let mut count = 0u64;
+    for _ in 0..ITERATIONS {
+        if account_and_fee(escrow_finish).is_ok() {
+            count += 1;
+        }
+    }
+    count
+}
+
 /// Benchmark decode_hex_32
 fn benchmark_decode_hex_32() -> u64 {
     let hex = *b"0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
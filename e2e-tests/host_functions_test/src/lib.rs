@@ -662,7 +662,7 @@ fn test_keylet_generation_functions() -> i32 {
 }
 
 /// Test Category 6: Utility Functions (5 functions)
-/// Tests utility functions for hashing, NFT access, and tracing
+/// Tests utility functions for hashing, NFT access and decoding, and tracing
 fn test_utility_functions() -> i32 {
     let _ = trace("--- Category 6: Utility Functions ---");
 
@@ -716,6 +716,34 @@ fn test_utility_functions() -> i32 {
         );
     }
 
+    // Test 6.2b: NFToken::decode() agrees with the get_nft_* host functions
+    let nft = xrpl_wasm_stdlib::nft_id!(
+        "000B0539C35B55AA096BA6D87A6E6C965A6534150DC56E5E12C5D09E0000000C"
+    );
+    let parts = nft.decode();
+    let host_parts = (
+        nft.flags(),
+        nft.transfer_fee(),
+        nft.issuer(),
+        nft.taxon(),
+        nft.token_sequence(),
+    );
+    let _ = trace_num("Decoded NFT taxon:", parts.taxon as i64);
+    // The native stub host functions return dummy values, so only compare against rippled.
+    if cfg!(target_arch = "wasm32")
+        && host_parts
+            != (
+                Ok(parts.flags),
+                Ok(parts.transfer_fee),
+                Ok(parts.issuer),
+                Ok(parts.taxon),
+                Ok(parts.token_sequence),
+            )
+    {
+        let _ = trace("ERROR: NFToken::decode() disagrees with the host");
+        return -602; // NFT ID decoding mismatch
+    }
+
     // Test 6.3: trace() - Debug logging with data
     let trace_message = b"Test trace message";
     let trace_data_payload = b"payload";
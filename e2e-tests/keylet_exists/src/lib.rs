@@ -3,7 +3,7 @@
 #[cfg(not(target_arch = "wasm32"))]
 extern crate std;
 
-use crate::host::{Error, Result, Result::Err, Result::Ok};
+use crate::host::{Error, Result, ResultExt};
 use xrpl_wasm_stdlib::core::ledger_objects::current_escrow::CurrentEscrow;
 use xrpl_wasm_stdlib::core::ledger_objects::current_escrow::get_current_escrow;
 use xrpl_wasm_stdlib::core::ledger_objects::ledger_object;
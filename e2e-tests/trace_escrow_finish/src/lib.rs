@@ -120,7 +120,10 @@ pub extern "C" fn finish() -> i32 {
                 let _ = trace_data("  SigningPubKey:", &signing_pub_key.0, DataRepr::AsHex);
             }
             host::Result::Err(e) => {
-                let _ = trace_num("  Error getting SigningPubKey, error_code = ", e as i64);
+                let _ = trace_num(
+                    "  Error getting SigningPubKey, error_code = ",
+                    e.code() as i64,
+                );
             }
         }
 
@@ -344,7 +347,7 @@ pub extern "C" fn finish() -> i32 {
             }
             host::Result::Err(e) => {
                 let _ = trace("  ERROR getting Condition");
-                let _ = trace_num("  error_code=", e as i64);
+                let _ = trace_num("  error_code=", e.code() as i64);
                 return e.code();
             }
         }
@@ -25,7 +25,6 @@ use xrpl_wasm_stdlib::core::ledger_objects::traits::{
     CurrentEscrowFields, CurrentLedgerObjectCommonFields,
 };
 use xrpl_wasm_stdlib::host::trace::{DataRepr, trace, trace_amount, trace_data, trace_num};
-use xrpl_wasm_stdlib::host::{Result::Err, Result::Ok};
 
 #[unsafe(no_mangle)]
 pub extern "C" fn finish() -> i32 {
@@ -89,7 +88,7 @@ pub extern "C" fn finish() -> i32 {
             }
             Err(e) => {
                 let _ = trace("  ERROR getting Condition");
-                let _ = trace_num("  error_code=", e as i64);
+                let _ = trace_num("  error_code=", e.code() as i64);
                 return e.code();
             }
         }
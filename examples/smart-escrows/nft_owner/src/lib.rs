@@ -5,22 +5,18 @@ extern crate std;
 
 use xrpl_wasm_stdlib::core::ledger_objects::current_escrow;
 use xrpl_wasm_stdlib::core::ledger_objects::traits::CurrentEscrowFields;
-use xrpl_wasm_stdlib::core::locator::Locator;
 use xrpl_wasm_stdlib::core::types::nft::{NFT_ID_SIZE, NFToken};
 use xrpl_wasm_stdlib::host::Error::InternalError;
 use xrpl_wasm_stdlib::host::get_tx_nested_field;
 use xrpl_wasm_stdlib::host::trace::{DataRepr, trace_data, trace_num};
-use xrpl_wasm_stdlib::host::{Error, Result, Result::Err, Result::Ok};
-use xrpl_wasm_stdlib::sfield;
+use xrpl_wasm_stdlib::host::{Error, Result};
+use xrpl_wasm_stdlib::locator;
 use xrpl_wasm_stdlib::types::{ContractData, XRPL_CONTRACT_DATA_SIZE};
 
 #[unsafe(no_mangle)]
 pub fn get_first_memo() -> Result<Option<ContractData>> {
     let mut data: ContractData = [0; XRPL_CONTRACT_DATA_SIZE];
-    let mut locator = Locator::new();
-    locator.pack(sfield::Memos);
-    locator.pack(0);
-    locator.pack(sfield::MemoData);
+    let locator = locator!(Memos[0].MemoData);
     let result_code = unsafe {
         get_tx_nested_field(
             locator.as_ptr(),
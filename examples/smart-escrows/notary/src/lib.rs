@@ -5,24 +5,16 @@ extern crate std;
 
 use xrpl_wasm_stdlib::core::current_tx::escrow_finish;
 use xrpl_wasm_stdlib::core::current_tx::traits::TransactionCommonFields;
-use xrpl_wasm_stdlib::host::trace::trace_num;
-use xrpl_wasm_stdlib::host::{Result::Err, Result::Ok};
+use xrpl_wasm_stdlib::host::Result;
 use xrpl_wasm_stdlib::r_address;
 
 // The notary account that is authorized to complete escrows
 // Using example notary account for testing
 const NOTARY_ACCOUNT: [u8; 20] = r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
 
-#[unsafe(no_mangle)]
-pub extern "C" fn finish() -> i32 {
+#[xrpl_wasm_stdlib::finish(trace_errors)]
+fn finish() -> Result<bool> {
     let escrow_finish = escrow_finish::get_current_escrow_finish();
-    let tx_account = match escrow_finish.get_account() {
-        Ok(v) => v,
-        Err(e) => {
-            let _ = trace_num("Error in Notary contract", e.code() as i64);
-            return e.code(); // Must return to short circuit.
-        }
-    };
-
-    (tx_account.0 == NOTARY_ACCOUNT) as i32 // <-- Finish the escrow to indicate a successful outcome
+    let tx_account = escrow_finish.get_account()?;
+    Ok(tx_account.0 == NOTARY_ACCOUNT) // <-- Only the notary may finish the escrow
 }
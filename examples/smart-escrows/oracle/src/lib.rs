@@ -3,56 +3,33 @@
 #[cfg(not(target_arch = "wasm32"))]
 extern crate std;
 
+use xrpl_wasm_stdlib::core::bytes::ByteReader;
+use xrpl_wasm_stdlib::core::ledger_objects::ledger_object;
 use xrpl_wasm_stdlib::core::locator::Locator;
 use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+use xrpl_wasm_stdlib::core::types::blob::Blob;
 use xrpl_wasm_stdlib::core::types::keylets::oracle_keylet;
-use xrpl_wasm_stdlib::host::error_codes::match_result_code;
+use xrpl_wasm_stdlib::host;
 use xrpl_wasm_stdlib::host::trace::{DataRepr, trace_data, trace_num};
-use xrpl_wasm_stdlib::host::{Result, Result::Err, Result::Ok};
-use xrpl_wasm_stdlib::r_address;
-use xrpl_wasm_stdlib::{host, sfield};
+use xrpl_wasm_stdlib::host::{Result, ResultExt};
+use xrpl_wasm_stdlib::{locator, r_address};
 
 const ORACLE_OWNER: AccountID = AccountID(r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"));
 const ORACLE_DOCUMENT_ID: i32 = 1;
 
-// TODO: Update this function to handle errors and return a Result<u64> instead.
-pub fn get_u64_from_buffer(bytes: &[u8]) -> u64 {
-    let mut result: u64 = 0;
-
-    // rippled uses big-endian: most significant byte is first
-    let mut i = 0;
-    while i < 8 {
-        result = (result << 8) | (bytes[i] as u64);
-        i += 1;
-    }
-
-    result
+/// Reads a big-endian `u64` (the encoding rippled uses) from the start of `bytes`.
+pub fn get_u64_from_buffer(bytes: &[u8]) -> Result<u64> {
+    ByteReader::new(bytes).read_u64_be()
 }
 
-pub fn get_price_from_oracle(slot: i32) -> Result<u64> {
-    let mut locator = Locator::new();
-    locator.pack(sfield::PriceDataSeries);
-    locator.pack(0);
-    locator.pack(sfield::AssetPrice);
+const ASSET_PRICE: Locator = locator!(PriceDataSeries[0].AssetPrice);
 
-    let mut data: [u8; 8] = [0; 8];
-    let result_code = unsafe {
-        host::get_ledger_obj_nested_field(
-            slot,
-            locator.as_ptr(),
-            locator.num_packed_bytes(),
-            data.as_mut_ptr(),
-            data.len(),
-        )
-    };
-    let asset_price = match match_result_code(result_code, || data) {
-        Ok(asset_bytes) => get_u64_from_buffer(&asset_bytes[0..8]),
-        Err(error) => {
-            let _ = trace_num("Error getting asset_price", error.code() as i64);
-            return Err(error); // Must return to short circuit.
-        }
-    };
-    Ok(asset_price)
+/// Reads `PriceDataSeries[0].AssetPrice` of the Oracle cached in `slot`.
+pub fn get_price_from_oracle(slot: i32) -> Result<u64> {
+    // Read the raw field bytes so they can be decoded in rippled's big-endian order
+    ledger_object::get_nested_field::<Blob<8>>(slot, &ASSET_PRICE)
+        .and_then(|asset_bytes| get_u64_from_buffer(asset_bytes.as_slice()))
+        .trace_err("Error getting asset_price")
 }
 
 #[unsafe(no_mangle)]
@@ -0,0 +1,55 @@
+#!/bin/bash
+# WASM binary size check
+# Checks that the default release build of each example matches the sizes recorded in
+# examples/wasm-sizes.txt, so opt-in diagnostics (e.g. the `panic-trace` feature) can't leak into it.
+# Run with `--update` to record new sizes after an intended change.
+
+set -euo pipefail
+
+# Change to the repository root directory (where this script's parent directory is located)
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+REPO_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
+cd "$REPO_ROOT"
+
+BASELINE="examples/wasm-sizes.txt"
+RELEASE_DIR="examples/target/wasm32v1-none/release"
+PANIC_TRACE_TARGET_DIR="target/panic-trace"
+
+echo "📏 Checking WASM binary sizes..."
+
+echo "🏗️  Building examples with default features..."
+(cd examples && cargo build --workspace --target wasm32v1-none --release)
+
+sizes=$(for wasm in "$RELEASE_DIR"/*.wasm; do
+    echo "$(basename "$wasm") $(wc -c <"$wasm" | tr -d ' ')"
+done)
+
+if [[ "${1:-}" == "--update" ]]; then
+    echo "$sizes" >"$BASELINE"
+    echo "✅ Updated $BASELINE"
+    exit 0
+fi
+
+if ! diff -u "$BASELINE" <(echo "$sizes"); then
+    echo "❌ Default build sizes differ from $BASELINE"
+    echo "   If the change is intended, run: ./scripts/check-wasm-size.sh --update"
+    exit 1
+fi
+
+for wasm in "$RELEASE_DIR"/*.wasm; do
+    if grep -q "panicked at" "$wasm"; then
+        echo "❌ $(basename "$wasm") contains the panic-trace handler without the feature enabled"
+        exit 1
+    fi
+done
+
+echo "🏗️  Building examples with the panic-trace feature..."
+(cd examples && cargo build --workspace --target wasm32v1-none --release \
+    --features xrpl-wasm-stdlib/panic-trace --target-dir "../$PANIC_TRACE_TARGET_DIR")
+
+if ! grep -q "panicked at" "$PANIC_TRACE_TARGET_DIR"/wasm32v1-none/release/*.wasm; then
+    echo "❌ No example contains the panic-trace handler with the feature enabled"
+    exit 1
+fi
+
+echo "✅ WASM binary size check passed!"
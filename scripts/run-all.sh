@@ -43,6 +43,7 @@ run_script "/fmt.sh"
 run_script "/host-function-audit.sh"
 run_script "/check-wasm-exports.sh"
 run_script "/build-and-test.sh"
+run_script "/check-wasm-size.sh"
 run_script "/run-markdown.sh"
 run_script "/run-tests.sh"
 
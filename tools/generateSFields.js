@@ -94,6 +94,16 @@ async function main() {
     stypeMap[key] = value
   })
 
+  ////////////////////////////////////////////////////////////////////////
+  //  Serialized type processing
+  ////////////////////////////////////////////////////////////////////////
+
+  addLine("// Serialized type codes (the upper 16 bits of every field code)")
+  stypeHits.forEach(([_, key, value]) => {
+    addLine(`pub const STI_${key}: i32 = ${value};`)
+  })
+  addLine("")
+
   ////////////////////////////////////////////////////////////////////////
   //  SField processing
   ////////////////////////////////////////////////////////////////////////
@@ -120,23 +130,6 @@ async function main() {
     )
   }
 
-  ////////////////////////////////////////////////////////////////////////
-  //  Serialized type processing
-  ////////////////////////////////////////////////////////////////////////
-  // addLine('  "TYPES": {')
-
-  // stypeHits.push(['', 'DONE', -1])
-  // stypeHits.sort((a, b) => sorter(translate(a[1]), translate(b[1])))
-  // for (let x = 0; x < stypeHits.length; ++x) {
-  //   addLine(
-  //     '    "' +
-  //       translate(stypeHits[x][1]) +
-  //       '": ' +
-  //       stypeHits[x][2] +
-  //       (x < stypeHits.length - 1 ? ',' : ''),
-  //   )
-  // }
-
   const outputFile =
     process.argv.length == 4
       ? process.argv[3]
@@ -0,0 +1,146 @@
+//! Base58check encoding and decoding with the XRPL alphabet, as used by r-addresses.
+
+use crate::core::sha256::double_sha256;
+
+/// The XRPL base58 alphabet (note it starts with `r`, so a leading zero byte encodes as `r`).
+pub(crate) const ALPHABET: &[u8; 58] =
+    b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+
+/// Maximum number of payload bytes (version byte included) that [`encode_check`] accepts and
+/// [`decode_check`] returns.
+const MAX_PAYLOAD_SIZE: usize = 33;
+
+/// Maximum base58 length of a `MAX_PAYLOAD_SIZE`-byte payload plus its 4-byte checksum.
+const MAX_ENCODED_SIZE: usize = 51;
+
+/// Encodes `payload` followed by its 4-byte double-SHA-256 checksum into `out`, returning the
+/// number of bytes written, or `None` if `payload` or `out` is too small.
+pub(crate) fn encode_check(payload: &[u8], out: &mut [u8]) -> Option<usize> {
+    if payload.len() > MAX_PAYLOAD_SIZE {
+        return None;
+    }
+    let mut input = [0u8; MAX_PAYLOAD_SIZE + 4];
+    let input_len = payload.len() + 4;
+    input[..payload.len()].copy_from_slice(payload);
+    input[payload.len()..input_len].copy_from_slice(&double_sha256(payload)[..4]);
+    let input = &input[..input_len];
+
+    // Base58 digits, least significant first.
+    let mut digits = [0u8; MAX_ENCODED_SIZE];
+    let mut num_digits = 0;
+    for &byte in input {
+        let mut carry = byte as u32;
+        for digit in digits[..num_digits].iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits[num_digits] = (carry % 58) as u8;
+            num_digits += 1;
+            carry /= 58;
+        }
+    }
+
+    let zeros = input.iter().take_while(|&&b| b == 0).count();
+    let len = zeros + num_digits;
+    if len > out.len() {
+        return None;
+    }
+    out[..zeros].fill(ALPHABET[0]);
+    for (out, &digit) in out[zeros..len]
+        .iter_mut()
+        .zip(digits[..num_digits].iter().rev())
+    {
+        *out = ALPHABET[digit as usize];
+    }
+    Some(len)
+}
+
+/// Decodes base58check `input` into `out`, returning the payload length (version byte included)
+/// once the checksum has been verified. Returns `None` if `input` has a character outside the
+/// alphabet, is too long or too short, has a bad checksum, or if `out` is too small.
+pub(crate) fn decode_check(input: &[u8], out: &mut [u8]) -> Option<usize> {
+    if input.len() > MAX_ENCODED_SIZE {
+        return None;
+    }
+
+    // Decoded bytes, least significant first.
+    let mut bytes = [0u8; MAX_PAYLOAD_SIZE + 4];
+    let mut num_bytes = 0;
+    for &c in input {
+        let mut carry = ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in bytes[..num_bytes].iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            if num_bytes == bytes.len() {
+                return None;
+            }
+            bytes[num_bytes] = carry as u8;
+            num_bytes += 1;
+            carry >>= 8;
+        }
+    }
+
+    let zeros = input.iter().take_while(|&&c| c == ALPHABET[0]).count();
+    let len = zeros + num_bytes;
+    if len < 4 || len > bytes.len() {
+        return None;
+    }
+    let mut decoded = [0u8; MAX_PAYLOAD_SIZE + 4];
+    for (out, &byte) in decoded[zeros..len]
+        .iter_mut()
+        .zip(bytes[..num_bytes].iter().rev())
+    {
+        *out = byte;
+    }
+
+    let (payload, checksum) = decoded[..len].split_at(len - 4);
+    if double_sha256(payload)[..4] != *checksum || payload.len() > out.len() {
+        return None;
+    }
+    out[..payload.len()].copy_from_slice(payload);
+    Some(payload.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let mut encoded = [0u8; MAX_ENCODED_SIZE];
+        let mut decoded = [0u8; MAX_PAYLOAD_SIZE];
+        for payload in [&[][..], &[0, 0, 1], &[0xFF; MAX_PAYLOAD_SIZE], b"hello"] {
+            let len = encode_check(payload, &mut encoded).unwrap();
+            let decoded_len = decode_check(&encoded[..len], &mut decoded).unwrap();
+            assert_eq!(&decoded[..decoded_len], payload);
+        }
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_input() {
+        let mut out = [0u8; MAX_PAYLOAD_SIZE];
+        // Characters outside the alphabet (it has no 0, O, I or l)
+        assert_eq!(decode_check(b"r0", &mut out), None);
+        assert_eq!(decode_check(b"rl", &mut out), None);
+        // Too short to hold a checksum
+        assert_eq!(decode_check(b"", &mut out), None);
+        assert_eq!(decode_check(b"rrr", &mut out), None);
+        // Too long
+        assert_eq!(decode_check(&[b'z'; MAX_ENCODED_SIZE + 1], &mut out), None);
+
+        // A single changed character breaks the checksum
+        let mut encoded = [0u8; MAX_ENCODED_SIZE];
+        let len = encode_check(b"payload", &mut encoded).unwrap();
+        encoded[3] = if encoded[3] == b'p' { b's' } else { b'p' };
+        assert_eq!(decode_check(&encoded[..len], &mut out), None);
+
+        // Output buffer too small
+        let len = encode_check(b"payload", &mut encoded).unwrap();
+        assert_eq!(decode_check(&encoded[..len], &mut out[..6]), None);
+    }
+}
@@ -0,0 +1,477 @@
+//! Bounds-checked cursors for reading and writing raw bytes.
+//!
+//! [`ByteReader`] reads integers (big- or little-endian), VL-prefixed slices, `AccountID`s,
+//! `Hash256`s and canonical `Amount`s from a `&[u8]`, a [`Blob`] or a [`ContractData`]. Reads past
+//! the end return `Error::UnexpectedEndOfData` instead of panicking. [`ByteWriter`] is the
+//! counterpart; writes past the end of the buffer return `Error::BufferTooSmall`.
+//!
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::bytes::ByteReader;
+//! use xrpl_wasm_stdlib::core::current_tx;
+//! use xrpl_wasm_stdlib::core::types::blob::MemoBlob;
+//! use xrpl_wasm_stdlib::locator;
+//! use xrpl_wasm_stdlib::host::ResultExt;
+//!
+//! let memo: MemoBlob = current_tx::get_nested_field(&locator!(Memos[0].MemoData)).unwrap_or_panic();
+//! let mut reader = ByteReader::from(&memo);
+//! let version = reader.read_u8().unwrap_or_panic();
+//! let price = reader.read_u64_be().unwrap_or_panic();
+//! let account = reader.read_account_id().unwrap_or_panic();
+//! ```
+
+use crate::core::codec::field_header::{decode_vl_length, encode_vl_length};
+use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::core::types::amount::Amount;
+use crate::core::types::blob::Blob;
+use crate::core::types::contract_data::ContractData;
+use crate::core::types::uint::{HASH256_SIZE, Hash256, UInt};
+use crate::host::Error::{BufferTooSmall, InvalidParams, UnexpectedEndOfData};
+use crate::host::Result;
+
+/// A read cursor over a byte slice.
+#[derive(Debug, Clone)]
+pub struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+macro_rules! reader_int_methods {
+    ($($be:ident, $le:ident => $t:ty;)*) => {
+        $(
+            #[doc = concat!("Reads a big-endian `", stringify!($t), "`.")]
+            #[inline]
+            pub fn $be(&mut self) -> Result<$t> {
+                Result::Ok(<$t>::from_be_bytes(self.read_array()?))
+            }
+
+            #[doc = concat!("Reads a little-endian `", stringify!($t), "`.")]
+            #[inline]
+            pub fn $le(&mut self) -> Result<$t> {
+                Result::Ok(<$t>::from_le_bytes(self.read_array()?))
+            }
+        )*
+    };
+}
+
+impl<'a> ByteReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        ByteReader { bytes, pos: 0 }
+    }
+
+    /// The number of bytes read so far.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The number of bytes not yet read.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Returns the next byte without consuming it.
+    pub fn peek_u8(&self) -> Result<u8> {
+        match self.bytes.get(self.pos) {
+            Some(&byte) => Result::Ok(byte),
+            None => Result::Err(UnexpectedEndOfData),
+        }
+    }
+
+    /// Skips `len` bytes.
+    pub fn skip(&mut self, len: usize) -> Result<()> {
+        self.read_bytes(len)?;
+        Result::Ok(())
+    }
+
+    /// Reads the next `len` bytes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&[u8])` - The bytes
+    /// * `Err(Error::UnexpectedEndOfData)` - If fewer than `len` bytes remain
+    #[inline]
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if len > self.remaining() {
+            return Result::Err(UnexpectedEndOfData);
+        }
+        let bytes = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        Result::Ok(bytes)
+    }
+
+    /// Reads the next `N` bytes into an array.
+    #[inline]
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self.read_bytes(N)?;
+        let mut array = [0u8; N];
+        array.copy_from_slice(bytes);
+        Result::Ok(array)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8> {
+        Result::Ok(self.read_array::<1>()?[0])
+    }
+
+    pub fn read_i8(&mut self) -> Result<i8> {
+        Result::Ok(self.read_u8()? as i8)
+    }
+
+    reader_int_methods! {
+        read_u16_be, read_u16_le => u16;
+        read_u32_be, read_u32_le => u32;
+        read_u64_be, read_u64_le => u64;
+        read_i16_be, read_i16_le => i16;
+        read_i32_be, read_i32_le => i32;
+        read_i64_be, read_i64_le => i64;
+    }
+
+    /// Reads an XRPL VL length prefix (1 to 3 bytes).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The encoded length
+    /// * `Err(Error::UnexpectedEndOfData)` - If the prefix is truncated
+    /// * `Err(Error::InvalidDecoding)` - If the prefix is malformed
+    pub fn read_vl_length(&mut self) -> Result<usize> {
+        let rest = &self.bytes[self.pos..];
+        match decode_vl_length(rest) {
+            Result::Ok((len, prefix_len)) => {
+                self.pos += prefix_len;
+                Result::Ok(len)
+            }
+            // A well-formed but cut-off prefix is reported as running out of data.
+            Result::Err(_) if rest.len() < 3 && rest.first().is_none_or(|&b| b > 192) => {
+                Result::Err(UnexpectedEndOfData)
+            }
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    /// Reads a VL length prefix followed by that many bytes.
+    pub fn read_vl(&mut self) -> Result<&'a [u8]> {
+        let start = self.pos;
+        let len = self.read_vl_length()?;
+        let result = self.read_bytes(len);
+        if result.is_err() {
+            self.pos = start;
+        }
+        result
+    }
+
+    pub fn read_account_id(&mut self) -> Result<AccountID> {
+        Result::Ok(AccountID(self.read_array::<ACCOUNT_ID_SIZE>()?))
+    }
+
+    pub fn read_hash256(&mut self) -> Result<Hash256> {
+        Result::Ok(UInt(self.read_array::<HASH256_SIZE>()?))
+    }
+
+    /// Reads an amount in its canonical 8-byte (XRP), 33-byte (MPT) or 48-byte (IOU) form.
+    ///
+    /// Non-canonical amounts are rejected with `Error::InvalidDecoding` (see
+    /// [`Amount::from_canonical_bytes`]).
+    pub fn read_amount(&mut self) -> Result<Amount> {
+        let first = self.peek_u8()?;
+        Amount::from_canonical_bytes(self.read_bytes(Amount::serialized_len(first))?)
+    }
+}
+
+impl<'a> From<&'a [u8]> for ByteReader<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        ByteReader::new(bytes)
+    }
+}
+
+/// Reads the blob's first `len` bytes; a `len` beyond the capacity is clamped to it.
+impl<'a, const N: usize> From<&'a Blob<N>> for ByteReader<'a> {
+    fn from(blob: &'a Blob<N>) -> Self {
+        ByteReader::new(&blob.data[..blob.len.min(N)])
+    }
+}
+
+/// Reads the data's first `len` bytes; a `len` beyond the capacity is clamped to it.
+impl<'a> From<&'a ContractData> for ByteReader<'a> {
+    fn from(data: &'a ContractData) -> Self {
+        ByteReader::new(&data.data[..data.len.min(data.data.len())])
+    }
+}
+
+/// A write cursor over a byte buffer.
+///
+/// When created from a [`Blob`] or [`ContractData`], writes append after the existing contents and
+/// keep the container's `len` up to date.
+#[derive(Debug)]
+pub struct ByteWriter<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+    len: Option<&'a mut usize>,
+}
+
+macro_rules! writer_int_methods {
+    ($($be:ident, $le:ident => $t:ty;)*) => {
+        $(
+            #[doc = concat!("Writes a big-endian `", stringify!($t), "`.")]
+            #[inline]
+            pub fn $be(&mut self, value: $t) -> Result<()> {
+                self.write_bytes(&value.to_be_bytes())
+            }
+
+            #[doc = concat!("Writes a little-endian `", stringify!($t), "`.")]
+            #[inline]
+            pub fn $le(&mut self, value: $t) -> Result<()> {
+                self.write_bytes(&value.to_le_bytes())
+            }
+        )*
+    };
+}
+
+impl<'a> ByteWriter<'a> {
+    /// Creates a writer that starts at the beginning of `buf`.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        ByteWriter {
+            buf,
+            pos: 0,
+            len: None,
+        }
+    }
+
+    /// Creates a writer that appends to `blob`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ByteWriter)` - A writer positioned after the blob's contents
+    /// * `Err(Error::InvalidParams)` - If `blob.len` exceeds the blob's capacity
+    pub fn from_blob<const N: usize>(blob: &'a mut Blob<N>) -> Result<Self> {
+        Self::appending(&mut blob.data, &mut blob.len)
+    }
+
+    /// Creates a writer that appends to `data`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ByteWriter)` - A writer positioned after the existing data
+    /// * `Err(Error::InvalidParams)` - If `data.len` exceeds the data's capacity
+    pub fn from_contract_data(data: &'a mut ContractData) -> Result<Self> {
+        Self::appending(&mut data.data, &mut data.len)
+    }
+
+    fn appending(buf: &'a mut [u8], len: &'a mut usize) -> Result<Self> {
+        if *len > buf.len() {
+            return Result::Err(InvalidParams);
+        }
+        Result::Ok(ByteWriter {
+            pos: *len,
+            buf,
+            len: Some(len),
+        })
+    }
+
+    /// The number of bytes in the buffer up to the write position.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The number of bytes that can still be written.
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// The bytes written so far (including any existing contents of a blob or contract data).
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.pos]
+    }
+
+    /// Writes `bytes`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the bytes fit
+    /// * `Err(Error::BufferTooSmall)` - If fewer than `bytes.len()` bytes remain; nothing is written
+    #[inline]
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() > self.remaining() {
+            return Result::Err(BufferTooSmall);
+        }
+        self.buf[self.pos..self.pos + bytes.len()].copy_from_slice(bytes);
+        self.pos += bytes.len();
+        if let Some(len) = self.len.as_deref_mut() {
+            *len = self.pos;
+        }
+        Result::Ok(())
+    }
+
+    pub fn write_u8(&mut self, value: u8) -> Result<()> {
+        self.write_bytes(&[value])
+    }
+
+    pub fn write_i8(&mut self, value: i8) -> Result<()> {
+        self.write_bytes(&[value as u8])
+    }
+
+    writer_int_methods! {
+        write_u16_be, write_u16_le => u16;
+        write_u32_be, write_u32_le => u32;
+        write_u64_be, write_u64_le => u64;
+        write_i16_be, write_i16_le => i16;
+        write_i32_be, write_i32_le => i32;
+        write_i64_be, write_i64_le => i64;
+    }
+
+    /// Writes an XRPL VL length prefix (1 to 3 bytes).
+    pub fn write_vl_length(&mut self, len: usize) -> Result<()> {
+        let (prefix, prefix_len) = encode_vl_length(len)?;
+        self.write_bytes(&prefix[..prefix_len])
+    }
+
+    /// Writes a VL length prefix followed by `bytes`; nothing is written if both do not fit.
+    pub fn write_vl(&mut self, bytes: &[u8]) -> Result<()> {
+        let (prefix, prefix_len) = encode_vl_length(bytes.len())?;
+        if prefix_len + bytes.len() > self.remaining() {
+            return Result::Err(BufferTooSmall);
+        }
+        self.write_bytes(&prefix[..prefix_len])?;
+        self.write_bytes(bytes)
+    }
+
+    pub fn write_account_id(&mut self, account: &AccountID) -> Result<()> {
+        self.write_bytes(&account.0)
+    }
+
+    pub fn write_hash256(&mut self, hash: &Hash256) -> Result<()> {
+        self.write_bytes(&hash.0)
+    }
+
+    /// Writes an amount in its canonical 8-byte (XRP), 33-byte (MPT) or 48-byte (IOU) form.
+    pub fn write_amount(&mut self, amount: &Amount) -> Result<()> {
+        let (bytes, len) = amount.to_canonical_bytes()?;
+        self.write_bytes(&bytes[..len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::contract_data::XRPL_CONTRACT_DATA_SIZE;
+    use crate::host::Error::InvalidDecoding;
+
+    #[test]
+    fn test_read_ints_both_endians() {
+        let bytes = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+        let mut reader = ByteReader::new(&bytes);
+        assert_eq!(reader.read_u16_be().unwrap(), 0x0102);
+        assert_eq!(reader.read_u16_le().unwrap(), 0x0403);
+        assert_eq!(reader.read_i32_be().unwrap(), 0x0506_0708);
+        assert!(reader.is_empty());
+
+        let mut reader = ByteReader::new(&bytes);
+        assert_eq!(reader.read_u64_be().unwrap(), 0x0102_0304_0506_0708);
+        let mut reader = ByteReader::new(&bytes);
+        assert_eq!(reader.read_u64_le().unwrap(), 0x0807_0605_0403_0201);
+    }
+
+    #[test]
+    fn test_short_input_returns_error() {
+        let mut reader = ByteReader::new(&[1, 2, 3]);
+        let error = reader.read_u64_be().err().unwrap();
+        assert_eq!(error.code(), UnexpectedEndOfData.code());
+        // A failed read does not consume anything
+        assert_eq!(reader.position(), 0);
+        assert_eq!(reader.read_u16_be().unwrap(), 0x0102);
+        assert!(reader.read_account_id().is_err());
+        assert!(reader.skip(2).is_err());
+        assert!(ByteReader::new(&[]).peek_u8().is_err());
+    }
+
+    #[test]
+    fn test_vl_roundtrip() {
+        let mut buf = [0u8; 512];
+        let mut writer = ByteWriter::new(&mut buf);
+        writer.write_vl(&[7u8; 200]).unwrap();
+        writer.write_vl(b"").unwrap();
+        assert_eq!(writer.position(), 2 + 200 + 1);
+
+        let len = writer.position();
+        let mut reader = ByteReader::new(&buf[..len]);
+        assert_eq!(reader.read_vl().unwrap(), &[7u8; 200][..]);
+        assert_eq!(reader.read_vl().unwrap(), b"");
+        assert!(reader.is_empty());
+
+        // Truncated payload and truncated two-byte prefix
+        let error = ByteReader::new(&[3, 1]).read_vl().err().unwrap();
+        assert_eq!(error.code(), UnexpectedEndOfData.code());
+        let error = ByteReader::new(&[193]).read_vl().err().unwrap();
+        assert_eq!(error.code(), UnexpectedEndOfData.code());
+        let error = ByteReader::new(&[255, 0, 0]).read_vl().err().unwrap();
+        assert_eq!(error.code(), InvalidDecoding.code());
+    }
+
+    #[test]
+    fn test_typed_roundtrip() {
+        let account = AccountID([3u8; 20]);
+        let hash: Hash256 = UInt([4u8; 32]);
+        let amount = Amount::XRP { num_drops: 1_000 };
+
+        let mut buf = [0u8; 128];
+        let mut writer = ByteWriter::new(&mut buf);
+        writer.write_account_id(&account).unwrap();
+        writer.write_hash256(&hash).unwrap();
+        writer.write_amount(&amount).unwrap();
+        writer.write_i64_le(-2).unwrap();
+        let len = writer.position();
+        assert_eq!(len, 20 + 32 + 8 + 8);
+
+        let mut reader = ByteReader::new(&buf[..len]);
+        assert_eq!(reader.read_account_id().unwrap(), account);
+        assert_eq!(reader.read_hash256().unwrap(), hash);
+        assert_eq!(reader.read_amount().unwrap(), amount);
+        assert_eq!(reader.read_i64_le().unwrap(), -2);
+    }
+
+    #[test]
+    fn test_writer_appends_to_blob_and_contract_data() {
+        let mut blob = Blob::<4>::from_slice(&[9]);
+        let mut writer = ByteWriter::from_blob(&mut blob).unwrap();
+        writer.write_u16_be(0x0102).unwrap();
+        let error = writer.write_u16_be(0x0304).err().unwrap();
+        assert_eq!(error.code(), BufferTooSmall.code());
+        assert_eq!(blob.as_slice(), &[9, 1, 2]);
+        assert_eq!(ByteReader::from(&blob).read_u8().unwrap(), 9);
+
+        let mut data = ContractData {
+            data: [0; XRPL_CONTRACT_DATA_SIZE],
+            len: 0,
+        };
+        let mut writer = ByteWriter::from_contract_data(&mut data).unwrap();
+        writer.write_u32_le(5).unwrap();
+        assert_eq!(data.len, 4);
+        assert_eq!(ByteReader::from(&data).read_u32_le().unwrap(), 5);
+    }
+
+    #[test]
+    fn test_oversized_len_is_rejected_or_clamped() {
+        let mut blob = Blob::<4>::from_slice(&[1, 2, 3, 4]);
+        blob.len = 5;
+        let error = ByteWriter::from_blob(&mut blob).err().unwrap();
+        assert_eq!(error.code(), InvalidParams.code());
+        let mut reader = ByteReader::from(&blob);
+        assert_eq!(reader.remaining(), 4);
+        assert_eq!(reader.read_u32_be().unwrap(), 0x0102_0304);
+
+        let mut data = ContractData {
+            data: [7; XRPL_CONTRACT_DATA_SIZE],
+            len: XRPL_CONTRACT_DATA_SIZE + 1,
+        };
+        let error = ByteWriter::from_contract_data(&mut data).err().unwrap();
+        assert_eq!(error.code(), InvalidParams.code());
+        assert_eq!(ByteReader::from(&data).remaining(), XRPL_CONTRACT_DATA_SIZE);
+
+        // A full container is fine, there is just no room left
+        data.len = XRPL_CONTRACT_DATA_SIZE;
+        let mut writer = ByteWriter::from_contract_data(&mut data).unwrap();
+        assert_eq!(writer.remaining(), 0);
+        assert!(writer.write_u8(0).is_err());
+    }
+}
@@ -0,0 +1,188 @@
+//! Field ID headers and variable-length (VL) prefixes.
+//!
+//! A field header packs the serialized type and the field ordinal into 1 to 3 bytes; values below
+//! 16 share the first byte as nibbles. VL prefixes encode blob lengths in 1 to 3 bytes.
+
+use super::{field_code, field_ordinal, type_code};
+use crate::host::Error::{InvalidDecoding, InvalidField, InvalidParams};
+use crate::host::Result;
+
+/// The largest encoded field header, in bytes.
+pub const MAX_FIELD_HEADER_SIZE: usize = 3;
+
+/// The largest encoded VL prefix, in bytes.
+pub const MAX_VL_PREFIX_SIZE: usize = 3;
+
+/// The largest length that can be expressed by a VL prefix.
+pub const MAX_VL_LENGTH: usize = 918_744;
+
+/// Encodes the header for `field_code`.
+///
+/// # Returns
+///
+/// * `Ok((bytes, len))` - The header in `bytes[..len]`
+/// * `Err(Error::InvalidField)` - If the type or ordinal is outside `1..=255`
+pub fn encode_field_header(field_code: i32) -> Result<([u8; MAX_FIELD_HEADER_SIZE], usize)> {
+    let type_code = type_code(field_code);
+    let ordinal = field_ordinal(field_code);
+    if !(1..=255).contains(&type_code) || !(1..=255).contains(&ordinal) {
+        return Result::Err(InvalidField);
+    }
+
+    let (type_code, ordinal) = (type_code as u8, ordinal as u8);
+    let header = match (type_code < 16, ordinal < 16) {
+        (true, true) => ([(type_code << 4) | ordinal, 0, 0], 1),
+        (true, false) => ([type_code << 4, ordinal, 0], 2),
+        (false, true) => ([ordinal, type_code, 0], 2),
+        (false, false) => ([0, type_code, ordinal], 3),
+    };
+    Result::Ok(header)
+}
+
+/// Decodes the field header at the start of `bytes`.
+///
+/// # Returns
+///
+/// * `Ok((field_code, consumed))` - The field code and the number of header bytes read
+/// * `Err(Error::InvalidDecoding)` - If the header is truncated or not canonically encoded
+pub fn decode_field_header(bytes: &[u8]) -> Result<(i32, usize)> {
+    let Some(&first) = bytes.first() else {
+        return Result::Err(InvalidDecoding);
+    };
+
+    let mut consumed = 1;
+    let mut type_code = (first >> 4) as i32;
+    let mut ordinal = (first & 0x0F) as i32;
+
+    if type_code == 0 {
+        match bytes.get(consumed) {
+            Some(&value) if value >= 16 => type_code = value as i32,
+            _ => return Result::Err(InvalidDecoding),
+        }
+        consumed += 1;
+    }
+    if ordinal == 0 {
+        match bytes.get(consumed) {
+            Some(&value) if value >= 16 => ordinal = value as i32,
+            _ => return Result::Err(InvalidDecoding),
+        }
+        consumed += 1;
+    }
+
+    Result::Ok((field_code(type_code, ordinal), consumed))
+}
+
+/// Encodes a VL length prefix.
+///
+/// # Returns
+///
+/// * `Ok((bytes, len))` - The prefix in `bytes[..len]`
+/// * `Err(Error::InvalidParams)` - If `length` exceeds [`MAX_VL_LENGTH`]
+pub fn encode_vl_length(length: usize) -> Result<([u8; MAX_VL_PREFIX_SIZE], usize)> {
+    if length <= 192 {
+        Result::Ok(([length as u8, 0, 0], 1))
+    } else if length <= 12_480 {
+        let length = length - 193;
+        Result::Ok(([193 + (length >> 8) as u8, (length & 0xFF) as u8, 0], 2))
+    } else if length <= MAX_VL_LENGTH {
+        let length = length - 12_481;
+        Result::Ok((
+            [
+                241 + (length >> 16) as u8,
+                ((length >> 8) & 0xFF) as u8,
+                (length & 0xFF) as u8,
+            ],
+            3,
+        ))
+    } else {
+        Result::Err(InvalidParams)
+    }
+}
+
+/// Decodes the VL length prefix at the start of `bytes`.
+///
+/// # Returns
+///
+/// * `Ok((length, consumed))` - The encoded length and the number of prefix bytes read
+/// * `Err(Error::InvalidDecoding)` - If the prefix is truncated or malformed
+pub fn decode_vl_length(bytes: &[u8]) -> Result<(usize, usize)> {
+    match bytes {
+        [b0, ..] if *b0 <= 192 => Result::Ok((*b0 as usize, 1)),
+        [b0, b1, ..] if *b0 <= 240 => {
+            Result::Ok((193 + (*b0 as usize - 193) * 256 + *b1 as usize, 2))
+        }
+        [b0, b1, b2, ..] if *b0 <= 254 => Result::Ok((
+            12_481 + (*b0 as usize - 241) * 65_536 + *b1 as usize * 256 + *b2 as usize,
+            3,
+        )),
+        _ => Result::Err(InvalidDecoding),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sfield;
+
+    fn header(field_code: i32) -> ([u8; 3], usize) {
+        encode_field_header(field_code).unwrap()
+    }
+
+    #[test]
+    fn test_field_header_encodings() {
+        // type < 16, ordinal < 16
+        assert_eq!(header(sfield::TransactionType), ([0x12, 0, 0], 1));
+        assert_eq!(header(sfield::Account), ([0x81, 0, 0], 1));
+        // type < 16, ordinal >= 16
+        assert_eq!(header(sfield::OfferSequence), ([0x20, 0x19, 0], 2));
+        // type >= 16, ordinal < 16
+        assert_eq!(header(sfield::CloseResolution), ([0x01, 0x10, 0], 2));
+        // type >= 16, ordinal >= 16
+        assert_eq!(header(sfield::TickSize), ([0x00, 0x10, 0x10], 3));
+    }
+
+    #[test]
+    fn test_field_header_roundtrip() {
+        for field in [
+            sfield::TransactionType,
+            sfield::OfferSequence,
+            sfield::CloseResolution,
+            sfield::TickSize,
+            sfield::Memos,
+            sfield::Asset,
+        ] {
+            let (bytes, len) = header(field);
+            assert_eq!(decode_field_header(&bytes[..len]).unwrap(), (field, len));
+        }
+    }
+
+    #[test]
+    fn test_field_header_rejects_invalid() {
+        assert!(encode_field_header(sfield::Invalid).is_err());
+        assert!(encode_field_header(sfield::Generic).is_err());
+        assert!(decode_field_header(&[]).is_err());
+        // Truncated two-byte header
+        assert!(decode_field_header(&[0x20]).is_err());
+        // Non-canonical: ordinal < 16 in the extended byte
+        assert!(decode_field_header(&[0x20, 0x02]).is_err());
+    }
+
+    #[test]
+    fn test_vl_length_boundaries() {
+        for (length, prefix_len) in [
+            (0, 1),
+            (192, 1),
+            (193, 2),
+            (12_480, 2),
+            (12_481, 3),
+            (MAX_VL_LENGTH, 3),
+        ] {
+            let (bytes, len) = encode_vl_length(length).unwrap();
+            assert_eq!(len, prefix_len);
+            assert_eq!(decode_vl_length(&bytes[..len]).unwrap(), (length, len));
+        }
+        assert!(encode_vl_length(MAX_VL_LENGTH + 1).is_err());
+        assert!(decode_vl_length(&[255, 0, 0]).is_err());
+        assert!(decode_vl_length(&[193]).is_err());
+    }
+}
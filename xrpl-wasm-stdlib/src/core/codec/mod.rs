@@ -0,0 +1,115 @@
+//! XRPL canonical binary codec.
+//!
+//! Decodes and encodes the XRPL binary format (the same bytes rippled signs and hashes) over
+//! fixed, caller-provided buffers, without `alloc`. This makes it possible to read inner objects,
+//! parse serialized transactions carried in memos, and build canonical bytes for hashing or
+//! signature verification.
+//!
+//! - [`field_header`]: Field ID headers and variable-length (VL) prefixes
+//! - [`parser`]: Zero-copy [`parser::BinaryParser`], [`parser::STObject`], [`parser::STArray`]
+//! - [`serializer`]: [`serializer::Serializer`] writing canonical bytes into a buffer
+//!
+//! Field codes are the values in [`sfield`](crate::sfield): the upper 16 bits hold the serialized
+//! type (`sfield::STI_*`) and the lower 16 bits hold the field's ordinal within that type.
+//!
+//! Example
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::codec::parser::STObject;
+//! use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+//! use xrpl_wasm_stdlib::sfield;
+//! use xrpl_wasm_stdlib::host::ResultExt;
+//!
+//! # let tx_bytes: &[u8] = &[];
+//! let tx = STObject::new(tx_bytes);
+//! if let Some(field) = tx.get(sfield::Account).unwrap_or_panic() {
+//!     let _account: AccountID = field.as_account_id().unwrap_or_panic();
+//! }
+//! ```
+
+pub mod field_header;
+pub mod parser;
+pub mod serializer;
+
+use crate::sfield;
+
+/// Field code of the marker that terminates an inner object (`STI_OBJECT`, ordinal 1).
+pub const OBJECT_END_MARKER: i32 = (sfield::STI_OBJECT << 16) | 1;
+
+/// Field code of the marker that terminates an array (`STI_ARRAY`, ordinal 1).
+pub const ARRAY_END_MARKER: i32 = (sfield::STI_ARRAY << 16) | 1;
+
+/// Returns the serialized type (one of `sfield::STI_*`) of a field code.
+#[inline]
+pub const fn type_code(field_code: i32) -> i32 {
+    field_code >> 16
+}
+
+/// Returns the ordinal of a field code within its serialized type.
+#[inline]
+pub const fn field_ordinal(field_code: i32) -> i32 {
+    field_code & 0xFFFF
+}
+
+/// Builds a field code from a serialized type and an ordinal.
+#[inline]
+pub const fn field_code(type_code: i32, ordinal: i32) -> i32 {
+    (type_code << 16) | ordinal
+}
+
+/// The size of a serialized `STNumber` (64-bit mantissa followed by a 32-bit exponent).
+pub const ST_NUMBER_SIZE: usize = 12;
+
+/// A decoded `STNumber`: the value is `mantissa * 10^exponent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct STNumber {
+    pub mantissa: i64,
+    pub exponent: i32,
+}
+
+impl STNumber {
+    pub fn from_bytes(bytes: &[u8; ST_NUMBER_SIZE]) -> Self {
+        let mut mantissa = [0u8; 8];
+        mantissa.copy_from_slice(&bytes[0..8]);
+        let mut exponent = [0u8; 4];
+        exponent.copy_from_slice(&bytes[8..12]);
+        STNumber {
+            mantissa: i64::from_be_bytes(mantissa),
+            exponent: i32::from_be_bytes(exponent),
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; ST_NUMBER_SIZE] {
+        let mut bytes = [0u8; ST_NUMBER_SIZE];
+        bytes[0..8].copy_from_slice(&self.mantissa.to_be_bytes());
+        bytes[8..12].copy_from_slice(&self.exponent.to_be_bytes());
+        bytes
+    }
+}
+
+/// The account rippled serializes in place of an issuer to mark an MPT `STIssue`
+/// (`rrrrrrrrrrrrrrrrrrrrBZbvji`, the account with ID `0x00..01`).
+pub(crate) const NO_ACCOUNT: [u8; 20] =
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_code_parts() {
+        assert_eq!(type_code(sfield::Account), sfield::STI_ACCOUNT);
+        assert_eq!(field_ordinal(sfield::Account), 1);
+        assert_eq!(field_code(sfield::STI_UINT32, 2), sfield::Flags);
+        assert_eq!(type_code(OBJECT_END_MARKER), sfield::STI_OBJECT);
+        assert_eq!(type_code(ARRAY_END_MARKER), sfield::STI_ARRAY);
+    }
+
+    #[test]
+    fn test_st_number_roundtrip() {
+        let number = STNumber {
+            mantissa: -1_234_567_890_123_456,
+            exponent: -12,
+        };
+        assert_eq!(STNumber::from_bytes(&number.to_bytes()), number);
+    }
+}
@@ -0,0 +1,706 @@
+//! Zero-copy parsing of canonical XRPL binary data.
+//!
+//! [`BinaryParser`] reads one [`Field`] at a time from a byte slice. Each field borrows its value
+//! from the input, and typed accessors (`as_u32`, `as_account_id`, `as_amount`, `as_object`, ...)
+//! check the field's serialized type before decoding it. Inner objects and arrays are exposed as
+//! [`STObject`] and [`STArray`] views over the same input, so nothing is copied until a value is
+//! actually decoded.
+
+use super::field_header::{decode_field_header, decode_vl_length};
+use super::{ARRAY_END_MARKER, NO_ACCOUNT, OBJECT_END_MARKER, ST_NUMBER_SIZE, STNumber, type_code};
+use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::core::types::amount::Amount;
+use crate::core::types::currency::{CURRENCY_SIZE, Currency};
+use crate::core::types::issue::Issue;
+use crate::core::types::uint::{HASH256_SIZE, Hash256, UInt};
+use crate::host::Error::{InvalidDecoding, InvalidField};
+use crate::host::Result;
+use crate::sfield;
+
+/// Inner objects and arrays may be nested at most this many levels deep, matching rippled.
+const MAX_NESTING_DEPTH: usize = 10;
+
+/// A single decoded field: its field code and its (borrowed) value bytes.
+///
+/// For VL-encoded types (`STI_VL`, `STI_ACCOUNT`, `STI_VECTOR256`) the value excludes the length
+/// prefix. For inner objects and arrays it excludes the end marker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Field<'a> {
+    pub field_code: i32,
+    pub value: &'a [u8],
+}
+
+/// Reads fields sequentially from canonical XRPL binary data.
+#[derive(Debug, Clone)]
+pub struct BinaryParser<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BinaryParser<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    /// The number of bytes consumed so far.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The bytes that have not been consumed yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.data[self.pos..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    /// Reads the next field (header and value).
+    ///
+    /// End markers are returned as fields with an empty value, so callers walking an inner object
+    /// or array can detect [`OBJECT_END_MARKER`] and [`ARRAY_END_MARKER`].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Field)` - The next field
+    /// * `Err(Error::InvalidDecoding)` - If the data is truncated or malformed
+    pub fn read_field(&mut self) -> Result<Field<'a>> {
+        self.read_field_at_depth(0)
+    }
+
+    fn read_field_at_depth(&mut self, depth: usize) -> Result<Field<'a>> {
+        let (field_code, header_len) = decode_field_header(self.remaining())?;
+        self.pos += header_len;
+
+        if field_code == OBJECT_END_MARKER || field_code == ARRAY_END_MARKER {
+            return Result::Ok(Field {
+                field_code,
+                value: &[],
+            });
+        }
+
+        let start = self.pos;
+        let extent = value_extent(type_code(field_code), self.remaining(), depth)?;
+        self.pos += extent.consumed;
+
+        Result::Ok(Field {
+            field_code,
+            value: &self.data[start + extent.start..start + extent.end],
+        })
+    }
+}
+
+/// Where a value lies relative to the start of its encoding.
+struct Extent {
+    /// Offset of the first value byte (non-zero when a VL prefix precedes the value).
+    start: usize,
+    /// Offset one past the last value byte.
+    end: usize,
+    /// Total number of bytes the encoding occupies (including prefixes and end markers).
+    consumed: usize,
+}
+
+impl Extent {
+    fn fixed(len: usize, bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < len {
+            return Result::Err(InvalidDecoding);
+        }
+        Result::Ok(Extent {
+            start: 0,
+            end: len,
+            consumed: len,
+        })
+    }
+}
+
+fn value_extent(type_code: i32, bytes: &[u8], depth: usize) -> Result<Extent> {
+    match type_code {
+        sfield::STI_UINT8 => Extent::fixed(1, bytes),
+        sfield::STI_UINT16 => Extent::fixed(2, bytes),
+        sfield::STI_UINT32 | sfield::STI_INT32 => Extent::fixed(4, bytes),
+        sfield::STI_UINT64 | sfield::STI_INT64 => Extent::fixed(8, bytes),
+        sfield::STI_UINT96 | sfield::STI_NUMBER => Extent::fixed(12, bytes),
+        sfield::STI_UINT128 => Extent::fixed(16, bytes),
+        sfield::STI_UINT160 | sfield::STI_CURRENCY => Extent::fixed(20, bytes),
+        sfield::STI_UINT192 => Extent::fixed(24, bytes),
+        sfield::STI_UINT256 => Extent::fixed(32, bytes),
+        sfield::STI_UINT384 => Extent::fixed(48, bytes),
+        sfield::STI_UINT512 => Extent::fixed(64, bytes),
+        sfield::STI_AMOUNT => match bytes.first() {
+            Some(&first) => Extent::fixed(Amount::serialized_len(first), bytes),
+            None => Result::Err(InvalidDecoding),
+        },
+        sfield::STI_ISSUE => Extent::fixed(issue_len(bytes)?, bytes),
+        sfield::STI_VL | sfield::STI_ACCOUNT | sfield::STI_VECTOR256 => vl_extent(bytes),
+        sfield::STI_OBJECT | sfield::STI_ARRAY => container_extent(type_code, bytes, depth),
+        sfield::STI_PATHSET => pathset_extent(bytes),
+        sfield::STI_XCHAIN_BRIDGE => xchain_bridge_extent(bytes),
+        _ => Result::Err(InvalidDecoding),
+    }
+}
+
+/// The canonical length of an `STIssue`: 20 bytes for XRP, 40 for an IOU and 44 for an MPT.
+fn issue_len(bytes: &[u8]) -> Result<usize> {
+    if bytes.len() < 20 {
+        return Result::Err(InvalidDecoding);
+    }
+    if bytes[..20].iter().all(|&b| b == 0) {
+        return Result::Ok(20);
+    }
+    if bytes.len() < 40 {
+        return Result::Err(InvalidDecoding);
+    }
+    if bytes[20..40] == NO_ACCOUNT {
+        return Result::Ok(44);
+    }
+    Result::Ok(40)
+}
+
+fn vl_extent(bytes: &[u8]) -> Result<Extent> {
+    let (len, prefix_len) = decode_vl_length(bytes)?;
+    if bytes.len() < prefix_len + len {
+        return Result::Err(InvalidDecoding);
+    }
+    Result::Ok(Extent {
+        start: prefix_len,
+        end: prefix_len + len,
+        consumed: prefix_len + len,
+    })
+}
+
+/// Walks an inner object or array up to (and including) its end marker.
+fn container_extent(type_code: i32, bytes: &[u8], depth: usize) -> Result<Extent> {
+    if depth >= MAX_NESTING_DEPTH {
+        return Result::Err(InvalidDecoding);
+    }
+    let end_marker = if type_code == sfield::STI_OBJECT {
+        OBJECT_END_MARKER
+    } else {
+        ARRAY_END_MARKER
+    };
+
+    let mut parser = BinaryParser::new(bytes);
+    loop {
+        if parser.is_empty() {
+            return Result::Err(InvalidDecoding);
+        }
+        let before = parser.position();
+        let field = parser.read_field_at_depth(depth + 1)?;
+        if field.field_code == end_marker {
+            return Result::Ok(Extent {
+                start: 0,
+                end: before,
+                consumed: parser.position(),
+            });
+        }
+        let is_misplaced_marker =
+            field.field_code == OBJECT_END_MARKER || field.field_code == ARRAY_END_MARKER;
+        // Array elements must be inner objects.
+        let is_invalid_element = type_code == sfield::STI_ARRAY
+            && super::type_code(field.field_code) != sfield::STI_OBJECT;
+        if is_misplaced_marker || is_invalid_element {
+            return Result::Err(InvalidDecoding);
+        }
+    }
+}
+
+/// Path step type flags of an `STPathSet`.
+const PATH_STEP_ACCOUNT: u8 = 0x01;
+const PATH_STEP_CURRENCY: u8 = 0x10;
+const PATH_STEP_ISSUER: u8 = 0x20;
+const PATH_STEP_MPT: u8 = 0x40;
+const PATH_BOUNDARY: u8 = 0xFF;
+const PATHSET_END: u8 = 0x00;
+
+fn pathset_extent(bytes: &[u8]) -> Result<Extent> {
+    let mut pos = 0;
+    loop {
+        let Some(&step_type) = bytes.get(pos) else {
+            return Result::Err(InvalidDecoding);
+        };
+        pos += 1;
+        match step_type {
+            PATHSET_END => {
+                return Result::Ok(Extent {
+                    start: 0,
+                    end: pos - 1,
+                    consumed: pos,
+                });
+            }
+            PATH_BOUNDARY => {}
+            _ => {
+                let known =
+                    PATH_STEP_ACCOUNT | PATH_STEP_CURRENCY | PATH_STEP_ISSUER | PATH_STEP_MPT;
+                if step_type & !known != 0 {
+                    return Result::Err(InvalidDecoding);
+                }
+                for (flag, len) in [
+                    (PATH_STEP_ACCOUNT, 20),
+                    (PATH_STEP_CURRENCY, 20),
+                    (PATH_STEP_ISSUER, 20),
+                    (PATH_STEP_MPT, 24),
+                ] {
+                    if step_type & flag != 0 {
+                        pos += len;
+                    }
+                }
+                if pos > bytes.len() {
+                    return Result::Err(InvalidDecoding);
+                }
+            }
+        }
+    }
+}
+
+/// An `XChainBridge` is a locking door account, locking issue, issuing door account and
+/// issuing issue, with both accounts VL-encoded.
+fn xchain_bridge_extent(bytes: &[u8]) -> Result<Extent> {
+    let mut pos = 0;
+    for _ in 0..2 {
+        pos += vl_extent(&bytes[pos..])?.consumed;
+        let len = issue_len(&bytes[pos..])?;
+        if pos + len > bytes.len() {
+            return Result::Err(InvalidDecoding);
+        }
+        pos += len;
+    }
+    Result::Ok(Extent {
+        start: 0,
+        end: pos,
+        consumed: pos,
+    })
+}
+
+impl<'a> Field<'a> {
+    /// The serialized type of this field (one of `sfield::STI_*`).
+    pub fn type_code(&self) -> i32 {
+        type_code(self.field_code)
+    }
+
+    fn expect_type(&self, type_code: i32) -> Result<&'a [u8]> {
+        if self.type_code() == type_code {
+            Result::Ok(self.value)
+        } else {
+            Result::Err(InvalidField)
+        }
+    }
+
+    fn fixed<const N: usize>(&self, type_code: i32) -> Result<[u8; N]> {
+        match self.expect_type(type_code)?.try_into() {
+            core::result::Result::Ok(bytes) => Result::Ok(bytes),
+            core::result::Result::Err(_) => Result::Err(InvalidDecoding),
+        }
+    }
+
+    pub fn as_u8(&self) -> Result<u8> {
+        Result::Ok(self.fixed::<1>(sfield::STI_UINT8)?[0])
+    }
+
+    pub fn as_u16(&self) -> Result<u16> {
+        Result::Ok(u16::from_be_bytes(self.fixed::<2>(sfield::STI_UINT16)?))
+    }
+
+    pub fn as_u32(&self) -> Result<u32> {
+        Result::Ok(u32::from_be_bytes(self.fixed::<4>(sfield::STI_UINT32)?))
+    }
+
+    pub fn as_u64(&self) -> Result<u64> {
+        Result::Ok(u64::from_be_bytes(self.fixed::<8>(sfield::STI_UINT64)?))
+    }
+
+    pub fn as_i32(&self) -> Result<i32> {
+        Result::Ok(i32::from_be_bytes(self.fixed::<4>(sfield::STI_INT32)?))
+    }
+
+    pub fn as_i64(&self) -> Result<i64> {
+        Result::Ok(i64::from_be_bytes(self.fixed::<8>(sfield::STI_INT64)?))
+    }
+
+    /// Decodes a `UInt96` through `UInt512` field (e.g. a `Hash256`) of exactly `N` bytes.
+    pub fn as_uint<const N: usize>(&self) -> Result<UInt<N>> {
+        let type_code = match N {
+            12 => sfield::STI_UINT96,
+            16 => sfield::STI_UINT128,
+            20 => sfield::STI_UINT160,
+            24 => sfield::STI_UINT192,
+            32 => sfield::STI_UINT256,
+            48 => sfield::STI_UINT384,
+            64 => sfield::STI_UINT512,
+            _ => return Result::Err(InvalidField),
+        };
+        Result::Ok(UInt(self.fixed::<N>(type_code)?))
+    }
+
+    pub fn as_account_id(&self) -> Result<AccountID> {
+        Result::Ok(AccountID(
+            self.fixed::<ACCOUNT_ID_SIZE>(sfield::STI_ACCOUNT)?,
+        ))
+    }
+
+    pub fn as_currency(&self) -> Result<Currency> {
+        Result::Ok(Currency(self.fixed::<CURRENCY_SIZE>(sfield::STI_CURRENCY)?))
+    }
+
+    /// Returns the contents of a VL (blob) field.
+    pub fn as_blob(&self) -> Result<&'a [u8]> {
+        self.expect_type(sfield::STI_VL)
+    }
+
+    pub fn as_amount(&self) -> Result<Amount> {
+        Amount::from_canonical_bytes(self.expect_type(sfield::STI_AMOUNT)?)
+    }
+
+    pub fn as_issue(&self) -> Result<Issue> {
+        Issue::from_stissue_bytes(self.expect_type(sfield::STI_ISSUE)?)
+    }
+
+    pub fn as_number(&self) -> Result<STNumber> {
+        Result::Ok(STNumber::from_bytes(
+            &self.fixed::<ST_NUMBER_SIZE>(sfield::STI_NUMBER)?,
+        ))
+    }
+
+    pub fn as_object(&self) -> Result<STObject<'a>> {
+        Result::Ok(STObject::new(self.expect_type(sfield::STI_OBJECT)?))
+    }
+
+    pub fn as_array(&self) -> Result<STArray<'a>> {
+        Result::Ok(STArray::new(self.expect_type(sfield::STI_ARRAY)?))
+    }
+
+    pub fn as_vector256(&self) -> Result<Vector256<'a>> {
+        let value = self.expect_type(sfield::STI_VECTOR256)?;
+        if value.len() % HASH256_SIZE != 0 {
+            return Result::Err(InvalidDecoding);
+        }
+        Result::Ok(Vector256 { data: value })
+    }
+}
+
+/// Iterator over the fields of an [`STObject`] or the elements of an [`STArray`].
+///
+/// Yields `Err` once if the data is malformed and then stops.
+#[derive(Debug, Clone)]
+pub struct FieldIter<'a> {
+    parser: BinaryParser<'a>,
+    failed: bool,
+}
+
+impl<'a> Iterator for FieldIter<'a> {
+    type Item = Result<Field<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.parser.is_empty() {
+            return None;
+        }
+        match self.parser.read_field() {
+            Result::Ok(field)
+                if field.field_code == OBJECT_END_MARKER
+                    || field.field_code == ARRAY_END_MARKER =>
+            {
+                self.failed = true;
+                Some(Result::Err(InvalidDecoding))
+            }
+            Result::Ok(field) => Some(Result::Ok(field)),
+            Result::Err(e) => {
+                self.failed = true;
+                Some(Result::Err(e))
+            }
+        }
+    }
+}
+
+/// A view over the fields of a serialized object (a whole transaction or ledger entry, or an
+/// inner object without its end marker).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct STObject<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> STObject<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    pub fn fields(&self) -> FieldIter<'a> {
+        FieldIter {
+            parser: BinaryParser::new(self.data),
+            failed: false,
+        }
+    }
+
+    /// Finds a field by its field code.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Field))` - If the field is present
+    /// * `Ok(None)` - If the field is not present
+    /// * `Err(Error::InvalidDecoding)` - If the object is malformed before the field is reached
+    pub fn get(&self, field_code: i32) -> Result<Option<Field<'a>>> {
+        for field in self.fields() {
+            let field = field?;
+            if field.field_code == field_code {
+                return Result::Ok(Some(field));
+            }
+        }
+        Result::Ok(None)
+    }
+}
+
+/// A view over the elements of a serialized array (without its end marker). Each element is an
+/// inner object field such as `Memo` or `SignerEntry`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct STArray<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> STArray<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    pub fn iter(&self) -> FieldIter<'a> {
+        FieldIter {
+            parser: BinaryParser::new(self.data),
+            failed: false,
+        }
+    }
+
+    /// Counts the elements of the array.
+    pub fn len(&self) -> Result<usize> {
+        let mut len = 0;
+        for element in self.iter() {
+            element?;
+            len += 1;
+        }
+        Result::Ok(len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the element at `index`, or `None` if the array is shorter.
+    pub fn get(&self, index: usize) -> Result<Option<Field<'a>>> {
+        match self.iter().nth(index) {
+            Some(Result::Ok(field)) => Result::Ok(Some(field)),
+            Some(Result::Err(e)) => Result::Err(e),
+            None => Result::Ok(None),
+        }
+    }
+}
+
+/// A view over a serialized `Vector256` (e.g. `Indexes` or `Amendments`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Vector256<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Vector256<'a> {
+    pub fn len(&self) -> usize {
+        self.data.len() / HASH256_SIZE
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Hash256> {
+        let start = index.checked_mul(HASH256_SIZE)?;
+        let bytes = self.data.get(start..start.checked_add(HASH256_SIZE)?)?;
+        let mut hash = [0u8; HASH256_SIZE];
+        hash.copy_from_slice(bytes);
+        Some(UInt(hash))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Hash256> + 'a {
+        self.data.chunks_exact(HASH256_SIZE).map(|chunk| {
+            let mut hash = [0u8; HASH256_SIZE];
+            hash.copy_from_slice(chunk);
+            UInt(hash)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A signed OfferCreate transaction from the XRPL serialization documentation.
+    const OFFER_CREATE: &str = "120007220008000024001ABED82A2380BF2C2019001ABED764D55920AC9391400000000000000000000000000055534400000000000A20B3C85F482532A9578DBB3950B85CA06594D165400000037E11D60068400000000000000A732103EE83BB432547885C219634A1BC407A9DB0474145D69737D09CCDC63E1DEE7FE3744630440220143759437C04F7B61F012563AFE90D8DAFC46E86035E1D965A9CED282C97D4CE02204CFD241E86F17E011298FC1A39B63386C74306A5DE047E213B0F29EFA4571C2C8114DD76483FACDEE26E60D8A586BB58D09F27045C46";
+
+    pub(crate) fn hex(input: &str, out: &mut [u8]) -> usize {
+        let bytes = input.as_bytes();
+        for (i, pair) in bytes.chunks(2).enumerate() {
+            let s = core::str::from_utf8(pair).unwrap();
+            out[i] = u8::from_str_radix(s, 16).unwrap();
+        }
+        bytes.len() / 2
+    }
+
+    #[test]
+    fn test_parse_offer_create() {
+        let mut buf = [0u8; 512];
+        let len = hex(OFFER_CREATE, &mut buf);
+        let tx = STObject::new(&buf[..len]);
+
+        let mut codes = [0i32; 11];
+        let mut count = 0;
+        for field in tx.fields() {
+            codes[count] = field.unwrap().field_code;
+            count += 1;
+        }
+        assert_eq!(
+            codes[..count],
+            [
+                sfield::TransactionType,
+                sfield::Flags,
+                sfield::Sequence,
+                sfield::Expiration,
+                sfield::OfferSequence,
+                sfield::TakerPays,
+                sfield::TakerGets,
+                sfield::Fee,
+                sfield::SigningPubKey,
+                sfield::TxnSignature,
+                sfield::Account,
+            ]
+        );
+
+        let field = |code| tx.get(code).unwrap().unwrap();
+        assert_eq!(field(sfield::TransactionType).as_u16().unwrap(), 7);
+        assert_eq!(field(sfield::Flags).as_u32().unwrap(), 0x0008_0000);
+        assert_eq!(field(sfield::Sequence).as_u32().unwrap(), 1_752_792);
+        assert_eq!(
+            field(sfield::Fee).as_amount().unwrap(),
+            Amount::XRP { num_drops: 10 }
+        );
+        assert_eq!(
+            field(sfield::TakerGets).as_amount().unwrap(),
+            Amount::XRP {
+                num_drops: 15_000_000_000
+            }
+        );
+        match field(sfield::TakerPays).as_amount().unwrap() {
+            Amount::IOU { currency, .. } => assert_eq!(currency, Currency::from(*b"USD")),
+            _ => panic!("Expected an IOU amount"),
+        }
+        assert_eq!(field(sfield::SigningPubKey).as_blob().unwrap().len(), 33);
+        assert_eq!(field(sfield::TxnSignature).as_blob().unwrap().len(), 70);
+        assert_eq!(
+            field(sfield::Account).as_account_id().unwrap().0[..4],
+            [0xDD, 0x76, 0x48, 0x3F]
+        );
+
+        // Type mismatches are rejected
+        assert!(field(sfield::Flags).as_u16().is_err());
+        assert!(tx.get(sfield::Memos).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parse_nested_memos() {
+        // Memos: [ Memo { MemoType: "ab", MemoData: "cdef" }, Memo { MemoData: "" } ]
+        let data = [
+            0xF9, // Memos
+            0xEA, // Memo
+            0x7C, 0x01, 0xAB, // MemoType
+            0x7D, 0x02, 0xCD, 0xEF, // MemoData
+            0xE1, // ObjectEndMarker
+            0xEA, // Memo
+            0x7D, 0x00, // MemoData
+            0xE1, // ObjectEndMarker
+            0xF1, // ArrayEndMarker
+            0x24, 0x00, 0x00, 0x00, 0x05, // Sequence
+        ];
+        let tx = STObject::new(&data);
+        let memos = tx.get(sfield::Memos).unwrap().unwrap().as_array().unwrap();
+        assert_eq!(memos.len().unwrap(), 2);
+
+        let memo = memos.get(0).unwrap().unwrap();
+        assert_eq!(memo.field_code, sfield::Memo);
+        let memo = memo.as_object().unwrap();
+        let memo_data = memo.get(sfield::MemoData).unwrap().unwrap();
+        assert_eq!(memo_data.as_blob().unwrap(), &[0xCD, 0xEF]);
+
+        let memo = memos.get(1).unwrap().unwrap().as_object().unwrap();
+        assert!(memo.get(sfield::MemoType).unwrap().is_none());
+        assert!(memos.get(2).unwrap().is_none());
+
+        let sequence = tx.get(sfield::Sequence).unwrap().unwrap();
+        assert_eq!(sequence.as_u32().unwrap(), 5);
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed() {
+        // Unterminated array
+        let tx = STObject::new(&[0xF9, 0xEA, 0xE1]);
+        assert!(tx.get(sfield::Sequence).is_err());
+
+        // Truncated UInt32
+        let tx = STObject::new(&[0x24, 0x00, 0x00]);
+        assert!(tx.get(sfield::Sequence).is_err());
+
+        // Array elements must be objects
+        let tx = STObject::new(&[0xF9, 0x24, 0, 0, 0, 1, 0xF1]);
+        assert!(tx.get(sfield::Memos).is_err());
+
+        // Stray end marker at the top level
+        let tx = STObject::new(&[0xE1]);
+        assert!(tx.get(sfield::Sequence).is_err());
+
+        // Too deeply nested
+        let mut deep = [0u8; 2 * (MAX_NESTING_DEPTH + 1)];
+        for i in 0..=MAX_NESTING_DEPTH {
+            deep[i] = 0xEA;
+            deep[2 * MAX_NESTING_DEPTH + 1 - i] = 0xE1;
+        }
+        assert!(STObject::new(&deep).get(sfield::Sequence).is_err());
+    }
+
+    #[test]
+    fn test_parse_issue_and_vector256() {
+        let issuer = [7u8; 20];
+        let mut mpt_issue = [0u8; 44];
+        mpt_issue[..20].copy_from_slice(&issuer);
+        mpt_issue[20..40].copy_from_slice(&NO_ACCOUNT);
+        // The sequence is little-endian in an STIssue
+        mpt_issue[40..].copy_from_slice(&42u32.to_le_bytes());
+        match Issue::from_stissue_bytes(&mpt_issue).unwrap() {
+            Issue::MPT(mpt) => {
+                assert_eq!(mpt.mpt_id().get_sequence_num(), 42);
+                assert_eq!(mpt.mpt_id().get_issuer(), AccountID(issuer));
+            }
+            _ => panic!("Expected an MPT issue"),
+        }
+        assert_eq!(issue_len(&mpt_issue).unwrap(), 44);
+        assert_eq!(issue_len(&[0u8; 20]).unwrap(), 20);
+        assert!(matches!(
+            Issue::from_stissue_bytes(&[0u8; 20]).unwrap(),
+            Issue::XRP(_)
+        ));
+
+        let mut data = [0u8; 2 + 1 + 64];
+        // Indexes: type 19, ordinal 1
+        data[0] = 0x01;
+        data[1] = 0x13;
+        data[2] = 64;
+        data[3 + 32] = 0xFF;
+        let tx = STObject::new(&data);
+        let indexes = tx.get(sfield::Indexes).unwrap().unwrap();
+        let indexes = indexes.as_vector256().unwrap();
+        assert_eq!(indexes.len(), 2);
+        assert_eq!(indexes.get(1).unwrap().0[0], 0xFF);
+        assert!(indexes.get(2).is_none());
+        assert!(indexes.get(usize::MAX / HASH256_SIZE).is_none());
+        assert_eq!(indexes.iter().count(), 2);
+    }
+}
@@ -0,0 +1,277 @@
+//! Writing canonical XRPL binary data into a caller-provided buffer.
+//!
+//! The [`Serializer`] does not sort fields: callers must write them in canonical order (ascending
+//! by type code, then by ordinal), exactly as rippled would, for the output to hash or verify
+//! correctly.
+
+use super::field_header::{encode_field_header, encode_vl_length};
+use super::{ARRAY_END_MARKER, OBJECT_END_MARKER, STNumber};
+use crate::core::types::account_id::AccountID;
+use crate::core::types::amount::Amount;
+use crate::core::types::currency::Currency;
+use crate::core::types::issue::Issue;
+use crate::core::types::uint::{Hash256, UInt};
+use crate::host::Error::BufferTooSmall;
+use crate::host::Result;
+
+/// Writes fields into a fixed buffer.
+///
+/// Every write either succeeds completely or fails with `Error::BufferTooSmall` and leaves the
+/// buffer's written length unchanged.
+#[derive(Debug)]
+pub struct Serializer<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> Serializer<'a> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// The number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The bytes written so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Appends raw bytes without any header or prefix.
+    pub fn write_raw(&mut self, bytes: &[u8]) -> Result<()> {
+        let end = self.len + bytes.len();
+        if end > self.buf.len() {
+            return Result::Err(BufferTooSmall);
+        }
+        self.buf[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+        Result::Ok(())
+    }
+
+    /// Appends the field header for `field_code`.
+    pub fn write_field_header(&mut self, field_code: i32) -> Result<()> {
+        let (header, header_len) = encode_field_header(field_code)?;
+        self.write_raw(&header[..header_len])
+    }
+
+    /// Appends a field header followed by `parts`, rolling back if anything does not fit.
+    fn write_field(&mut self, field_code: i32, parts: &[&[u8]]) -> Result<()> {
+        let start = self.len;
+        let mut result = self.write_field_header(field_code);
+        for part in parts {
+            if result.is_err() {
+                break;
+            }
+            result = self.write_raw(part);
+        }
+        if result.is_err() {
+            self.len = start;
+        }
+        result
+    }
+
+    /// Appends a field header, a VL prefix and `value`.
+    fn write_vl_field(&mut self, field_code: i32, value: &[u8]) -> Result<()> {
+        let (prefix, prefix_len) = encode_vl_length(value.len())?;
+        self.write_field(field_code, &[&prefix[..prefix_len], value])
+    }
+
+    pub fn write_u8(&mut self, field_code: i32, value: u8) -> Result<()> {
+        self.write_field(field_code, &[&[value]])
+    }
+
+    pub fn write_u16(&mut self, field_code: i32, value: u16) -> Result<()> {
+        self.write_field(field_code, &[&value.to_be_bytes()])
+    }
+
+    pub fn write_u32(&mut self, field_code: i32, value: u32) -> Result<()> {
+        self.write_field(field_code, &[&value.to_be_bytes()])
+    }
+
+    pub fn write_u64(&mut self, field_code: i32, value: u64) -> Result<()> {
+        self.write_field(field_code, &[&value.to_be_bytes()])
+    }
+
+    pub fn write_i32(&mut self, field_code: i32, value: i32) -> Result<()> {
+        self.write_field(field_code, &[&value.to_be_bytes()])
+    }
+
+    pub fn write_i64(&mut self, field_code: i32, value: i64) -> Result<()> {
+        self.write_field(field_code, &[&value.to_be_bytes()])
+    }
+
+    /// Writes a `UInt96` through `UInt512` field (e.g. a `Hash256`).
+    pub fn write_uint<const N: usize>(&mut self, field_code: i32, value: &UInt<N>) -> Result<()> {
+        self.write_field(field_code, &[value.as_bytes()])
+    }
+
+    /// Writes an account field (VL-encoded, like rippled's `STAccount`).
+    pub fn write_account(&mut self, field_code: i32, value: &AccountID) -> Result<()> {
+        self.write_vl_field(field_code, &value.0)
+    }
+
+    /// Writes a VL (blob) field.
+    pub fn write_blob(&mut self, field_code: i32, value: &[u8]) -> Result<()> {
+        self.write_vl_field(field_code, value)
+    }
+
+    /// Writes an amount in its canonical 8-byte (XRP), 33-byte (MPT) or 48-byte (IOU) form.
+    pub fn write_amount(&mut self, field_code: i32, value: &Amount) -> Result<()> {
+        let (bytes, len) = value.to_canonical_bytes()?;
+        self.write_field(field_code, &[&bytes[..len]])
+    }
+
+    pub fn write_currency(&mut self, field_code: i32, value: &Currency) -> Result<()> {
+        self.write_field(field_code, &[value.as_bytes()])
+    }
+
+    /// Writes an `STIssue`: 20 zero bytes for XRP, currency and issuer for an IOU, and issuer,
+    /// the no-account marker and sequence for an MPT.
+    pub fn write_issue(&mut self, field_code: i32, value: &Issue) -> Result<()> {
+        let (bytes, len) = value.to_stissue_bytes();
+        self.write_field(field_code, &[&bytes[..len]])
+    }
+
+    pub fn write_number(&mut self, field_code: i32, value: &STNumber) -> Result<()> {
+        self.write_field(field_code, &[&value.to_bytes()])
+    }
+
+    /// Writes a `Vector256` field (VL-encoded list of hashes).
+    pub fn write_vector256(&mut self, field_code: i32, values: &[Hash256]) -> Result<()> {
+        let (prefix, prefix_len) = encode_vl_length(values.len() * 32)?;
+        let start = self.len;
+        let mut result = self.write_field(field_code, &[&prefix[..prefix_len]]);
+        for value in values {
+            if result.is_err() {
+                break;
+            }
+            result = self.write_raw(value.as_bytes());
+        }
+        if result.is_err() {
+            self.len = start;
+        }
+        result
+    }
+
+    /// Starts an inner object field (e.g. `Memo`); finish it with [`Serializer::end_object`].
+    pub fn begin_object(&mut self, field_code: i32) -> Result<()> {
+        self.write_field_header(field_code)
+    }
+
+    pub fn end_object(&mut self) -> Result<()> {
+        self.write_field_header(OBJECT_END_MARKER)
+    }
+
+    /// Starts an array field (e.g. `Memos`); finish it with [`Serializer::end_array`].
+    pub fn begin_array(&mut self, field_code: i32) -> Result<()> {
+        self.write_field_header(field_code)
+    }
+
+    pub fn end_array(&mut self) -> Result<()> {
+        self.write_field_header(ARRAY_END_MARKER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::codec::parser::STObject;
+    use crate::core::types::issue::{IouIssue, MptIssue, XrpIssue};
+    use crate::core::types::mpt_id::MptId;
+    use crate::host::Error;
+    use crate::sfield;
+
+    #[test]
+    fn test_roundtrip_payment() {
+        let account = AccountID([1u8; 20]);
+        let destination = AccountID([2u8; 20]);
+        let mut buf = [0u8; 256];
+        let mut s = Serializer::new(&mut buf);
+        s.write_u16(sfield::TransactionType, 0).unwrap();
+        s.write_u32(sfield::Sequence, 7).unwrap();
+        s.write_amount(sfield::Amount, &Amount::XRP { num_drops: 1_000 })
+            .unwrap();
+        s.write_amount(sfield::Fee, &Amount::XRP { num_drops: 12 })
+            .unwrap();
+        s.write_blob(sfield::SigningPubKey, &[0xED; 33]).unwrap();
+        s.write_account(sfield::Account, &account).unwrap();
+        s.write_account(sfield::Destination, &destination).unwrap();
+        s.begin_array(sfield::Memos).unwrap();
+        s.begin_object(sfield::Memo).unwrap();
+        s.write_blob(sfield::MemoData, b"hi").unwrap();
+        s.end_object().unwrap();
+        s.end_array().unwrap();
+
+        // TransactionType, Sequence and the XRP Amount use the shortest encodings
+        assert_eq!(&s.as_bytes()[..3], &[0x12, 0x00, 0x00]);
+        assert_eq!(
+            &s.as_bytes()[8..17],
+            &[0x61, 0x40, 0, 0, 0, 0, 0, 0x03, 0xE8]
+        );
+
+        let tx = STObject::new(s.as_bytes());
+        let field = |code| tx.get(code).unwrap().unwrap();
+        assert_eq!(field(sfield::Sequence).as_u32().unwrap(), 7);
+        assert_eq!(
+            field(sfield::Amount).as_amount().unwrap(),
+            Amount::XRP { num_drops: 1_000 }
+        );
+        assert_eq!(field(sfield::Account).as_account_id().unwrap(), account);
+        assert_eq!(
+            field(sfield::Destination).as_account_id().unwrap(),
+            destination
+        );
+        let memo = field(sfield::Memos)
+            .as_array()
+            .unwrap()
+            .get(0)
+            .unwrap()
+            .unwrap()
+            .as_object()
+            .unwrap();
+        let memo_data = memo.get(sfield::MemoData).unwrap().unwrap();
+        assert_eq!(memo_data.as_blob().unwrap(), b"hi");
+    }
+
+    #[test]
+    fn test_roundtrip_issues() {
+        let issuer = AccountID([9u8; 20]);
+        let issues = [
+            Issue::XRP(XrpIssue {}),
+            Issue::IOU(IouIssue::new(issuer, Currency::from(*b"USD"))),
+            Issue::MPT(MptIssue::new(MptId::new(5, issuer))),
+        ];
+        for (issue, encoded_len) in issues.iter().zip([20, 40, 44]) {
+            let mut buf = [0u8; 64];
+            let mut s = Serializer::new(&mut buf);
+            s.write_issue(sfield::Asset, issue).unwrap();
+            // Asset (type 24, ordinal 3) has a 2-byte header: 0x03 then the type byte
+            assert_eq!(s.len(), 2 + encoded_len);
+
+            let field = STObject::new(s.as_bytes())
+                .get(sfield::Asset)
+                .unwrap()
+                .unwrap();
+            assert_eq!(field.as_issue().unwrap().as_bytes(), issue.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_buffer_too_small_leaves_length_unchanged() {
+        let mut buf = [0u8; 8];
+        let mut s = Serializer::new(&mut buf);
+        s.write_u32(sfield::Sequence, 1).unwrap();
+        let error = s.write_u32(sfield::Flags, 2).err().unwrap();
+        assert_eq!(error.code(), Error::BufferTooSmall.code());
+        assert_eq!(s.len(), 5);
+
+        let error = s.write_field_header(sfield::Generic).err().unwrap();
+        assert_eq!(error.code(), Error::InvalidField.code());
+    }
+}
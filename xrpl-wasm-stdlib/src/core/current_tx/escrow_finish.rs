@@ -81,6 +81,7 @@ impl EscrowFinishFields for EscrowFinish {}
 /// ```no_run
 /// use xrpl_wasm_stdlib::core::current_tx::escrow_finish::EscrowFinish;
 /// use xrpl_wasm_stdlib::core::current_tx::traits::{TransactionCommonFields, EscrowFinishFields};
+/// use xrpl_wasm_stdlib::host::ResultExt;
 /// let tx = EscrowFinish;
 /// let owner = tx.get_owner().unwrap_or_panic();
 /// let offer_seq = tx.get_offer_sequence().unwrap_or_panic();
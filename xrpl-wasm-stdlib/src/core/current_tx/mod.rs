@@ -53,6 +53,7 @@
 //! ```no_run
 //! use xrpl_wasm_stdlib::core::current_tx::escrow_finish::EscrowFinish;
 //! use xrpl_wasm_stdlib::core::current_tx::traits::TransactionCommonFields;
+//! use xrpl_wasm_stdlib::host::ResultExt;
 //! let tx = EscrowFinish;
 //! let account = tx.get_account().unwrap_or_panic();
 //! let _flags = tx.get_flags().unwrap_or_panic();
@@ -61,10 +62,13 @@
 pub mod escrow_finish;
 pub mod traits;
 
+use crate::core::locator::Locator;
+use crate::core::types::array::{ArrayView, CurrentTxRoot};
 use crate::host::error_codes::{
-    match_result_code_with_expected_bytes, match_result_code_with_expected_bytes_optional,
+    match_result_code, match_result_code_with_expected_bytes,
+    match_result_code_with_expected_bytes_optional,
 };
-use crate::host::{Result, get_tx_field};
+use crate::host::{Result, get_tx_field, get_tx_nested_array_len, get_tx_nested_field};
 
 /// Trait for types that can be retrieved from current transaction fields.
 ///
@@ -154,6 +158,34 @@ pub trait CurrentTxFieldGetter: Sized {
     /// * `Ok(None)` - If the field is not present in the transaction
     /// * `Err(Error)` - If the field cannot be retrieved or has unexpected size
     fn get_from_current_tx_optional(field_code: i32) -> Result<Option<Self>>;
+
+    /// Get a required nested field from the current transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `locator` - The locator identifying the nested field (e.g. `Memos[0].MemoData`)
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Self>` where:
+    /// * `Ok(Self)` - The field value at the specified location
+    /// * `Err(Error::FieldNotFound)` - If the field is not present in the transaction
+    /// * `Err(Error)` - If the field cannot be retrieved or has unexpected size
+    fn get_from_current_tx_nested(locator: &Locator) -> Result<Self>;
+
+    /// Get an optional nested field from the current transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `locator` - The locator identifying the nested field (e.g. `Memos[0].MemoData`)
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<Self>>` where:
+    /// * `Ok(Some(Self))` - The field value at the specified location
+    /// * `Ok(None)` - If the field is not present in the transaction
+    /// * `Err(Error)` - If the field cannot be retrieved or has unexpected size
+    fn get_from_current_tx_nested_optional(locator: &Locator) -> Result<Option<Self>>;
 }
 
 /// Trait for types that can be retrieved as fixed-size fields from transactions.
@@ -220,6 +252,38 @@ impl<T: FixedSizeFieldType> CurrentTxFieldGetter for T {
             Some(unsafe { value.assume_init() })
         })
     }
+
+    #[inline]
+    fn get_from_current_tx_nested(locator: &Locator) -> Result<Self> {
+        let mut value = core::mem::MaybeUninit::<T>::uninit();
+        let result_code = unsafe {
+            get_tx_nested_field(
+                locator.as_ptr(),
+                locator.len(),
+                value.as_mut_ptr().cast(),
+                T::SIZE,
+            )
+        };
+        match_result_code_with_expected_bytes(result_code, T::SIZE, || unsafe {
+            value.assume_init()
+        })
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        let mut value = core::mem::MaybeUninit::<T>::uninit();
+        let result_code = unsafe {
+            get_tx_nested_field(
+                locator.as_ptr(),
+                locator.len(),
+                value.as_mut_ptr().cast(),
+                T::SIZE,
+            )
+        };
+        match_result_code_with_expected_bytes_optional(result_code, T::SIZE, || {
+            Some(unsafe { value.assume_init() })
+        })
+    }
 }
 
 /// Retrieves a field from the current transaction.
@@ -254,3 +318,121 @@ pub fn get_field<T: CurrentTxFieldGetter>(field_code: i32) -> Result<T> {
 pub fn get_field_optional<T: CurrentTxFieldGetter>(field_code: i32) -> Result<Option<T>> {
     T::get_from_current_tx_optional(field_code)
 }
+
+/// Retrieves a nested field from the current transaction.
+///
+/// # Arguments
+///
+/// * `locator` - The locator identifying the nested field (e.g. `Memos[0].MemoData`)
+///
+/// # Returns
+///
+/// Returns a `Result<T>` where:
+/// * `Ok(T)` - The field value at the specified location
+/// * `Err(Error)` - If the field cannot be retrieved or has unexpected size
+///
+/// # Example
+///
+/// ```no_run
+/// use xrpl_wasm_stdlib::core::current_tx::get_nested_field;
+/// use xrpl_wasm_stdlib::core::types::blob::MemoBlob;
+/// use xrpl_wasm_stdlib::locator;
+/// let memo_data: MemoBlob = get_nested_field(&locator!(Memos[0].MemoData)).unwrap();
+/// ```
+#[inline]
+pub fn get_nested_field<T: CurrentTxFieldGetter>(locator: &Locator) -> Result<T> {
+    T::get_from_current_tx_nested(locator)
+}
+
+/// Retrieves an optionally present nested field from the current transaction.
+///
+/// # Arguments
+///
+/// * `locator` - The locator identifying the nested field (e.g. `Memos[0].MemoData`)
+///
+/// # Returns
+///
+/// Returns a `Result<Option<T>>` where:
+/// * `Ok(Some(T))` - The field value at the specified location
+/// * `Ok(None)` - If the field is not present
+/// * `Err(Error)` - If the field cannot be retrieved or has unexpected size
+#[inline]
+pub fn get_nested_field_optional<T: CurrentTxFieldGetter>(locator: &Locator) -> Result<Option<T>> {
+    T::get_from_current_tx_nested_optional(locator)
+}
+
+/// Retrieves the number of elements in a nested array of the current transaction.
+///
+/// # Arguments
+///
+/// * `locator` - The locator identifying the nested array
+///
+/// # Returns
+///
+/// Returns a `Result<usize>` where:
+/// * `Ok(usize)` - The number of elements in the array
+/// * `Err(Error)` - If the locator does not point at an array or the length cannot be read
+#[inline]
+pub fn get_nested_array_len(locator: &Locator) -> Result<usize> {
+    let result_code = unsafe { get_tx_nested_array_len(locator.as_ptr(), locator.len()) };
+    match_result_code(result_code, || result_code as usize)
+}
+
+/// Opens an array field of the current transaction (e.g. `Memos` or `Signers`).
+///
+/// # Arguments
+///
+/// * `field_code` - The field code of the array
+///
+/// # Returns
+///
+/// Returns a `Result<ArrayView<CurrentTxRoot>>` where:
+/// * `Ok(ArrayView)` - A view over the array's elements
+/// * `Err(Error)` - If the array is not present or the field is not an array
+#[inline]
+pub fn get_array(field_code: i32) -> Result<ArrayView<CurrentTxRoot>> {
+    ArrayView::new(CurrentTxRoot, Locator::from_path(&[field_code]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::account_id::AccountID;
+    use crate::core::types::blob::MemoBlob;
+    use crate::core::types::public_key::PublicKey;
+    use crate::host::set_nested_field;
+    use crate::locator;
+
+    #[test]
+    fn test_nested_field_getters() {
+        let memo_data = locator!(Memos[0].MemoData);
+        let weight = locator!(Signers[0].SignerWeight);
+        let account = locator!(Signers[0].Account);
+        let signing_key = locator!(Signers[0].SigningPubKey);
+
+        set_nested_field(&memo_data, b"hello");
+        set_nested_field(&weight, &3u16.to_ne_bytes());
+        set_nested_field(&account, &[7; 20]);
+        set_nested_field(&signing_key, &[0xED; 33]);
+
+        let memo: MemoBlob = get_nested_field(&memo_data).unwrap();
+        assert_eq!(memo.as_slice(), b"hello");
+        assert_eq!(get_nested_field::<u16>(&weight).unwrap(), 3);
+        assert_eq!(
+            get_nested_field::<AccountID>(&account).unwrap(),
+            AccountID([7; 20])
+        );
+        assert_eq!(
+            get_nested_field::<PublicKey>(&signing_key).unwrap(),
+            PublicKey([0xED; 33])
+        );
+
+        let weight = get_nested_field_optional::<u16>(&weight).unwrap();
+        assert_eq!(weight, Some(3));
+    }
+
+    #[test]
+    fn test_nested_array_len() {
+        assert_eq!(get_nested_array_len(&locator!(Memos)).unwrap(), 0);
+    }
+}
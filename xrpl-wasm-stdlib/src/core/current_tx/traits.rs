@@ -38,7 +38,6 @@
 //! - **PublicKey**: 33-byte compressed public keys
 //! - **TransactionType**: Enumerated transaction type identifiers
 
-use crate::core::current_tx::{get_field, get_field_optional};
 use crate::core::types::account_id::AccountID;
 use crate::core::types::amount::Amount;
 use crate::core::types::blob::{
@@ -50,6 +49,7 @@ use crate::core::types::uint::Hash256;
 use crate::host::error_codes::match_result_code_optional;
 use crate::host::{Error, Result, get_tx_field};
 use crate::sfield;
+use crate::xrpl_object;
 
 /// Trait providing access to common fields present in all XRPL transactions.
 ///
@@ -58,6 +58,7 @@ use crate::sfield;
 /// Types implementing this trait should ensure they are used only in the context of a valid
 /// XRPL transaction. The trait methods assume the current transaction context is properly
 /// established by the XRPL Programmability environment.
+#[xrpl_object(TransactionType)]
 pub trait TransactionCommonFields {
     /// Retrieves the account field from the current transaction.
     ///
@@ -69,9 +70,8 @@ pub trait TransactionCommonFields {
     /// Returns a `Result<AccountID>` where:
     /// * `Ok(AccountID)` - The 20-byte account identifier of the transaction sender
     /// * `Err(Error)` - If the field cannot be retrieved or has an unexpected size
-    fn get_account(&self) -> Result<AccountID> {
-        get_field(sfield::Account)
-    }
+    #[field(Account)]
+    fn get_account(&self) -> Result<AccountID>;
 
     /// Retrieves the transaction type from the current transaction.
     ///
@@ -84,9 +84,8 @@ pub trait TransactionCommonFields {
     /// * `Ok(TransactionType)` - An enumerated value representing the transaction type
     /// * `Err(Error)` - If the field cannot be retrieved or has an unexpected size
     ///
-    fn get_transaction_type(&self) -> Result<TransactionType> {
-        get_field(sfield::TransactionType)
-    }
+    #[field(TransactionType)]
+    fn get_transaction_type(&self) -> Result<TransactionType>;
 
     /// Retrieves the computation allowance from the current transaction.
     ///
@@ -99,9 +98,8 @@ pub trait TransactionCommonFields {
     /// Returns a `Result<u32>` where:
     /// * `Ok(u32)` - The computation allowance value in platform-defined units
     /// * `Err(Error)` - If the field cannot be retrieved or has an unexpected size
-    fn get_computation_allowance(&self) -> Result<u32> {
-        get_field(sfield::ComputationAllowance)
-    }
+    #[field(ComputationAllowance)]
+    fn get_computation_allowance(&self) -> Result<u32>;
 
     /// Retrieves the fee amount from the current transaction.
     ///
@@ -120,9 +118,8 @@ pub trait TransactionCommonFields {
     ///
     /// Returns XRP amounts only (for now). Future versions may support other token types
     /// when the underlying amount handling is enhanced.
-    fn get_fee(&self) -> Result<Amount> {
-        get_field(sfield::Fee)
-    }
+    #[field(Fee)]
+    fn get_fee(&self) -> Result<Amount>;
 
     /// Retrieves the sequence number from the current transaction.
     ///
@@ -141,9 +138,8 @@ pub trait TransactionCommonFields {
     ///
     /// If the transaction uses tickets instead of sequence numbers, this field may not
     /// be present. In such cases, use `get_ticket_sequence()` instead.
-    fn get_sequence(&self) -> Result<u32> {
-        get_field(sfield::Sequence)
-    }
+    #[field(Sequence)]
+    fn get_sequence(&self) -> Result<u32>;
 
     /// Retrieves the account transaction ID from the current transaction.
     ///
@@ -157,9 +153,8 @@ pub trait TransactionCommonFields {
     /// * `Ok(Some(Hash256))` - The hash of the required previous transaction
     /// * `Ok(None)` - If no previous transaction requirement is specified
     /// * `Err(Error)` - If an error occurred during field retrieval
-    fn get_account_txn_id(&self) -> Result<Option<Hash256>> {
-        get_field_optional(sfield::AccountTxnID)
-    }
+    #[field(AccountTxnID)]
+    fn get_account_txn_id(&self) -> Result<Option<Hash256>>;
 
     /// Retrieves the `flags` field from the current transaction.
     ///
@@ -172,9 +167,8 @@ pub trait TransactionCommonFields {
     /// * `Ok(Some(u32))` - The flags bitfield if present
     /// * `Ok(None)` - If no flags are specified (equivalent to flags = 0)
     /// * `Err(Error)` - If an error occurred during field retrieval
-    fn get_flags(&self) -> Result<Option<u32>> {
-        get_field_optional(sfield::Flags)
-    }
+    #[field(Flags)]
+    fn get_flags(&self) -> Result<Option<u32>>;
 
     /// Retrieves the last ledger sequence from the current transaction.
     ///
@@ -188,9 +182,8 @@ pub trait TransactionCommonFields {
     /// * `Ok(Some(u32))` - The maximum ledger index for transaction inclusion
     /// * `Ok(None)` - If no expiration is specified (transaction never expires)
     /// * `Err(Error)` - If an error occurred during field retrieval
-    fn get_last_ledger_sequence(&self) -> Result<Option<u32>> {
-        get_field_optional(sfield::LastLedgerSequence)
-    }
+    #[field(LastLedgerSequence)]
+    fn get_last_ledger_sequence(&self) -> Result<Option<u32>>;
 
     /// Retrieves the network ID from the current transaction.
     ///
@@ -204,9 +197,8 @@ pub trait TransactionCommonFields {
     /// * `Ok(Some(u32))` - The network identifier
     /// * `Ok(None)` - If no specific network is specified (uses default network)
     /// * `Err(Error)` - If an error occurred during field retrieval
-    fn get_network_id(&self) -> Result<Option<u32>> {
-        get_field_optional(sfield::NetworkID)
-    }
+    #[field(NetworkID)]
+    fn get_network_id(&self) -> Result<Option<u32>>;
 
     /// Retrieves the source tag from the current transaction.
     ///
@@ -220,9 +212,8 @@ pub trait TransactionCommonFields {
     /// * `Ok(Some(u32))` - The source tag identifier
     /// * `Ok(None)` - If no source tag is specified
     /// * `Err(Error)` - If an error occurred during field retrieval
-    fn get_source_tag(&self) -> Result<Option<u32>> {
-        get_field_optional(sfield::SourceTag)
-    }
+    #[field(SourceTag)]
+    fn get_source_tag(&self) -> Result<Option<u32>>;
 
     /// Retrieves the signing public key from the current transaction.
     ///
@@ -241,9 +232,8 @@ pub trait TransactionCommonFields {
     /// The presence of this field doesn't guarantee the signature is valid. Instead, this field
     /// only provides the key claimed to be used for signing. The XRPL network performs signature
     /// validation before transaction execution.
-    fn get_signing_pub_key(&self) -> Result<PublicKey> {
-        get_field(sfield::SigningPubKey)
-    }
+    #[field(SigningPubKey)]
+    fn get_signing_pub_key(&self) -> Result<PublicKey>;
 
     /// Retrieves the ticket sequence from the current transaction.
     ///
@@ -261,9 +251,8 @@ pub trait TransactionCommonFields {
     ///
     /// Transactions use either `Sequence` or `TicketSequence`, but not both. Check this
     /// field when `get_sequence()` fails or when implementing ticket-aware logic.
-    fn get_ticket_sequence(&self) -> Result<Option<u32>> {
-        get_field_optional(sfield::TicketSequence)
-    }
+    #[field(TicketSequence)]
+    fn get_ticket_sequence(&self) -> Result<Option<u32>>;
 
     /// Retrieves the transaction signature from the current transaction.
     ///
@@ -285,9 +274,8 @@ pub trait TransactionCommonFields {
     /// The signature is validated by the XRPL network before transaction execution.
     /// In the programmability context, you can access the signature for logging or
     /// analysis purposes, but signature validation has already been performed.
-    fn get_txn_signature(&self) -> Result<SignatureBlob> {
-        get_field(sfield::TxnSignature)
-    }
+    #[field(TxnSignature)]
+    fn get_txn_signature(&self) -> Result<SignatureBlob>;
 }
 
 /// Trait providing access to fields specific to EscrowFinish transactions.
@@ -303,6 +291,7 @@ pub trait TransactionCommonFields {
 /// - Also implement `TransactionCommonFields` for access to common transaction fields
 /// - Only be used in the context of processing EscrowFinish transactions
 /// - Ensure proper error handling when accessing conditional fields
+#[xrpl_object(TransactionType::EscrowFinish)]
 pub trait EscrowFinishFields: TransactionCommonFields {
     /// Retrieves the owner account from the current EscrowFinish transaction.
     ///
@@ -315,9 +304,8 @@ pub trait EscrowFinishFields: TransactionCommonFields {
     /// Returns a `Result<AccountID>` where:
     /// * `Ok(AccountID)` - The 20-byte account identifier of the escrow owner
     /// * `Err(Error)` - If the field cannot be retrieved or has an unexpected size
-    fn get_owner(&self) -> Result<AccountID> {
-        get_field(sfield::Owner)
-    }
+    #[field(Owner)]
+    fn get_owner(&self) -> Result<AccountID>;
 
     /// Retrieves the offer sequence from the current EscrowFinish transaction.
     ///
@@ -331,9 +319,8 @@ pub trait EscrowFinishFields: TransactionCommonFields {
     /// Returns a `Result<u32>` where:
     /// * `Ok(u32)` - The sequence number of the EscrowCreate transaction
     /// * `Err(Error)` - If the field cannot be retrieved or has an unexpected size
-    fn get_offer_sequence(&self) -> Result<u32> {
-        get_field(sfield::OfferSequence)
-    }
+    #[field(OfferSequence)]
+    fn get_offer_sequence(&self) -> Result<u32>;
 
     /// Retrieves the cryptographic condition from the current EscrowFinish transaction.
     ///
@@ -0,0 +1,370 @@
+//! A bounded key-value map stored in an escrow's `Data` field.
+//!
+//! Entries are stored back to back as `key-length key value-length value`, with both lengths
+//! VL-encoded (one byte for lengths up to 192), so small maps of flags, counters or prices use
+//! only a few bytes more than their contents. Keys are non-empty byte strings; values may be empty.
+//!
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::kv_store::KvStore;
+//! use xrpl_wasm_stdlib::host::ResultExt;
+//!
+//! let mut store = KvStore::load().unwrap_or_panic();
+//! let count = match store.get(b"approvals") {
+//!     Some(bytes) if bytes.len() == 4 => u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+//!     _ => 0,
+//! };
+//! store.set(b"approvals", &(count + 1).to_be_bytes()).unwrap_or_panic();
+//! store.store().unwrap_or_panic();
+//! ```
+
+use crate::core::codec::field_header::{decode_vl_length, encode_vl_length};
+use crate::core::ledger_objects::current_escrow::{CurrentEscrow, get_current_escrow};
+use crate::core::ledger_objects::traits::CurrentEscrowFields;
+use crate::core::types::contract_data::{ContractData, XRPL_CONTRACT_DATA_SIZE};
+use crate::host::Error::{
+    DataFieldTooLarge, FieldNotFound, InvalidDecoding, InvalidParams, KvStoreFull,
+};
+use crate::host::Result;
+
+/// A key-value map backed by a [`ContractData`] buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KvStore {
+    data: ContractData,
+}
+
+/// The location of one entry within the buffer.
+struct Entry {
+    start: usize,
+    key: (usize, usize),
+    value: (usize, usize),
+    end: usize,
+}
+
+impl KvStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        KvStore {
+            data: ContractData {
+                data: [0; XRPL_CONTRACT_DATA_SIZE],
+                len: 0,
+            },
+        }
+    }
+
+    /// Wraps existing contract data, validating that it is a well-formed store.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(KvStore)` - If every entry parses
+    /// * `Err(Error::InvalidDecoding)` - If the data is truncated or contains an empty key
+    pub fn from_contract_data(data: ContractData) -> Result<Self> {
+        if data.len > XRPL_CONTRACT_DATA_SIZE {
+            return Result::Err(InvalidDecoding);
+        }
+        let store = KvStore { data };
+        let mut pos = 0;
+        while pos < store.data.len {
+            pos = store.entry_at(pos)?.end;
+        }
+        Result::Ok(store)
+    }
+
+    /// Loads the store from the current escrow's `Data` field.
+    ///
+    /// An escrow without a `Data` field (e.g. on the first `finish()` call) yields an empty store.
+    pub fn load() -> Result<Self> {
+        Self::from_data_field(get_current_escrow().get_data())
+    }
+
+    fn from_data_field(field: Result<ContractData>) -> Result<Self> {
+        match field {
+            Result::Ok(data) => Self::from_contract_data(data),
+            Result::Err(FieldNotFound) => Result::Ok(Self::new()),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    /// Writes the store to the current escrow's `Data` field.
+    pub fn store(&self) -> Result<()> {
+        CurrentEscrow::update_current_escrow_data(self.data.clone())
+    }
+
+    /// The underlying contract data.
+    pub fn as_contract_data(&self) -> &ContractData {
+        &self.data
+    }
+
+    /// Returns the value stored under `key`.
+    pub fn get(&self, key: &[u8]) -> Option<&[u8]> {
+        self.find(key)
+            .map(|entry| &self.data.data[entry.value.0..entry.value.1])
+    }
+
+    pub fn contains_key(&self, key: &[u8]) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// Inserts or replaces the value stored under `key`.
+    ///
+    /// On error the store is left unchanged.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the entry was written
+    /// * `Err(Error::InvalidParams)` - If `key` is empty
+    /// * `Err(Error::DataFieldTooLarge)` - If `key` or `value` is too long to length-prefix
+    /// * `Err(Error::KvStoreFull)` - If the entry does not fit in the remaining capacity
+    pub fn set(&mut self, key: &[u8], value: &[u8]) -> Result<()> {
+        if key.is_empty() {
+            return Result::Err(InvalidParams);
+        }
+        let (key_prefix, key_prefix_len) = match encode_vl_length(key.len()) {
+            Result::Ok(prefix) => prefix,
+            Result::Err(_) => return Result::Err(DataFieldTooLarge),
+        };
+        let (value_prefix, value_prefix_len) = match encode_vl_length(value.len()) {
+            Result::Ok(prefix) => prefix,
+            Result::Err(_) => return Result::Err(DataFieldTooLarge),
+        };
+        let new_size = key_prefix_len + key.len() + value_prefix_len + value.len();
+
+        let existing = self.find(key);
+        let freed = existing.as_ref().map_or(0, |entry| entry.end - entry.start);
+        if self.data.len - freed + new_size > XRPL_CONTRACT_DATA_SIZE {
+            return Result::Err(KvStoreFull);
+        }
+
+        if let Some(entry) = existing {
+            self.remove_entry(&entry);
+        }
+        for part in [
+            &key_prefix[..key_prefix_len],
+            key,
+            &value_prefix[..value_prefix_len],
+            value,
+        ] {
+            let end = self.data.len + part.len();
+            self.data.data[self.data.len..end].copy_from_slice(part);
+            self.data.len = end;
+        }
+        Result::Ok(())
+    }
+
+    /// Removes `key`, returning whether it was present.
+    pub fn remove(&mut self, key: &[u8]) -> bool {
+        match self.find(key) {
+            Some(entry) => {
+                self.remove_entry(&entry);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes every entry.
+    pub fn clear(&mut self) {
+        self.data.data[..self.data.len].fill(0);
+        self.data.len = 0;
+    }
+
+    /// Iterates over `(key, value)` pairs in storage order.
+    pub fn iter(&self) -> KvIter<'_> {
+        KvIter {
+            store: self,
+            pos: 0,
+        }
+    }
+
+    /// The number of entries.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.len == 0
+    }
+
+    /// The number of bytes in use.
+    pub fn used(&self) -> usize {
+        self.data.len
+    }
+
+    /// The total number of bytes available (`XRPL_CONTRACT_DATA_SIZE`).
+    pub const fn capacity(&self) -> usize {
+        XRPL_CONTRACT_DATA_SIZE
+    }
+
+    /// The number of bytes still free.
+    pub fn remaining(&self) -> usize {
+        XRPL_CONTRACT_DATA_SIZE - self.data.len
+    }
+
+    fn find(&self, key: &[u8]) -> Option<Entry> {
+        let mut pos = 0;
+        while pos < self.data.len {
+            // Entries were validated on construction, so parsing cannot fail here.
+            let entry = self.entry_at(pos).ok()?;
+            if &self.data.data[entry.key.0..entry.key.1] == key {
+                return Some(entry);
+            }
+            pos = entry.end;
+        }
+        None
+    }
+
+    fn entry_at(&self, start: usize) -> Result<Entry> {
+        let bytes = &self.data.data[..self.data.len];
+        let key = read_vl(bytes, start)?;
+        if key.1 <= key.0 {
+            return Result::Err(InvalidDecoding);
+        }
+        let value = read_vl(bytes, key.1)?;
+        Result::Ok(Entry {
+            start,
+            key,
+            value,
+            end: value.1,
+        })
+    }
+
+    fn remove_entry(&mut self, entry: &Entry) {
+        let len = entry.end - entry.start;
+        self.data
+            .data
+            .copy_within(entry.end..self.data.len, entry.start);
+        self.data.len -= len;
+        self.data.data[self.data.len..self.data.len + len].fill(0);
+    }
+}
+
+impl Default for KvStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reads a VL-prefixed slice starting at `pos`, returning its `(start, end)` offsets.
+fn read_vl(bytes: &[u8], pos: usize) -> Result<(usize, usize)> {
+    let (len, prefix_len) = decode_vl_length(&bytes[pos..])?;
+    if pos + prefix_len + len > bytes.len() {
+        return Result::Err(InvalidDecoding);
+    }
+    Result::Ok((pos + prefix_len, pos + prefix_len + len))
+}
+
+/// Iterator over the entries of a [`KvStore`].
+pub struct KvIter<'a> {
+    store: &'a KvStore,
+    pos: usize,
+}
+
+impl<'a> Iterator for KvIter<'a> {
+    type Item = (&'a [u8], &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.store.data.len {
+            return None;
+        }
+        let entry = self.store.entry_at(self.pos).ok()?;
+        self.pos = entry.end;
+        let data = &self.store.data.data;
+        Some((
+            &data[entry.key.0..entry.key.1],
+            &data[entry.value.0..entry.value.1],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_get_replace_remove() {
+        let mut store = KvStore::new();
+        assert!(store.is_empty());
+        store.set(b"alice", &[1]).unwrap();
+        store.set(b"bob", &[0, 0, 0, 7]).unwrap();
+        assert_eq!(store.get(b"alice"), Some(&[1u8][..]));
+        assert_eq!(store.get(b"bob"), Some(&[0u8, 0, 0, 7][..]));
+        assert_eq!(store.get(b"carol"), None);
+        assert_eq!(store.len(), 2);
+        // 1 + 5 + 1 + 1 and 1 + 3 + 1 + 4
+        assert_eq!(store.used(), 8 + 9);
+
+        store.set(b"alice", &[2, 3]).unwrap();
+        assert_eq!(store.get(b"alice"), Some(&[2u8, 3][..]));
+        assert_eq!(store.len(), 2);
+
+        assert!(store.remove(b"bob"));
+        assert!(!store.remove(b"bob"));
+        assert!(!store.contains_key(b"bob"));
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.used(), 9);
+
+        store.clear();
+        assert!(store.is_empty());
+        assert_eq!(store.remaining(), XRPL_CONTRACT_DATA_SIZE);
+    }
+
+    #[test]
+    fn test_iter_in_storage_order() {
+        let mut store = KvStore::new();
+        store.set(b"a", b"1").unwrap();
+        store.set(b"b", b"").unwrap();
+        store.set(b"c", b"3").unwrap();
+        let mut iter = store.iter();
+        assert_eq!(iter.next(), Some((&b"a"[..], &b"1"[..])));
+        assert_eq!(iter.next(), Some((&b"b"[..], &b""[..])));
+        assert_eq!(iter.next(), Some((&b"c"[..], &b"3"[..])));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_full_store_is_left_unchanged() {
+        let mut store = KvStore::new();
+        let big = [0xAAu8; 2000];
+        store.set(b"one", &big).unwrap();
+        store.set(b"two", &big).unwrap();
+        let used = store.used();
+
+        assert_eq!(store.set(b"three", &big), Err(KvStoreFull));
+        assert_eq!(store.used(), used);
+
+        // Replacing an entry only needs room for the difference
+        store.set(b"two", &[0xBB; 2010]).unwrap();
+        assert_eq!(store.get(b"two").unwrap().len(), 2010);
+        assert_eq!(store.get(b"one"), Some(&big[..]));
+
+        assert!(store.set(b"", b"x").is_err());
+    }
+
+    #[test]
+    fn test_from_contract_data_validates() {
+        let mut store = KvStore::new();
+        store.set(b"k", b"v").unwrap();
+        let data = store.as_contract_data().clone();
+        assert_eq!(KvStore::from_contract_data(data).unwrap(), store);
+
+        let mut truncated = store.as_contract_data().clone();
+        truncated.len -= 1;
+        assert!(KvStore::from_contract_data(truncated).is_err());
+
+        // The test host returns a zero-filled Data field, which contains empty keys
+        assert!(KvStore::load().is_err());
+        assert!(store.store().is_ok());
+    }
+
+    #[test]
+    fn test_load_without_data_field_is_empty() {
+        // A fresh escrow has no Data field yet
+        let store = KvStore::from_data_field(Result::Err(FieldNotFound)).unwrap();
+        assert_eq!(store, KvStore::new());
+        assert_eq!(store.iter().count(), 0);
+
+        let error = KvStore::from_data_field(Result::Err(InvalidParams))
+            .err()
+            .unwrap();
+        assert_eq!(error, InvalidParams);
+    }
+}
@@ -21,10 +21,7 @@ impl AccountFields for AccountRoot {}
 
 pub fn get_account_balance(account_id: &AccountID) -> host::Result<Option<Amount>> {
     // Construct the account keylet. This calls a host function, so propagate the error via `?`
-    let account_keylet = match account_keylet(account_id) {
-        host::Result::Ok(keylet) => keylet,
-        host::Result::Err(e) => return host::Result::Err(e),
-    };
+    let account_keylet = account_keylet(account_id)?;
 
     // Try to cache the ledger object inside rippled
     let slot = unsafe { host::cache_ledger_obj(account_keylet.as_ptr(), account_keylet.len(), 0) };
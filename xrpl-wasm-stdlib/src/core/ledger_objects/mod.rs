@@ -1,12 +1,17 @@
 pub mod account_root;
 pub mod current_escrow;
 pub mod escrow;
+pub mod nftoken_page;
 pub mod traits;
 
+use crate::core::locator::Locator;
 use crate::host::error_codes::{
     match_result_code_with_expected_bytes, match_result_code_with_expected_bytes_optional,
 };
-use crate::host::{Result, get_current_ledger_obj_field, get_ledger_obj_field};
+use crate::host::{
+    Result, get_current_ledger_obj_field, get_current_ledger_obj_nested_field,
+    get_ledger_obj_field, get_ledger_obj_nested_field,
+};
 
 /// Trait for types that can be retrieved from ledger object fields.
 ///
@@ -111,6 +116,65 @@ pub trait LedgerObjectFieldGetter: Sized {
     /// * `Ok(None)` - If the field is not present in the ledger object
     /// * `Err(Error)` - If the field retrieval operation failed
     fn get_from_ledger_obj_optional(register_num: i32, field_code: i32) -> Result<Option<Self>>;
+
+    /// Get a required nested field from the current ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `locator` - The locator identifying the nested field
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Self>` where:
+    /// * `Ok(Self)` - The field value at the specified location
+    /// * `Err(Error)` - If the field cannot be retrieved or has unexpected size
+    fn get_from_current_ledger_obj_nested(locator: &Locator) -> Result<Self>;
+
+    /// Get an optional nested field from the current ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `locator` - The locator identifying the nested field
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<Self>>` where:
+    /// * `Ok(Some(Self))` - The field value at the specified location
+    /// * `Ok(None)` - If the field is not present
+    /// * `Err(Error)` - If the field cannot be retrieved or has unexpected size
+    fn get_from_current_ledger_obj_nested_optional(locator: &Locator) -> Result<Option<Self>>;
+
+    /// Get a required nested field from a specific ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `register_num` - The register number holding the ledger object
+    /// * `locator` - The locator identifying the nested field
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Self>` where:
+    /// * `Ok(Self)` - The field value at the specified location
+    /// * `Err(Error)` - If the field cannot be retrieved or has unexpected size
+    fn get_from_ledger_obj_nested(register_num: i32, locator: &Locator) -> Result<Self>;
+
+    /// Get an optional nested field from a specific ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `register_num` - The register number holding the ledger object
+    /// * `locator` - The locator identifying the nested field
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<Self>>` where:
+    /// * `Ok(Some(Self))` - The field value at the specified location
+    /// * `Ok(None)` - If the field is not present in the ledger object
+    /// * `Err(Error)` - If the field retrieval operation failed
+    fn get_from_ledger_obj_nested_optional(
+        register_num: i32,
+        locator: &Locator,
+    ) -> Result<Option<Self>>;
 }
 
 /// Trait for types that can be retrieved as fixed-size fields from ledger objects.
@@ -201,11 +265,83 @@ impl<T: FixedSizeFieldType> LedgerObjectFieldGetter for T {
             Some(unsafe { value.assume_init() })
         })
     }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested(locator: &Locator) -> Result<Self> {
+        let mut value = core::mem::MaybeUninit::<T>::uninit();
+        let result_code = unsafe {
+            get_current_ledger_obj_nested_field(
+                locator.as_ptr(),
+                locator.len(),
+                value.as_mut_ptr().cast(),
+                T::SIZE,
+            )
+        };
+        match_result_code_with_expected_bytes(result_code, T::SIZE, || unsafe {
+            value.assume_init()
+        })
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        let mut value = core::mem::MaybeUninit::<T>::uninit();
+        let result_code = unsafe {
+            get_current_ledger_obj_nested_field(
+                locator.as_ptr(),
+                locator.len(),
+                value.as_mut_ptr().cast(),
+                T::SIZE,
+            )
+        };
+        match_result_code_with_expected_bytes_optional(result_code, T::SIZE, || {
+            Some(unsafe { value.assume_init() })
+        })
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested(register_num: i32, locator: &Locator) -> Result<Self> {
+        let mut value = core::mem::MaybeUninit::<T>::uninit();
+        let result_code = unsafe {
+            get_ledger_obj_nested_field(
+                register_num,
+                locator.as_ptr(),
+                locator.len(),
+                value.as_mut_ptr().cast(),
+                T::SIZE,
+            )
+        };
+        match_result_code_with_expected_bytes(result_code, T::SIZE, || unsafe {
+            value.assume_init()
+        })
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested_optional(
+        register_num: i32,
+        locator: &Locator,
+    ) -> Result<Option<Self>> {
+        let mut value = core::mem::MaybeUninit::<T>::uninit();
+        let result_code = unsafe {
+            get_ledger_obj_nested_field(
+                register_num,
+                locator.as_ptr(),
+                locator.len(),
+                value.as_mut_ptr().cast(),
+                T::SIZE,
+            )
+        };
+        match_result_code_with_expected_bytes_optional(result_code, T::SIZE, || {
+            Some(unsafe { value.assume_init() })
+        })
+    }
 }
 
 pub mod current_ledger_object {
     use super::LedgerObjectFieldGetter;
-    use crate::host::Result;
+    use crate::core::locator::Locator;
+    use crate::core::types::array::{ArrayView, CurrentLedgerObjectRoot};
+    use crate::host::error_codes::match_result_code;
+    use crate::host::{Result, get_current_ledger_obj_nested_array_len};
 
     /// Retrieves a field from the current ledger object.
     ///
@@ -239,11 +375,83 @@ pub mod current_ledger_object {
     pub fn get_field_optional<T: LedgerObjectFieldGetter>(field_code: i32) -> Result<Option<T>> {
         T::get_from_current_ledger_obj_optional(field_code)
     }
+
+    /// Retrieves a nested field from the current ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `locator` - The locator identifying the nested field
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<T>` where:
+    /// * `Ok(T)` - The field value at the specified location
+    /// * `Err(Error)` - If the field cannot be retrieved or has unexpected size
+    #[inline]
+    pub fn get_nested_field<T: LedgerObjectFieldGetter>(locator: &Locator) -> Result<T> {
+        T::get_from_current_ledger_obj_nested(locator)
+    }
+
+    /// Retrieves an optionally present nested field from the current ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `locator` - The locator identifying the nested field
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<T>>` where:
+    /// * `Ok(Some(T))` - The field value at the specified location
+    /// * `Ok(None)` - If the field is not present
+    /// * `Err(Error)` - If the field cannot be retrieved or has unexpected size
+    #[inline]
+    pub fn get_nested_field_optional<T: LedgerObjectFieldGetter>(
+        locator: &Locator,
+    ) -> Result<Option<T>> {
+        T::get_from_current_ledger_obj_nested_optional(locator)
+    }
+
+    /// Retrieves the number of elements in a nested array of the current ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `locator` - The locator identifying the nested array
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<usize>` where:
+    /// * `Ok(usize)` - The number of elements in the array
+    /// * `Err(Error)` - If the locator does not point at an array or the length cannot be read
+    #[inline]
+    pub fn get_nested_array_len(locator: &Locator) -> Result<usize> {
+        let result_code =
+            unsafe { get_current_ledger_obj_nested_array_len(locator.as_ptr(), locator.len()) };
+        match_result_code(result_code, || result_code as usize)
+    }
+
+    /// Opens an array field of the current ledger object (e.g. `SignerEntries`).
+    ///
+    /// # Arguments
+    ///
+    /// * `field_code` - The field code of the array
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<ArrayView<CurrentLedgerObjectRoot>>` where:
+    /// * `Ok(ArrayView)` - A view over the array's elements
+    /// * `Err(Error)` - If the array is not present or the field is not an array
+    #[inline]
+    pub fn get_array(field_code: i32) -> Result<ArrayView<CurrentLedgerObjectRoot>> {
+        ArrayView::new(CurrentLedgerObjectRoot, Locator::from_path(&[field_code]))
+    }
 }
 
 pub mod ledger_object {
     use super::LedgerObjectFieldGetter;
-    use crate::host::Result;
+    use crate::core::locator::Locator;
+    use crate::core::types::array::{ArrayView, LedgerObjectRoot};
+    use crate::host::error_codes::match_result_code;
+    use crate::host::{Result, get_ledger_obj_nested_array_len};
 
     /// Retrieves a field from a specified ledger object.
     ///
@@ -283,6 +491,89 @@ pub mod ledger_object {
         T::get_from_ledger_obj_optional(register_num, field_code)
     }
 
+    /// Retrieves a nested field from a specified ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `register_num` - The register number holding the ledger object to look for data in
+    /// * `locator` - The locator identifying the nested field
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<T>` where:
+    /// * `Ok(T)` - The field value at the specified location
+    /// * `Err(Error)` - If the field cannot be retrieved or has unexpected size
+    #[inline]
+    pub fn get_nested_field<T: LedgerObjectFieldGetter>(
+        register_num: i32,
+        locator: &Locator,
+    ) -> Result<T> {
+        T::get_from_ledger_obj_nested(register_num, locator)
+    }
+
+    /// Retrieves an optionally present nested field from a specified ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `register_num` - The register number holding the ledger object to look for data in
+    /// * `locator` - The locator identifying the nested field
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<Option<T>>` where:
+    /// * `Ok(Some(T))` - The field value at the specified location
+    /// * `Ok(None)` - If the field is not present in the ledger object
+    /// * `Err(Error)` - If the field retrieval operation failed
+    #[inline]
+    pub fn get_nested_field_optional<T: LedgerObjectFieldGetter>(
+        register_num: i32,
+        locator: &Locator,
+    ) -> Result<Option<T>> {
+        T::get_from_ledger_obj_nested_optional(register_num, locator)
+    }
+
+    /// Retrieves the number of elements in a nested array of a specified ledger object.
+    ///
+    /// # Arguments
+    ///
+    /// * `register_num` - The register number holding the ledger object to look for data in
+    /// * `locator` - The locator identifying the nested array
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<usize>` where:
+    /// * `Ok(usize)` - The number of elements in the array
+    /// * `Err(Error)` - If the locator does not point at an array or the length cannot be read
+    #[inline]
+    pub fn get_nested_array_len(register_num: i32, locator: &Locator) -> Result<usize> {
+        let result_code = unsafe {
+            get_ledger_obj_nested_array_len(register_num, locator.as_ptr(), locator.len())
+        };
+        match_result_code(result_code, || result_code as usize)
+    }
+
+    /// Opens an array field of a specified ledger object (e.g. `PriceDataSeries` of an Oracle).
+    ///
+    /// # Arguments
+    ///
+    /// * `register_num` - The register number holding the ledger object to look for data in
+    /// * `field_code` - The field code of the array
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<ArrayView<LedgerObjectRoot>>` where:
+    /// * `Ok(ArrayView)` - A view over the array's elements
+    /// * `Err(Error)` - If the array is not present or the field is not an array
+    #[inline]
+    pub fn get_array(register_num: i32, field_code: i32) -> Result<ArrayView<LedgerObjectRoot>> {
+        ArrayView::new(
+            LedgerObjectRoot {
+                slot_num: register_num,
+            },
+            Locator::from_path(&[field_code]),
+        )
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -290,8 +581,10 @@ pub mod ledger_object {
         use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
         use crate::core::types::amount::Amount;
         use crate::core::types::blob::{Blob, DEFAULT_BLOB_SIZE};
+        use crate::core::types::currency::Currency;
         use crate::core::types::public_key::PUBLIC_KEY_BUFFER_SIZE;
         use crate::core::types::uint::{HASH128_SIZE, HASH256_SIZE, Hash128, Hash256};
+        use crate::host::set_nested_field;
         use crate::sfield;
 
         // ========================================
@@ -385,6 +678,71 @@ pub mod ledger_object {
             assert!(result.unwrap().is_some());
         }
 
+        // ========================================
+        // Tests for nested field access
+        // ========================================
+
+        #[test]
+        fn test_nested_field_getters() {
+            let asset_price = crate::locator!(PriceDataSeries[0].AssetPrice);
+            let base_asset = crate::locator!(PriceDataSeries[0].BaseAsset);
+            let price = crate::locator!(AuctionSlot.Price);
+            let signer = crate::locator!(SignerEntries[0].Account);
+            let uri = crate::locator!(NFTokens[0].URI);
+            let slot = 0;
+
+            let mut usd = [0u8; 20];
+            usd[12..15].copy_from_slice(b"USD");
+            // A positive XRP amount of 1,000,000 drops in its canonical 8-byte form
+            let drops = (0x4000_0000_0000_0000u64 | 1_000_000).to_be_bytes();
+
+            set_nested_field(&asset_price, &42u64.to_ne_bytes());
+            set_nested_field(&base_asset, &usd);
+            set_nested_field(&price, &drops);
+            set_nested_field(&signer, &[7; 20]);
+            set_nested_field(&uri, b"ipfs://nft");
+
+            assert_eq!(
+                current_ledger_object::get_nested_field::<u64>(&asset_price).unwrap(),
+                42
+            );
+            assert_eq!(
+                ledger_object::get_nested_field::<u64>(slot, &asset_price).unwrap(),
+                42
+            );
+            assert_eq!(
+                ledger_object::get_nested_field::<Currency>(slot, &base_asset).unwrap(),
+                Currency(usd)
+            );
+            assert_eq!(
+                ledger_object::get_nested_field::<Amount>(slot, &price).unwrap(),
+                Amount::XRP {
+                    num_drops: 1_000_000
+                }
+            );
+
+            let result = ledger_object::get_nested_field_optional::<AccountID>(slot, &signer);
+            assert_eq!(result.unwrap(), Some(AccountID([7; 20])));
+
+            let blob: Blob<DEFAULT_BLOB_SIZE> =
+                current_ledger_object::get_nested_field(&uri).unwrap();
+            assert_eq!(blob.as_slice(), b"ipfs://nft");
+        }
+
+        #[test]
+        fn test_nested_array_len() {
+            // The test host echoes the locator length back as the array length
+            let locator = crate::locator!(PriceDataSeries);
+            assert_eq!(
+                current_ledger_object::get_nested_array_len(&locator).unwrap(),
+                locator.len()
+            );
+            assert_eq!(
+                ledger_object::get_nested_array_len(0, &locator).unwrap(),
+                locator.len()
+            );
+        }
+
         // ========================================
         // Type inference and compilation tests
         // ========================================
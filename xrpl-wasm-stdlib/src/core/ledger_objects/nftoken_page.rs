@@ -0,0 +1,280 @@
+//! NFTokenPage ledger objects, and traversal of the NFTokens an account holds.
+//!
+//! An account's NFTokens are stored in a doubly linked list of NFTokenPage objects, each holding up
+//! to [`NFTOKEN_PAGE_MAX_TOKENS`] tokens sorted by the low 96 bits of their NFTokenID. Page keys are
+//! not hashed (see [`nftpage_max_keylet`]), so the list is walked from its last page backwards
+//! through `PreviousPageMin` without any keylet host calls.
+//!
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::ledger_objects::current_escrow::get_current_escrow;
+//! use xrpl_wasm_stdlib::core::ledger_objects::nftoken_page::owns_nft_matching;
+//! use xrpl_wasm_stdlib::core::ledger_objects::traits::CurrentEscrowFields;
+//! use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+//! use xrpl_wasm_stdlib::host::ResultExt;
+//! use xrpl_wasm_stdlib::r_address;
+//!
+//! // Only release the escrow to holders of the issuer's taxon 42 collection
+//! let holder = get_current_escrow().get_destination().unwrap_or_panic();
+//! let issuer = AccountID::from(r_address!("rJoxBSzpXhPtAuqFmqxQtGKjA13jUJWthE"));
+//! let allowed = owns_nft_matching(&holder, &issuer, 42).unwrap_or_panic();
+//! ```
+
+use crate::core::ledger_objects::ledger_object;
+use crate::core::ledger_objects::traits::LedgerObjectCommonFields;
+use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::core::types::array::{ArrayView, LedgerObjectRoot};
+use crate::core::types::keylets::{KeyletBytes, nftpage_max_keylet};
+use crate::core::types::nft::NFToken;
+use crate::core::types::uint::Hash256;
+use crate::host;
+use crate::host::{Error, Result};
+use crate::sfield;
+
+/// The maximum number of NFTokens stored on a single NFTokenPage.
+pub const NFTOKEN_PAGE_MAX_TOKENS: usize = 32;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(C)]
+pub struct NFTokenPage {
+    pub(crate) slot_num: i32,
+}
+
+impl LedgerObjectCommonFields for NFTokenPage {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl NFTokenPage {
+    /// Caches the NFTokenPage stored at `keylet` in a new slot.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(NFTokenPage)` - If a page is stored at `keylet`
+    /// * `Err(Error)` - If there is no such page or no free slot
+    pub fn load(keylet: &KeyletBytes) -> Result<Self> {
+        Self::cache(keylet, 0)
+    }
+
+    fn cache(keylet: &KeyletBytes, slot_num: i32) -> Result<Self> {
+        let slot = unsafe { host::cache_ledger_obj(keylet.as_ptr(), keylet.len(), slot_num) };
+        if slot < 0 {
+            return Result::Err(Error::from_code(slot));
+        }
+        Result::Ok(NFTokenPage { slot_num: slot })
+    }
+
+    /// The key of the previous page of the owner's list, absent on the first page.
+    pub fn previous_page_min(&self) -> Result<Option<Hash256>> {
+        ledger_object::get_field_optional(self.slot_num, sfield::PreviousPageMin)
+    }
+
+    /// The key of the next page of the owner's list, absent on the last page.
+    pub fn next_page_min(&self) -> Result<Option<Hash256>> {
+        ledger_object::get_field_optional(self.slot_num, sfield::NextPageMin)
+    }
+
+    /// Opens the page's `NFTokens` array.
+    pub fn nftokens(&self) -> Result<ArrayView<LedgerObjectRoot>> {
+        ledger_object::get_array(self.slot_num, sfield::NFTokens)
+    }
+
+    /// Returns the first NFToken on this page for which `predicate` returns `true`.
+    pub fn find<F>(&self, mut predicate: F) -> Result<Option<NFToken>>
+    where
+        F: FnMut(&NFToken) -> bool,
+    {
+        let nftokens = self.nftokens()?;
+        for element in nftokens.iter() {
+            let id: Hash256 = element.get_field(sfield::NFTokenID)?;
+            let token = NFToken::new(id.0);
+            if predicate(&token) {
+                return Result::Ok(Some(token));
+            }
+        }
+        Result::Ok(None)
+    }
+}
+
+/// Iterator over an account's NFTokenPages, from the last page to the first.
+///
+/// Every page is cached into the same slot, replacing the previous one, so a walk uses a single
+/// slot however many pages the account has. As a consequence, a yielded [`NFTokenPage`] must only
+/// be read before the iterator is advanced again.
+///
+/// The walk stops with [`Error::InvalidDecoding`] if a `PreviousPageMin` link does not point to a
+/// lower page of the same owner, so that malformed data cannot make it loop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NFTokenPages {
+    owner: AccountID,
+    /// The key of the page to read next, or of the page in `slot_num` once the walk has started.
+    key: KeyletBytes,
+    /// The slot holding the last yielded page, 0 before the first one.
+    slot_num: i32,
+    done: bool,
+}
+
+impl NFTokenPages {
+    pub fn new(owner: &AccountID) -> Self {
+        NFTokenPages {
+            owner: *owner,
+            key: nftpage_max_keylet(owner),
+            slot_num: 0,
+            done: false,
+        }
+    }
+
+    /// Follows `PreviousPageMin` of the page in `slot_num`, returning `Ok(false)` on the first page.
+    fn follow_previous_link(&mut self) -> Result<bool> {
+        let page = NFTokenPage {
+            slot_num: self.slot_num,
+        };
+        match page.previous_page_min()? {
+            Some(previous) => {
+                if previous.0 >= self.key || previous.0[..ACCOUNT_ID_SIZE] != self.owner.0 {
+                    return Result::Err(Error::InvalidDecoding);
+                }
+                self.key = previous.0;
+                Result::Ok(true)
+            }
+            None => Result::Ok(false),
+        }
+    }
+}
+
+impl Iterator for NFTokenPages {
+    type Item = Result<NFTokenPage>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if self.slot_num != 0 {
+            match self.follow_previous_link() {
+                Result::Ok(true) => {}
+                Result::Ok(false) => {
+                    self.done = true;
+                    return None;
+                }
+                Result::Err(e) => {
+                    self.done = true;
+                    return Some(Result::Err(e));
+                }
+            }
+        }
+
+        match NFTokenPage::cache(&self.key, self.slot_num) {
+            Result::Ok(page) => {
+                self.slot_num = page.slot_num;
+                Some(Result::Ok(page))
+            }
+            // An account without NFTokens has no last page
+            Result::Err(Error::LedgerObjNotFound) if self.slot_num == 0 => {
+                self.done = true;
+                None
+            }
+            Result::Err(e) => {
+                self.done = true;
+                Some(Result::Err(e))
+            }
+        }
+    }
+}
+
+/// Walks the NFTokenPages of `owner`, from the last page to the first.
+pub fn nftoken_pages(owner: &AccountID) -> NFTokenPages {
+    NFTokenPages::new(owner)
+}
+
+/// Returns the first NFToken held by `owner` for which `predicate` returns `true`.
+///
+/// Pages are visited from the last to the first, so tokens are not visited in ascending order.
+///
+/// # Returns
+///
+/// * `Ok(Some(NFToken))` - The first matching token
+/// * `Ok(None)` - If `owner` holds no matching token (or no token at all)
+/// * `Err(Error)` - If a page could not be read
+pub fn find_nft<F>(owner: &AccountID, mut predicate: F) -> Result<Option<NFToken>>
+where
+    F: FnMut(&NFToken) -> bool,
+{
+    for page in nftoken_pages(owner) {
+        if let Some(token) = page?.find(&mut predicate)? {
+            return Result::Ok(Some(token));
+        }
+    }
+    Result::Ok(None)
+}
+
+/// Checks whether `owner` holds any NFToken of `issuer`'s collection `taxon`.
+///
+/// Issuer and taxon are decoded locally with [`NFToken::decode`]. Tokens are not sorted by issuer,
+/// so every page of the owner may be read.
+pub fn owns_nft_matching(owner: &AccountID, issuer: &AccountID, taxon: u32) -> Result<bool> {
+    let token = find_nft(owner, |token| {
+        let parts = token.decode();
+        parts.issuer == *issuer && parts.taxon == taxon
+    })?;
+    Result::Ok(token.is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::keylets::{nftpage_keylet, nftpage_min_keylet};
+    use crate::nft_id;
+    use crate::r_address;
+
+    #[test]
+    fn test_page_keylets() {
+        let owner = AccountID::from(r_address!("rJoxBSzpXhPtAuqFmqxQtGKjA13jUJWthE"));
+        let token = nft_id!("000B0539C35B55AA096BA6D87A6E6C965A6534150DC56E5E12C5D09E0000000C");
+
+        let min = nftpage_min_keylet(&owner);
+        let max = nftpage_max_keylet(&owner);
+        let key = nftpage_keylet(&owner, &token);
+
+        assert_eq!(min[..ACCOUNT_ID_SIZE], owner.0);
+        assert_eq!(min[ACCOUNT_ID_SIZE..], [0u8; 12]);
+        assert_eq!(max[..ACCOUNT_ID_SIZE], owner.0);
+        assert_eq!(max[ACCOUNT_ID_SIZE..], [0xFFu8; 12]);
+        assert_eq!(key[..ACCOUNT_ID_SIZE], owner.0);
+        assert_eq!(key[ACCOUNT_ID_SIZE..], token.as_bytes()[ACCOUNT_ID_SIZE..]);
+        assert!(min < key && key < max);
+    }
+
+    #[test]
+    fn test_page_reads() {
+        // The test host caches every keylet and echoes buffer and locator lengths back
+        let page = NFTokenPage::load(&nftpage_max_keylet(&AccountID([1; 20]))).unwrap();
+        assert!(page.previous_page_min().unwrap().is_some());
+        assert!(page.next_page_min().unwrap().is_some());
+        assert_eq!(page.nftokens().unwrap().len(), 4);
+
+        assert!(page.find(|_| true).unwrap().is_some());
+        assert_eq!(page.find(|_| false).unwrap(), None);
+    }
+
+    #[test]
+    fn test_find_nft() {
+        let owner = AccountID([1; 20]);
+        // A match on the last page is returned without following PreviousPageMin
+        assert!(find_nft(&owner, |_| true).unwrap().is_some());
+
+        // Otherwise the walk follows the link, which the test host does not report as a lower
+        // page of the same owner
+        let error = owns_nft_matching(&owner, &AccountID([2; 20]), 42)
+            .err()
+            .unwrap();
+        assert_eq!(error.code(), Error::InvalidDecoding.code());
+    }
+
+    #[test]
+    fn test_pages_stop_on_malformed_link() {
+        let mut pages = nftoken_pages(&AccountID([1; 20]));
+        assert!(pages.next().unwrap().is_ok());
+        assert!(pages.next().unwrap().is_err());
+        assert!(pages.next().is_none());
+    }
+}
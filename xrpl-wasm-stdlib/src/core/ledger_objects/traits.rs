@@ -7,18 +7,18 @@ use crate::core::types::blob::{
 use crate::core::types::contract_data::{ContractData, XRPL_CONTRACT_DATA_SIZE};
 use crate::core::types::public_key::PUBLIC_KEY_BUFFER_SIZE;
 use crate::core::types::uint::{Hash128, Hash256};
+use crate::core::types::x_address::XAddress;
 
+use crate::host::Result;
 /// This module provides traits for interacting with XRP Ledger objects.
 ///
 /// It defines common interfaces for accessing and manipulating different types of ledger objects,
 /// particularly focusing on Escrow objects. The traits provide methods to get and set various
 /// fields of ledger objects, with separate traits for current ledger objects and general ledger objects.
-use crate::host::error_codes::{
-    match_result_code, match_result_code_optional, match_result_code_with_expected_bytes,
-};
+use crate::host::error_codes::{match_result_code_optional, match_result_code_with_expected_bytes};
 use crate::host::{Error, get_current_ledger_obj_field, get_ledger_obj_field, update_data};
-use crate::host::{Result, Result::Err, Result::Ok};
 use crate::sfield;
+use crate::xrpl_object;
 
 /// Trait providing access to common fields present in all ledger objects.
 ///
@@ -95,31 +95,30 @@ pub trait CurrentLedgerObjectCommonFields {
     }
 }
 
-/// Trait providing access to fields specific to Escrow objects in the current ledger.
+/// Fields specific to Escrow objects.
 ///
-/// This trait extends `CurrentLedgerObjectCommonFields` and provides methods to access
-/// fields that are specific to Escrow objects in the current ledger being processed.
-pub trait CurrentEscrowFields: CurrentLedgerObjectCommonFields {
+/// Generates `EscrowFields`, which reads an Escrow cached in a slot, and `CurrentEscrowFields`,
+/// which reads the Escrow currently being processed.
+#[xrpl_object(LedgerEntryType::Escrow)]
+pub trait EscrowFields {
     /// The address of the owner (sender) of this escrow. This is the account that provided the XRP
     /// and gets it back if the escrow is canceled.
-    fn get_account(&self) -> Result<AccountID> {
-        current_ledger_object::get_field(sfield::Account)
-    }
+    #[field(Account)]
+    fn get_account(&self) -> Result<AccountID>;
 
     /// The amount currently held in the escrow (could be XRP, IOU, or MPT).
-    fn get_amount(&self) -> Result<Amount> {
-        current_ledger_object::get_field(sfield::Amount)
-    }
+    #[field(Amount)]
+    fn get_amount(&self) -> Result<Amount>;
 
     /// The escrow can be canceled if and only if this field is present and the time it specifies
     /// has passed. Specifically, this is specified as seconds since the Ripple Epoch and it
     /// "has passed" if it's earlier than the close time of the previous validated ledger.
-    fn get_cancel_after(&self) -> Result<Option<u32>> {
-        current_ledger_object::get_field_optional(sfield::CancelAfter)
-    }
+    #[field(CancelAfter)]
+    fn get_cancel_after(&self) -> Result<Option<u32>>;
 
     /// A PREIMAGE-SHA-256 crypto-condition in full crypto-condition format. If present, the EscrowFinish
     /// transaction must contain a fulfillment that satisfies this condition.
+    #[current]
     fn get_condition(&self) -> Result<Option<ConditionBlob>> {
         let mut buffer = [0u8; CONDITION_BLOB_SIZE];
 
@@ -140,148 +139,9 @@ pub trait CurrentEscrowFields: CurrentLedgerObjectCommonFields {
         })
     }
 
-    /// The destination address where the XRP is paid if the escrow is successful.
-    fn get_destination(&self) -> Result<AccountID> {
-        current_ledger_object::get_field(sfield::Destination)
-    }
-
-    /// A hint indicating which page of the destination's owner directory links to this object, in
-    /// case the directory consists of multiple pages. Omitted on escrows created before enabling the fix1523 amendment.
-    fn get_destination_node(&self) -> Result<Option<u64>> {
-        current_ledger_object::get_field_optional(sfield::DestinationNode)
-    }
-
-    /// An arbitrary tag to further specify the destination for this escrow, such as a hosted
-    /// recipient at the destination address.
-    fn get_destination_tag(&self) -> Result<Option<u32>> {
-        current_ledger_object::get_field_optional(sfield::DestinationTag)
-    }
-
-    /// The time, in seconds since the Ripple Epoch, after which this escrow can be finished. Any
-    /// EscrowFinish transaction before this time fails. (Specifically, this is compared with the
-    /// close time of the previous validated ledger.)
-    fn get_finish_after(&self) -> Result<Option<u32>> {
-        current_ledger_object::get_field_optional(sfield::FinishAfter)
-    }
-
-    // TODO: Implement this function.
-    // /// The value 0x0075, mapped to the string Escrow, indicates that this is an Escrow entry.
-    // fn get_ledger_entry_type(&self) -> Result<LedgerEntryType> {
-    //     return Ok(LedgerEntryType::Escrow);
-    // }
-
-    /// A hint indicating which page of the sender's owner directory links to this entry, in case
-    /// the directory consists of multiple pages.
-    fn get_owner_node(&self) -> Result<u64> {
-        current_ledger_object::get_field(sfield::OwnerNode)
-    }
-
-    /// The identifying hash of the transaction that most recently modified this entry.
-    fn get_previous_txn_id(&self) -> Result<Hash256> {
-        current_ledger_object::get_field(sfield::PreviousTxnID)
-    }
-
-    /// The index of the ledger that contains the transaction that most recently modified this
-    /// entry.
-    fn get_previous_txn_lgr_seq(&self) -> Result<u32> {
-        current_ledger_object::get_field(sfield::PreviousTxnLgrSeq)
-    }
-
-    /// An arbitrary tag to further specify the source for this escrow, such as a hosted recipient
-    /// at the owner's address.
-    fn get_source_tag(&self) -> Result<Option<u32>> {
-        current_ledger_object::get_field_optional(sfield::SourceTag)
-    }
-
-    /// The WASM code that is executing.
-    fn get_finish_function(&self) -> Result<Option<Blob<{ DEFAULT_BLOB_SIZE }>>> {
-        current_ledger_object::get_field_optional(sfield::FinishFunction)
-    }
-
-    /// Retrieves the contract `data` from the current escrow object.
-    ///
-    /// This function fetches the `data` field from the current ledger object and returns it as a
-    /// ContractData structure. The data is read into a fixed-size buffer of XRPL_CONTRACT_DATA_SIZE.
-    ///
-    /// # Returns
-    ///
-    /// Returns a `Result<ContractData>` where:
-    /// * `Ok(ContractData)` - Contains the retrieved data and its actual length
-    /// * `Err(Error)` - If the retrieval operation failed
-    fn get_data(&self) -> Result<ContractData> {
-        let mut data: [u8; XRPL_CONTRACT_DATA_SIZE] = [0; XRPL_CONTRACT_DATA_SIZE];
-
-        let result_code =
-            unsafe { get_current_ledger_obj_field(sfield::Data, data.as_mut_ptr(), data.len()) };
-
-        match result_code {
-            code if code >= 0 => Ok(ContractData {
-                data,
-                len: code as usize,
-            }),
-            code => Err(Error::from_code(code)),
-        }
-    }
-
-    /// Updates the contract data in the current escrow object.
-    ///
-    /// # Arguments
-    ///
-    /// * `data` - The contract data to update
-    ///
-    /// # Returns
-    ///
-    /// Returns a `Result<()>` where:
-    /// * `Ok(())` - The data was successfully updated
-    /// * `Err(Error)` - If the update operation failed
-    fn update_current_escrow_data(data: ContractData) -> Result<()> {
-        // TODO: Make sure rippled always deletes any existing data bytes in rippled, and sets the new
-        // length to be `data.len` (e.g., if the developer writes 2 bytes, then that's the new
-        // length and any old bytes are lost).
-        let result_code = unsafe { update_data(data.data.as_ptr(), data.len) };
-        match_result_code_with_expected_bytes(result_code, data.len, || ())
-    }
-}
-
-/// Trait providing access to fields specific to Escrow objects in any ledger.
-///
-/// This trait extends `LedgerObjectCommonFields` and provides methods to access
-/// fields that are specific to Escrow objects in any ledger, not just the current one.
-/// Each method requires a register number to identify which ledger object to access.
-pub trait EscrowFields: LedgerObjectCommonFields {
-    /// The address of the owner (sender) of this escrow. This is the account that provided the XRP
-    /// and gets it back if the escrow is canceled.
-    fn get_account(&self) -> Result<AccountID> {
-        ledger_object::get_field(self.get_slot_num(), sfield::Account)
-    }
-
-    /// The amount of XRP, in drops, currently held in the escrow.
-    fn get_amount(&self) -> Result<Amount> {
-        // Create a buffer large enough for any Amount type
-        const BUFFER_SIZE: usize = 48usize;
-        let mut buffer = [0u8; BUFFER_SIZE];
-
-        let result_code = unsafe {
-            get_ledger_obj_field(
-                self.get_slot_num(),
-                sfield::Amount,
-                buffer.as_mut_ptr(),
-                buffer.len(),
-            )
-        };
-
-        match_result_code(result_code, || Amount::from(buffer))
-    }
-
-    /// The escrow can be canceled if and only if this field is present and the time it specifies
-    /// has passed. Specifically, this is specified as seconds since the Ripple Epoch and it
-    /// "has passed" if it's earlier than the close time of the previous validated ledger.
-    fn get_cancel_after(&self) -> Result<Option<u32>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::CancelAfter)
-    }
-
     /// A PREIMAGE-SHA-256 crypto-condition in full crypto-condition format. If present, the EscrowFinish
     /// transaction must contain a fulfillment that satisfies this condition.
+    #[slot]
     fn get_condition(&self) -> Result<Option<ConditionBlob>> {
         let mut buffer = [0u8; CONDITION_BLOB_SIZE];
 
@@ -308,61 +168,88 @@ pub trait EscrowFields: LedgerObjectCommonFields {
     }
 
     /// The destination address where the XRP is paid if the escrow is successful.
-    fn get_destination(&self) -> Result<AccountID> {
-        ledger_object::get_field(self.get_slot_num(), sfield::Destination)
-    }
+    #[field(Destination)]
+    fn get_destination(&self) -> Result<AccountID>;
 
     /// A hint indicating which page of the destination's owner directory links to this object, in
     /// case the directory consists of multiple pages. Omitted on escrows created before enabling the fix1523 amendment.
-    fn get_destination_node(&self) -> Result<Option<u64>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::DestinationNode)
-    }
+    #[field(DestinationNode)]
+    fn get_destination_node(&self) -> Result<Option<u64>>;
 
     /// An arbitrary tag to further specify the destination for this escrow, such as a hosted
     /// recipient at the destination address.
-    fn get_destination_tag(&self) -> Result<Option<u32>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::DestinationTag)
+    #[field(DestinationTag)]
+    fn get_destination_tag(&self) -> Result<Option<u32>>;
+
+    /// Checks whether the escrow pays `destination` (e.g. from `x_address!`): the same account and
+    /// the same destination tag, or no tag on either side.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - Whether the destination and its tag match
+    /// * `Err(Error::InvalidAccount)` - If `destination` is for another network: a testnet
+    ///   (`T...`) address without the `testnet` feature, or a mainnet one with it
+    fn matches_destination(&self, destination: &XAddress) -> Result<bool> {
+        if destination.is_test != cfg!(feature = "testnet") {
+            return Err(Error::InvalidAccount);
+        }
+        Ok(self.get_destination()? == destination.account
+            && self.get_destination_tag()? == destination.tag)
     }
 
     /// The time, in seconds since the Ripple Epoch, after which this escrow can be finished. Any
     /// EscrowFinish transaction before this time fails. (Specifically, this is compared with the
     /// close time of the previous validated ledger.)
-    fn get_finish_after(&self) -> Result<Option<u32>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::FinishAfter)
-    }
-
-    // TODO: Implement this function.
-    // /// The value 0x0075, mapped to the string Escrow, indicates that this is an Escrow entry.
-    // fn get_ledger_entry_type(&self) -> Result<LedgerEntryType> {
-    //     return Ok(LedgerEntryType::Escrow);
-    // }
+    #[field(FinishAfter)]
+    fn get_finish_after(&self) -> Result<Option<u32>>;
 
     /// A hint indicating which page of the sender's owner directory links to this entry, in case
     /// the directory consists of multiple pages.
-    fn get_owner_node(&self) -> Result<u64> {
-        ledger_object::get_field(self.get_slot_num(), sfield::OwnerNode)
-    }
+    #[field(OwnerNode)]
+    fn get_owner_node(&self) -> Result<u64>;
 
     /// The identifying hash of the transaction that most recently modified this entry.
-    fn get_previous_txn_id(&self) -> Result<Hash256> {
-        ledger_object::get_field(self.get_slot_num(), sfield::PreviousTxnID)
-    }
+    #[field(PreviousTxnID)]
+    fn get_previous_txn_id(&self) -> Result<Hash256>;
 
     /// The index of the ledger that contains the transaction that most recently modified this
     /// entry.
-    fn get_previous_txn_lgr_seq(&self) -> Result<u32> {
-        ledger_object::get_field(self.get_slot_num(), sfield::PreviousTxnLgrSeq)
-    }
+    #[field(PreviousTxnLgrSeq)]
+    fn get_previous_txn_lgr_seq(&self) -> Result<u32>;
 
     /// An arbitrary tag to further specify the source for this escrow, such as a hosted recipient
     /// at the owner's address.
-    fn get_source_tag(&self) -> Result<Option<u32>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::SourceTag)
-    }
+    #[field(SourceTag)]
+    fn get_source_tag(&self) -> Result<Option<u32>>;
 
     /// The WASM code that is executing.
-    fn get_finish_function(&self) -> Result<Option<Blob<{ DEFAULT_BLOB_SIZE }>>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::FinishFunction)
+    #[field(FinishFunction)]
+    fn get_finish_function(&self) -> Result<Option<Blob<{ DEFAULT_BLOB_SIZE }>>>;
+
+    /// Retrieves the contract `data` from the current escrow object.
+    ///
+    /// This function fetches the `data` field from the current ledger object and returns it as a
+    /// ContractData structure. The data is read into a fixed-size buffer of XRPL_CONTRACT_DATA_SIZE.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<ContractData>` where:
+    /// * `Ok(ContractData)` - Contains the retrieved data and its actual length
+    /// * `Err(Error)` - If the retrieval operation failed
+    #[current]
+    fn get_data(&self) -> Result<ContractData> {
+        let mut data: [u8; XRPL_CONTRACT_DATA_SIZE] = [0; XRPL_CONTRACT_DATA_SIZE];
+
+        let result_code =
+            unsafe { get_current_ledger_obj_field(sfield::Data, data.as_mut_ptr(), data.len()) };
+
+        match result_code {
+            code if code >= 0 => Ok(ContractData {
+                data,
+                len: code as usize,
+            }),
+            code => Err(Error::from_code(code)),
+        }
     }
 
     /// Retrieves the contract data from the specified ledger object.
@@ -371,15 +258,12 @@ pub trait EscrowFields: LedgerObjectCommonFields {
     /// and returns it as a ContractData structure. The data is read into a fixed-size buffer
     /// of XRPL_CONTRACT_DATA_SIZE.
     ///
-    /// # Arguments
-    ///
-    /// * `register_num` - The register number where the ledger object is stored
-    ///
     /// # Returns
     ///
     /// Returns a `Result<ContractData>` where:
     /// * `Ok(ContractData)` - Contains the retrieved data and its actual length
     /// * `Err(Error)` - If the retrieval operation failed
+    #[slot]
     fn get_data(&self) -> Result<ContractData> {
         let mut data: [u8; XRPL_CONTRACT_DATA_SIZE] = [0; XRPL_CONTRACT_DATA_SIZE];
 
@@ -400,131 +284,130 @@ pub trait EscrowFields: LedgerObjectCommonFields {
             code => Err(Error::from_code(code)),
         }
     }
+
+    /// Updates the contract data in the current escrow object.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The contract data to update
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<()>` where:
+    /// * `Ok(())` - The data was successfully updated
+    /// * `Err(Error)` - If the update operation failed
+    #[current]
+    fn update_current_escrow_data(data: ContractData) -> Result<()> {
+        // TODO: Make sure rippled always deletes any existing data bytes in rippled, and sets the new
+        // length to be `data.len` (e.g., if the developer writes 2 bytes, then that's the new
+        // length and any old bytes are lost).
+        let result_code = unsafe { update_data(data.data.as_ptr(), data.len) };
+        match_result_code_with_expected_bytes(result_code, data.len, || ())
+    }
 }
 
-/// Trait providing access to fields specific to AccountRoot objects in any ledger.
+/// Fields specific to AccountRoot objects.
 ///
-/// This trait extends `LedgerObjectCommonFields` and provides methods to access
-/// fields that are specific to Escrow objects in any ledger, not just the current one.
-/// Each method requires a register number to identify which ledger object to access.
-pub trait AccountFields: LedgerObjectCommonFields {
+/// Generates `AccountFields`, which reads an AccountRoot cached in a slot, and
+/// `CurrentAccountFields`, which reads the AccountRoot currently being processed.
+#[xrpl_object(LedgerEntryType::AccountRoot)]
+pub trait AccountFields {
     /// The identifying address of the account.
-    fn get_account(&self) -> Result<AccountID> {
-        ledger_object::get_field(self.get_slot_num(), sfield::Account)
-    }
+    #[field(Account)]
+    fn get_account(&self) -> Result<AccountID>;
 
     /// AccountTxnID field for the account.
-    fn account_txn_id(&self) -> Result<Option<Hash256>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::AccountTxnID)
-    }
+    #[field(AccountTxnID)]
+    fn account_txn_id(&self) -> Result<Option<Hash256>>;
 
     /// The ledger entry ID of the corresponding AMM ledger entry. Set during account creation; cannot be modified.
     /// If present, indicates that this is a special AMM AccountRoot; always omitted on non-AMM accounts.
     /// (Added by the AMM amendment)
-    fn amm_id(&self) -> Result<Option<Hash256>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::AMMID)
-    }
+    #[field(AMMID)]
+    fn amm_id(&self) -> Result<Option<Hash256>>;
 
     /// The account's current XRP balance in drops.
-    fn balance(&self) -> Result<Option<Amount>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::Balance)
-    }
+    #[field(Balance)]
+    fn balance(&self) -> Result<Option<Amount>>;
 
     /// How many total of this account's issued non-fungible tokens have been burned.
     /// This number is always equal or less than MintedNFTokens.
-    fn burned_nf_tokens(&self) -> Result<Option<u32>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::BurnedNFTokens)
-    }
+    #[field(BurnedNFTokens)]
+    fn burned_nf_tokens(&self) -> Result<Option<u32>>;
 
     /// A domain associated with this account. In JSON, this is the hexadecimal for the ASCII representation of the
     /// domain. Cannot be more than 256 bytes in length.
-    fn domain(&self) -> Result<Option<UriBlob>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::Domain)
-    }
+    #[field(Domain)]
+    fn domain(&self) -> Result<Option<UriBlob>>;
 
     /// The MD5 hash of an email address. Clients can use this to look up an avatar through services such as Gravatar.
-    fn email_hash(&self) -> Result<Option<Hash128>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::EmailHash)
-    }
+    #[field(EmailHash)]
+    fn email_hash(&self) -> Result<Option<Hash128>>;
 
     /// The account's Sequence Number at the time it minted its first non-fungible-token.
     /// (Added by the fixNFTokenRemint amendment)
-    fn first_nf_token_sequence(&self) -> Result<Option<u32>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::FirstNFTokenSequence)
-    }
+    #[field(FirstNFTokenSequence)]
+    fn first_nf_token_sequence(&self) -> Result<Option<u32>>;
 
     /// The value 0x0061, mapped to the string AccountRoot, indicates that this is an AccountRoot object.
-    fn ledger_entry_type(&self) -> Result<u16> {
-        ledger_object::get_field(self.get_slot_num(), sfield::LedgerEntryType)
-    }
+    #[field(LedgerEntryType)]
+    fn ledger_entry_type(&self) -> Result<u16>;
 
     /// A public key that may be used to send encrypted messages to this account. In JSON, uses hexadecimal.
     /// Must be exactly 33 bytes, with the first byte indicating the key type: 0x02 or 0x03 for secp256k1 keys,
     /// 0xED for Ed25519 keys.
-    fn message_key(&self) -> Result<Option<Blob<{ PUBLIC_KEY_BUFFER_SIZE }>>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::MessageKey)
-    }
+    #[field(MessageKey)]
+    fn message_key(&self) -> Result<Option<Blob<{ PUBLIC_KEY_BUFFER_SIZE }>>>;
 
     /// How many total non-fungible tokens have been minted by and on behalf of this account.
     /// (Added by the NonFungibleTokensV1_1 amendment)
-    fn minted_nf_tokens(&self) -> Result<Option<u32>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::MintedNFTokens)
-    }
+    #[field(MintedNFTokens)]
+    fn minted_nf_tokens(&self) -> Result<Option<u32>>;
 
     /// Another account that can mint non-fungible tokens on behalf of this account.
     /// (Added by the NonFungibleTokensV1_1 amendment)
-    fn nf_token_minter(&self) -> Result<Option<AccountID>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::NFTokenMinter)
-    }
+    #[field(NFTokenMinter)]
+    fn nf_token_minter(&self) -> Result<Option<AccountID>>;
 
     /// The number of objects this account owns in the ledger, which contributes to its owner reserve.
-    fn owner_count(&self) -> Result<u32> {
-        ledger_object::get_field(self.get_slot_num(), sfield::OwnerCount)
-    }
+    #[field(OwnerCount)]
+    fn owner_count(&self) -> Result<u32>;
 
     /// The identifying hash of the transaction that most recently modified this object.
-    fn previous_txn_id(&self) -> Result<Hash256> {
-        ledger_object::get_field(self.get_slot_num(), sfield::PreviousTxnID)
-    }
+    #[field(PreviousTxnID)]
+    fn previous_txn_id(&self) -> Result<Hash256>;
 
     /// The index of the ledger that contains the transaction that most recently modified this object.
-    fn previous_txn_lgr_seq(&self) -> Result<u32> {
-        ledger_object::get_field(self.get_slot_num(), sfield::PreviousTxnLgrSeq)
-    }
+    #[field(PreviousTxnLgrSeq)]
+    fn previous_txn_lgr_seq(&self) -> Result<u32>;
 
     /// The address of a key pair that can be used to sign transactions for this account instead of the master key.
     /// Use a SetRegularKey transaction to change this value.
-    fn regular_key(&self) -> Result<Option<AccountID>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::RegularKey)
-    }
+    #[field(RegularKey)]
+    fn regular_key(&self) -> Result<Option<AccountID>>;
 
     /// The sequence number of the next valid transaction for this account.
-    fn sequence(&self) -> Result<u32> {
-        ledger_object::get_field(self.get_slot_num(), sfield::Sequence)
-    }
+    #[field(Sequence)]
+    fn sequence(&self) -> Result<u32>;
 
     /// How many Tickets this account owns in the ledger. This is updated automatically to ensure that
     /// the account stays within the hard limit of 250 Tickets at a time. This field is omitted if the account has zero
     /// Tickets. (Added by the TicketBatch amendment.)
-    fn ticket_count(&self) -> Result<Option<u32>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::TicketCount)
-    }
+    #[field(TicketCount)]
+    fn ticket_count(&self) -> Result<Option<u32>>;
 
     /// How many significant digits to use for exchange rates of Offers involving currencies issued by this address.
     /// Valid values are 3 to 15, inclusive. (Added by the TickSize amendment.)
-    fn tick_size(&self) -> Result<Option<u8>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::TickSize)
-    }
+    #[field(TickSize)]
+    fn tick_size(&self) -> Result<Option<u8>>;
 
     /// A transfer fee to charge other users for sending currency issued by this account to each other.
-    fn transfer_rate(&self) -> Result<Option<u32>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::TransferRate)
-    }
+    #[field(TransferRate)]
+    fn transfer_rate(&self) -> Result<Option<u32>>;
 
     /// An arbitrary 256-bit value that users can set.
-    fn wallet_locator(&self) -> Result<Option<Hash256>> {
-        ledger_object::get_field_optional(self.get_slot_num(), sfield::WalletLocator)
-    }
+    #[field(WalletLocator)]
+    fn wallet_locator(&self) -> Result<Option<Hash256>>;
 }
 
 #[cfg(test)]
@@ -532,6 +415,7 @@ mod tests {
     use super::*;
     use crate::core::ledger_objects::current_escrow::CurrentEscrow;
     use crate::core::ledger_objects::escrow::Escrow;
+    use crate::core::types::ledger_entry_type::LedgerEntryType;
 
     #[test]
     fn test_current_escrow_get_condition_returns_some_with_data() {
@@ -588,6 +472,127 @@ mod tests {
         assert!(result3.unwrap().is_some());
     }
 
+    #[xrpl_object(LedgerEntryType::Oracle)]
+    trait OracleFields {
+        #[field(Owner)]
+        fn get_owner(&self) -> Result<AccountID>;
+
+        #[field(LastUpdateTime)]
+        fn get_last_update_time(&self) -> Result<Option<u32>>;
+
+        #[slot]
+        fn slot_only(&self) -> i32 {
+            self.get_slot_num()
+        }
+    }
+
+    struct Oracle {
+        slot_num: i32,
+    }
+
+    impl LedgerObjectCommonFields for Oracle {
+        fn get_slot_num(&self) -> i32 {
+            self.slot_num
+        }
+    }
+
+    impl OracleFields for Oracle {}
+
+    struct CurrentOracle;
+
+    impl CurrentLedgerObjectCommonFields for CurrentOracle {}
+
+    impl CurrentOracleFields for CurrentOracle {}
+
+    #[test]
+    fn test_xrpl_object_generates_slot_and_current_traits() {
+        let oracle = Oracle { slot_num: 3 };
+        assert!(oracle.get_owner().is_ok());
+        assert!(oracle.get_last_update_time().unwrap().is_some());
+        assert_eq!(oracle.slot_only(), 3);
+        assert_eq!(Oracle::LEDGER_ENTRY_TYPE, LedgerEntryType::Oracle);
+
+        assert!(CurrentOracle.get_owner().is_ok());
+        assert!(CurrentOracle.get_last_update_time().unwrap().is_some());
+        assert_eq!(
+            <CurrentOracle as CurrentOracleFields>::LEDGER_ENTRY_TYPE,
+            LedgerEntryType::Oracle
+        );
+        assert_eq!(Escrow::LEDGER_ENTRY_TYPE, LedgerEntryType::Escrow);
+    }
+
+    /// An escrow whose destination and tag are fixed, for checks built on the field getters.
+    struct FakeEscrow {
+        destination: AccountID,
+        tag: Option<u32>,
+    }
+
+    impl LedgerObjectCommonFields for FakeEscrow {
+        fn get_slot_num(&self) -> i32 {
+            0
+        }
+    }
+
+    impl EscrowFields for FakeEscrow {
+        fn get_destination(&self) -> Result<AccountID> {
+            Ok(self.destination)
+        }
+
+        fn get_destination_tag(&self) -> Result<Option<u32>> {
+            Ok(self.tag)
+        }
+    }
+
+    const DESTINATION: AccountID =
+        AccountID(crate::r_address!("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf"));
+
+    #[test]
+    fn test_matches_destination_tags() {
+        let escrow = FakeEscrow {
+            destination: DESTINATION,
+            tag: Some(1),
+        };
+        let is_test = cfg!(feature = "testnet");
+        let tagged = XAddress::new(DESTINATION, Some(1), is_test);
+        assert_eq!(escrow.matches_destination(&tagged), Ok(true));
+
+        let other_tag = XAddress::new(DESTINATION, Some(2), is_test);
+        assert_eq!(escrow.matches_destination(&other_tag), Ok(false));
+        let other_account = XAddress::new(AccountID([1; 20]), Some(1), is_test);
+        assert_eq!(escrow.matches_destination(&other_account), Ok(false));
+
+        // A tagged address does not match an escrow without a DestinationTag, and vice versa
+        let untagged_escrow = FakeEscrow {
+            destination: DESTINATION,
+            tag: None,
+        };
+        assert_eq!(untagged_escrow.matches_destination(&tagged), Ok(false));
+        let untagged = XAddress::new(DESTINATION, None, is_test);
+        assert_eq!(untagged_escrow.matches_destination(&untagged), Ok(true));
+        assert_eq!(escrow.matches_destination(&untagged), Ok(false));
+    }
+
+    #[test]
+    fn test_matches_destination_rejects_other_network() {
+        let escrow = FakeEscrow {
+            destination: DESTINATION,
+            tag: None,
+        };
+        // TVE26TYGhfLC7tQDno7G8dGtxSkYQn49b3qD26PK7FcGSKE is DESTINATION without a tag
+        let testnet = crate::x_address!("TVE26TYGhfLC7tQDno7G8dGtxSkYQn49b3qD26PK7FcGSKE");
+        let mainnet = XAddress::new(DESTINATION, None, false);
+        let (ours, theirs) = if cfg!(feature = "testnet") {
+            (testnet, mainnet)
+        } else {
+            (mainnet, testnet)
+        };
+        assert_eq!(escrow.matches_destination(&ours), Ok(true));
+        assert_eq!(
+            escrow.matches_destination(&theirs),
+            Err(Error::InvalidAccount)
+        );
+    }
+
     #[test]
     fn test_current_escrow_struct_get_condition() {
         // Test the actual CurrentEscrow struct implementation
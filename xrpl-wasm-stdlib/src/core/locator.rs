@@ -13,11 +13,22 @@
 //! l.pack(sfield::MemoType);
 //! # let _ = (l.len() >= 3);
 //! ```
+//!
+//! When the path is known up front, prefer the [`locator!`](crate::locator) macro, which
+//! resolves field names against [`sfield`](crate::sfield) and packs the whole path at
+//! compile time:
+//!
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::locator::Locator;
+//! use xrpl_wasm_stdlib::locator;
+//! const MEMO_DATA: Locator = locator!(Memos[0].MemoData);
+//! # let _ = MEMO_DATA.len();
+//! ```
 
 use core::mem::MaybeUninit;
 
 /// The size of the buffer, in bytes, to use for any new locator
-const LOCATOR_BUFFER_SIZE: usize = 64;
+pub const LOCATOR_BUFFER_SIZE: usize = 64;
 
 // /// A Locator may only pack this many levels deep in an object hierarchy (inclusive of the first
 // /// field)
@@ -79,6 +90,52 @@ impl Locator {
         }
     }
 
+    /// Create a Locator from a complete path of sfield codes and array indices.
+    ///
+    /// This is a `const fn` so that fixed paths can be packed entirely at compile time (see the
+    /// [`locator!`](crate::locator) macro). Panics if the path does not fit in
+    /// [`LOCATOR_BUFFER_SIZE`]; in a `const` context this is a compile error.
+    pub const fn from_path(path: &[i32]) -> Locator {
+        Self::pack_path([0u8; LOCATOR_BUFFER_SIZE], 0, path)
+    }
+
+    /// Same as [`Locator::from_path`], but prefixed with a slot number like
+    /// [`Locator::new_with_slot`].
+    pub const fn from_path_with_slot(slot_num: u8, path: &[i32]) -> Locator {
+        let mut buffer = [0u8; LOCATOR_BUFFER_SIZE];
+        buffer[0] = slot_num;
+        Self::pack_path(buffer, 1, path)
+    }
+
+    const fn pack_path(
+        mut buffer: [u8; LOCATOR_BUFFER_SIZE],
+        start: usize,
+        path: &[i32],
+    ) -> Locator {
+        assert!(
+            start + path.len() * 4 <= LOCATOR_BUFFER_SIZE,
+            "locator path exceeds LOCATOR_BUFFER_SIZE"
+        );
+
+        let mut cur_buffer_index = start;
+        let mut i = 0;
+        while i < path.len() {
+            let value_bytes: [u8; 4] = path[i].to_le_bytes();
+            let mut j = 0;
+            while j < 4 {
+                buffer[cur_buffer_index + j] = value_bytes[j];
+                j += 1;
+            }
+            cur_buffer_index += 4;
+            i += 1;
+        }
+
+        Self {
+            buffer,
+            cur_buffer_index,
+        }
+    }
+
     pub fn pack(&mut self, sfield_or_index: i32) -> bool {
         if self.cur_buffer_index + 4 > LOCATOR_BUFFER_SIZE {
             return false;
@@ -117,3 +174,55 @@ impl Locator {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sfield;
+
+    #[test]
+    fn test_from_path_matches_pack() {
+        let mut packed = Locator::new();
+        packed.pack(sfield::Memos);
+        packed.pack(0);
+        packed.pack(sfield::MemoData);
+
+        let from_path = Locator::from_path(&[sfield::Memos, 0, sfield::MemoData]);
+        assert_eq!(from_path.len(), 12);
+        assert_eq!(from_path.buffer[..12], packed.buffer[..12]);
+    }
+
+    #[test]
+    fn test_from_path_with_slot_matches_pack() {
+        let mut packed = Locator::new_with_slot(3);
+        packed.pack(sfield::PriceDataSeries);
+        packed.pack(1);
+        packed.pack(sfield::AssetPrice);
+
+        let from_path =
+            Locator::from_path_with_slot(3, &[sfield::PriceDataSeries, 1, sfield::AssetPrice]);
+        assert_eq!(from_path.len(), 13);
+        assert_eq!(from_path.buffer[..13], packed.buffer[..13]);
+    }
+
+    #[test]
+    fn test_locator_macro() {
+        const MEMO_DATA: Locator = crate::locator!(Memos[0].MemoData);
+        assert_eq!(
+            MEMO_DATA,
+            Locator::from_path(&[sfield::Memos, 0, sfield::MemoData])
+        );
+
+        const ASSET_PRICE: Locator = crate::locator!(slot = 2, PriceDataSeries[4].AssetPrice);
+        assert_eq!(
+            ASSET_PRICE,
+            Locator::from_path_with_slot(2, &[sfield::PriceDataSeries, 4, sfield::AssetPrice])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "locator path exceeds LOCATOR_BUFFER_SIZE")]
+    fn test_from_path_too_deep() {
+        Locator::from_path(&[0; LOCATOR_BUFFER_SIZE / 4 + 1]);
+    }
+}
@@ -5,13 +5,25 @@
 //! - [`ledger_objects`]: Read fields from on-ledger objects (current or cached)
 //! - [`types`]: Strongly-typed XRPL primitives (AccountID, Hash256, Amount, etc.)
 //! - [`locator`]: Build locators for nested field access
+//! - [`bytes`]: Bounds-checked readers and writers over raw bytes
+//! - [`codec`]: Encode and decode the XRPL canonical binary format
+//! - [`state`]: Typed contract state persisted in an escrow's `Data` field
+//! - [`state_machine`]: Multi-stage escrows with persisted stages and guarded transitions
+//! - [`kv_store`]: A bounded key-value map persisted in an escrow's `Data` field
 //! - [`constants`]: Internal helpers for buffer sizes
 //!
 //! Start with [`current_tx::escrow_finish::EscrowFinish`] to access EscrowFinish TX fields,
 //! or [`ledger_objects::current_escrow::get_current_escrow`] to access the active escrow.
 
+pub(crate) mod base58;
+pub mod bytes;
+pub mod codec;
 pub mod constants;
 pub mod current_tx;
+pub mod kv_store;
 pub mod ledger_objects;
 pub mod locator;
+pub(crate) mod sha256;
+pub mod state;
+pub mod state_machine;
 pub mod types;
@@ -0,0 +1,454 @@
+//! Typed contract state stored in an escrow's `Data` field.
+//!
+//! A [`ContractState`] is a Rust struct that is encoded into the escrow's `Data` field behind a
+//! one-byte schema version. Fields are written in declaration order: integers big-endian,
+//! `AccountID`s and hashes as raw bytes, `Amount`s in their canonical 8/33/48-byte form, and
+//! bounded blobs behind a VL length prefix. Derive the trait with `#[derive(ContractState)]`:
+//!
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::state::{ContractState, load_state, store_state};
+//! use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+//! use xrpl_wasm_stdlib::core::types::blob::Blob;
+//! use xrpl_wasm_stdlib::host::ResultExt;
+//!
+//! #[derive(ContractState)]
+//! #[contract_state(version = 2)]
+//! struct Approvals {
+//!     approver: AccountID,
+//!     count: u32,
+//!     note: Blob<32>,
+//! }
+//!
+//! let mut state: Approvals = load_state().unwrap_or_panic();
+//! state.count += 1;
+//! store_state(&state).unwrap_or_panic();
+//! ```
+//!
+//! Loading fails with `Error::StateVersionMismatch` if the stored version differs from
+//! [`ContractState::VERSION`], and storing fails with `Error::DataFieldTooLarge` if the encoded
+//! state does not fit in [`XRPL_CONTRACT_DATA_SIZE`] bytes.
+
+pub use xrpl_address_macro::ContractState;
+
+use crate::core::codec::field_header::{decode_vl_length, encode_vl_length};
+use crate::core::ledger_objects::current_escrow::{CurrentEscrow, get_current_escrow};
+use crate::core::ledger_objects::traits::CurrentEscrowFields;
+use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::core::types::amount::Amount;
+use crate::core::types::blob::Blob;
+use crate::core::types::contract_data::{ContractData, XRPL_CONTRACT_DATA_SIZE};
+use crate::core::types::currency::{CURRENCY_SIZE, Currency};
+use crate::core::types::uint::UInt;
+use crate::host::Error::{DataFieldTooLarge, FieldNotFound, InvalidDecoding, StateVersionMismatch};
+use crate::host::Result;
+
+/// A value that can be written to and read back from contract state.
+///
+/// Implemented for integers, `()`, `bool`, `AccountID`, `Amount`, `Currency`, `UInt<N>` hashes,
+/// `Blob<N>`, byte arrays and `Option<T>`. `#[derive(ContractState)]` also implements it for the
+/// derived struct, so states can nest.
+pub trait StateField: Sized {
+    fn encode_state(&self, encoder: &mut StateEncoder) -> Result<()>;
+    fn decode_state(decoder: &mut StateDecoder) -> Result<Self>;
+}
+
+/// A struct persisted in an escrow's `Data` field behind a schema version byte.
+pub trait ContractState: StateField {
+    /// Schema version written before the fields. Bump it whenever the layout changes.
+    const VERSION: u8;
+}
+
+/// Writes state fields into a [`ContractData`] buffer.
+pub struct StateEncoder {
+    data: ContractData,
+}
+
+impl StateEncoder {
+    pub fn new() -> Self {
+        StateEncoder {
+            data: ContractData {
+                data: [0; XRPL_CONTRACT_DATA_SIZE],
+                len: 0,
+            },
+        }
+    }
+
+    /// Appends raw bytes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the bytes fit
+    /// * `Err(Error::DataFieldTooLarge)` - If the state would exceed `XRPL_CONTRACT_DATA_SIZE`
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<()> {
+        let end = self.data.len + bytes.len();
+        if end > XRPL_CONTRACT_DATA_SIZE {
+            return Result::Err(DataFieldTooLarge);
+        }
+        self.data.data[self.data.len..end].copy_from_slice(bytes);
+        self.data.len = end;
+        Result::Ok(())
+    }
+
+    /// Appends any [`StateField`].
+    #[inline]
+    pub fn write<T: StateField>(&mut self, value: &T) -> Result<()> {
+        value.encode_state(self)
+    }
+
+    /// The encoded bytes so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data.data[..self.data.len]
+    }
+
+    pub fn into_contract_data(self) -> ContractData {
+        self.data
+    }
+}
+
+impl Default for StateEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reads state fields back from encoded bytes.
+pub struct StateDecoder<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> StateDecoder<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        StateDecoder { bytes, pos: 0 }
+    }
+
+    /// Consumes the next `len` bytes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&[u8])` - The bytes
+    /// * `Err(Error::InvalidDecoding)` - If fewer than `len` bytes remain
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        match self.bytes.get(self.pos..self.pos + len) {
+            Some(bytes) => {
+                self.pos += len;
+                Result::Ok(bytes)
+            }
+            None => Result::Err(InvalidDecoding),
+        }
+    }
+
+    /// Consumes the next `N` bytes as an array.
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self.read_bytes(N)?;
+        let mut array = [0u8; N];
+        array.copy_from_slice(bytes);
+        Result::Ok(array)
+    }
+
+    /// Reads any [`StateField`].
+    #[inline]
+    pub fn read<T: StateField>(&mut self) -> Result<T> {
+        T::decode_state(self)
+    }
+
+    /// The number of bytes not yet consumed.
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+}
+
+/// Encodes `state` (version byte followed by its fields) into a [`ContractData`].
+pub fn encode_state<T: ContractState>(state: &T) -> Result<ContractData> {
+    let mut encoder = StateEncoder::new();
+    encoder.write(&T::VERSION)?;
+    encoder.write(state)?;
+    Result::Ok(encoder.into_contract_data())
+}
+
+/// Decodes a state previously written by [`encode_state`].
+///
+/// # Returns
+///
+/// * `Ok(T)` - The decoded state
+/// * `Err(Error::FieldNotFound)` - If `bytes` is empty (no state has been stored yet)
+/// * `Err(Error::StateVersionMismatch)` - If the stored version differs from `T::VERSION`
+/// * `Err(Error::InvalidDecoding)` - If the bytes are truncated or have trailing data
+pub fn decode_state<T: ContractState>(bytes: &[u8]) -> Result<T> {
+    match bytes.first() {
+        None => return Result::Err(FieldNotFound),
+        Some(&version) if version != T::VERSION => return Result::Err(StateVersionMismatch),
+        Some(_) => {}
+    }
+    let mut decoder = StateDecoder::new(&bytes[1..]);
+    let state = decoder.read::<T>()?;
+    if decoder.remaining() != 0 {
+        return Result::Err(InvalidDecoding);
+    }
+    Result::Ok(state)
+}
+
+/// Loads the state stored in the current escrow's `Data` field.
+#[inline]
+pub fn load_state<T: ContractState>() -> Result<T> {
+    let data = get_current_escrow().get_data()?;
+    decode_state(&data.data[..data.len])
+}
+
+/// Encodes `state` and writes it to the current escrow's `Data` field.
+#[inline]
+pub fn store_state<T: ContractState>(state: &T) -> Result<()> {
+    CurrentEscrow::update_current_escrow_data(encode_state(state)?)
+}
+
+macro_rules! impl_state_field_for_int {
+    ($($t:ty),*) => {
+        $(
+            impl StateField for $t {
+                #[inline]
+                fn encode_state(&self, encoder: &mut StateEncoder) -> Result<()> {
+                    encoder.write_bytes(&self.to_be_bytes())
+                }
+
+                #[inline]
+                fn decode_state(decoder: &mut StateDecoder) -> Result<Self> {
+                    Result::Ok(<$t>::from_be_bytes(decoder.read_array()?))
+                }
+            }
+        )*
+    };
+}
+
+impl_state_field_for_int!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl StateField for () {
+    fn encode_state(&self, _encoder: &mut StateEncoder) -> Result<()> {
+        Result::Ok(())
+    }
+
+    fn decode_state(_decoder: &mut StateDecoder) -> Result<Self> {
+        Result::Ok(())
+    }
+}
+
+impl StateField for bool {
+    fn encode_state(&self, encoder: &mut StateEncoder) -> Result<()> {
+        encoder.write_bytes(&[*self as u8])
+    }
+
+    fn decode_state(decoder: &mut StateDecoder) -> Result<Self> {
+        match decoder.read_array::<1>()? {
+            [0] => Result::Ok(false),
+            [1] => Result::Ok(true),
+            _ => Result::Err(InvalidDecoding),
+        }
+    }
+}
+
+impl<const N: usize> StateField for [u8; N] {
+    fn encode_state(&self, encoder: &mut StateEncoder) -> Result<()> {
+        encoder.write_bytes(self)
+    }
+
+    fn decode_state(decoder: &mut StateDecoder) -> Result<Self> {
+        decoder.read_array()
+    }
+}
+
+impl<const N: usize> StateField for UInt<N> {
+    fn encode_state(&self, encoder: &mut StateEncoder) -> Result<()> {
+        encoder.write_bytes(&self.0)
+    }
+
+    fn decode_state(decoder: &mut StateDecoder) -> Result<Self> {
+        Result::Ok(UInt(decoder.read_array()?))
+    }
+}
+
+impl StateField for AccountID {
+    fn encode_state(&self, encoder: &mut StateEncoder) -> Result<()> {
+        encoder.write_bytes(&self.0)
+    }
+
+    fn decode_state(decoder: &mut StateDecoder) -> Result<Self> {
+        Result::Ok(AccountID(decoder.read_array::<ACCOUNT_ID_SIZE>()?))
+    }
+}
+
+impl StateField for Currency {
+    fn encode_state(&self, encoder: &mut StateEncoder) -> Result<()> {
+        encoder.write_bytes(&self.0)
+    }
+
+    fn decode_state(decoder: &mut StateDecoder) -> Result<Self> {
+        Result::Ok(Currency(decoder.read_array::<CURRENCY_SIZE>()?))
+    }
+}
+
+impl StateField for Amount {
+    fn encode_state(&self, encoder: &mut StateEncoder) -> Result<()> {
+        let (bytes, len) = self.to_canonical_bytes()?;
+        encoder.write_bytes(&bytes[..len])
+    }
+
+    fn decode_state(decoder: &mut StateDecoder) -> Result<Self> {
+        let first = match decoder.bytes.get(decoder.pos) {
+            Some(&first) => first,
+            None => return Result::Err(InvalidDecoding),
+        };
+        Amount::from_bytes(decoder.read_bytes(Amount::serialized_len(first))?)
+    }
+}
+
+impl<const N: usize> StateField for Blob<N> {
+    fn encode_state(&self, encoder: &mut StateEncoder) -> Result<()> {
+        let (prefix, prefix_len) = match encode_vl_length(self.len) {
+            Result::Ok(prefix) => prefix,
+            Result::Err(_) => return Result::Err(DataFieldTooLarge),
+        };
+        encoder.write_bytes(&prefix[..prefix_len])?;
+        encoder.write_bytes(self.as_slice())
+    }
+
+    fn decode_state(decoder: &mut StateDecoder) -> Result<Self> {
+        let (len, prefix_len) = decode_vl_length(&decoder.bytes[decoder.pos..])?;
+        if len > N {
+            return Result::Err(InvalidDecoding);
+        }
+        decoder.pos += prefix_len;
+        Result::Ok(Blob::from_slice(decoder.read_bytes(len)?))
+    }
+}
+
+impl<T: StateField> StateField for Option<T> {
+    fn encode_state(&self, encoder: &mut StateEncoder) -> Result<()> {
+        match self {
+            Some(value) => {
+                encoder.write(&true)?;
+                encoder.write(value)
+            }
+            None => encoder.write(&false),
+        }
+    }
+
+    fn decode_state(decoder: &mut StateDecoder) -> Result<Self> {
+        if decoder.read::<bool>()? {
+            decoder.read().map(Some)
+        } else {
+            Result::Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::uint::Hash256;
+
+    #[derive(ContractState, Debug, PartialEq)]
+    #[contract_state(version = 3)]
+    struct EscrowState {
+        owner: AccountID,
+        stage: u8,
+        approvals: u32,
+        deadline: Option<u64>,
+        paid: Amount,
+        memo: Blob<16>,
+        digest: Hash256,
+    }
+
+    #[derive(ContractState, Debug, PartialEq)]
+    struct Counter(u64, bool);
+
+    #[derive(ContractState, Debug, PartialEq)]
+    struct Outer {
+        counter: Counter,
+        limit: i64,
+    }
+
+    fn sample() -> EscrowState {
+        EscrowState {
+            owner: AccountID([7u8; 20]),
+            stage: 2,
+            approvals: 0x0102_0304,
+            deadline: Some(1_000),
+            paid: Amount::XRP { num_drops: 25 },
+            memo: Blob::from_slice(b"hello"),
+            digest: UInt([0xAB; 32]),
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let data = encode_state(&sample()).unwrap();
+        // version + account + stage + approvals + option tag + u64 + XRP amount + VL blob + hash
+        assert_eq!(data.len, 1 + 20 + 1 + 4 + 1 + 8 + 8 + 6 + 32);
+        assert_eq!(data.data[0], 3);
+        assert_eq!(data.data[22..26], [1, 2, 3, 4]);
+
+        let decoded: EscrowState = decode_state(&data.data[..data.len]).unwrap();
+        assert_eq!(decoded, sample());
+    }
+
+    #[test]
+    fn test_tuple_and_nested_states() {
+        assert_eq!(Counter::VERSION, 1);
+        let outer = Outer {
+            counter: Counter(9, true),
+            limit: -4,
+        };
+        let data = encode_state(&outer).unwrap();
+        assert_eq!(data.len, 1 + 8 + 1 + 8);
+        let decoded: Outer = decode_state(&data.data[..data.len]).unwrap();
+        assert_eq!(decoded, outer);
+    }
+
+    #[test]
+    fn test_version_mismatch() {
+        let mut data = encode_state(&sample()).unwrap();
+        data.data[0] = 4;
+        let error = decode_state::<EscrowState>(&data.data[..data.len])
+            .err()
+            .unwrap();
+        assert_eq!(error.code(), StateVersionMismatch.code());
+
+        let error = decode_state::<EscrowState>(&[]).err().unwrap();
+        assert_eq!(error.code(), FieldNotFound.code());
+    }
+
+    #[test]
+    fn test_truncated_and_trailing_bytes() {
+        let data = encode_state(&Counter(1, false)).unwrap();
+        assert!(decode_state::<Counter>(&data.data[..data.len - 1]).is_err());
+        assert!(decode_state::<Counter>(&data.data[..data.len + 1]).is_err());
+
+        // Booleans must be 0 or 1
+        let mut bytes = [0u8; 10];
+        bytes[0] = 1;
+        bytes[9] = 2;
+        assert!(decode_state::<Counter>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_size_overflow() {
+        #[derive(ContractState)]
+        struct TooLarge {
+            a: [u8; 2048],
+            b: [u8; 2048],
+        }
+        let state = TooLarge {
+            a: [0; 2048],
+            b: [0; 2048],
+        };
+        let error = encode_state(&state).err().unwrap();
+        assert_eq!(error.code(), DataFieldTooLarge.code());
+    }
+
+    #[test]
+    fn test_store_and_load_through_host() {
+        assert!(store_state(&Counter(5, true)).is_ok());
+        // The test host fills the Data field with zeros, i.e. version 0
+        let error = load_state::<Counter>().err().unwrap();
+        assert_eq!(error.code(), StateVersionMismatch.code());
+    }
+}
@@ -0,0 +1,377 @@
+//! Multi-stage escrows driven by a declared state machine.
+//!
+//! An escrow that may only finish after several steps declares its stages, the transitions between
+//! them and a guard for each transition. The current stage and any metadata are persisted in the
+//! escrow's `Data` field, so every `finish()` call picks up where the previous one stopped:
+//!
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::state_machine::{self, Stage, StateMachine, Transition};
+//! use xrpl_wasm_stdlib::host::Result;
+//!
+//! #[derive(Clone, Copy, PartialEq, Eq)]
+//! enum Step {
+//!     AwaitingApproval,
+//!     AwaitingOracle,
+//!     Released,
+//! }
+//!
+//! impl Stage for Step {
+//!     fn id(self) -> u8 {
+//!         self as u8
+//!     }
+//!     fn from_id(id: u8) -> Option<Self> {
+//!         [Step::AwaitingApproval, Step::AwaitingOracle, Step::Released].get(id as usize).copied()
+//!     }
+//!     fn name(self) -> &'static str {
+//!         match self {
+//!             Step::AwaitingApproval => "AwaitingApproval",
+//!             Step::AwaitingOracle => "AwaitingOracle",
+//!             Step::Released => "Released",
+//!         }
+//!     }
+//!     fn allows_finish(self) -> bool {
+//!         self == Step::Released
+//!     }
+//! }
+//!
+//! fn approved(approvals: &mut u32) -> Result<bool> {
+//!     *approvals += 1;
+//!     Result::Ok(*approvals >= 2)
+//! }
+//!
+//! fn oracle_confirms(_: &mut u32) -> Result<bool> {
+//!     Result::Ok(true)
+//! }
+//!
+//! struct Release;
+//!
+//! impl StateMachine for Release {
+//!     type Stage = Step;
+//!     type Meta = u32;
+//!     const INITIAL: Step = Step::AwaitingApproval;
+//!     const TRANSITIONS: &'static [Transition<Step, u32>] = &[
+//!         Transition { from: Step::AwaitingApproval, to: Step::AwaitingOracle, guard: approved },
+//!         Transition { from: Step::AwaitingOracle, to: Step::Released, guard: oracle_confirms },
+//!     ];
+//! }
+//!
+//! #[unsafe(no_mangle)]
+//! pub extern "C" fn finish() -> i32 {
+//!     match state_machine::run::<Release>() {
+//!         Result::Ok(release) => release as i32,
+//!         Result::Err(e) => e.code(),
+//!     }
+//! }
+//! ```
+//!
+//! The `Data` field holds a version byte, the stage id and the metadata encoded as a
+//! [`StateField`]. An escrow with no `Data` starts in [`StateMachine::INITIAL`].
+
+use crate::core::bytes::ByteWriter;
+use crate::core::ledger_objects::current_escrow::{CurrentEscrow, get_current_escrow};
+use crate::core::ledger_objects::traits::CurrentEscrowFields;
+use crate::core::state::{StateDecoder, StateEncoder, StateField};
+use crate::core::types::contract_data::ContractData;
+use crate::host::Error::{FieldNotFound, InvalidDecoding, StateVersionMismatch};
+use crate::host::Result;
+use crate::host::trace::{DataRepr, trace_data};
+
+/// One stage of a [`StateMachine`].
+pub trait Stage: Copy + Eq + 'static {
+    /// A stable identifier persisted in the escrow's `Data` field.
+    fn id(self) -> u8;
+
+    /// The inverse of [`Stage::id`]; `None` for unknown ids.
+    fn from_id(id: u8) -> Option<Self>;
+
+    /// A name used in transition traces.
+    fn name(self) -> &'static str;
+
+    /// Whether `finish()` should release the escrow in this stage.
+    fn allows_finish(self) -> bool;
+}
+
+/// A guarded edge between two stages.
+///
+/// The guard may update the metadata (for example to count approvals) even when it returns
+/// `Ok(false)`; the update is persisted either way.
+pub struct Transition<S, M> {
+    pub from: S,
+    pub to: S,
+    pub guard: fn(&mut M) -> Result<bool>,
+}
+
+/// The declaration of a multi-stage escrow.
+pub trait StateMachine {
+    type Stage: Stage;
+
+    /// Data persisted alongside the stage. Use `()` if none is needed.
+    type Meta: StateField + Default + 'static;
+
+    /// Schema version written before the stage. Bump it whenever the stages or metadata change.
+    const VERSION: u8 = 1;
+
+    /// The stage of an escrow that has no state yet.
+    const INITIAL: Self::Stage;
+
+    /// Transitions are tried in order; the first one leaving the current stage whose guard passes
+    /// is taken.
+    const TRANSITIONS: &'static [Transition<Self::Stage, Self::Meta>];
+}
+
+/// The runtime state of a [`StateMachine`]: its current stage and metadata.
+pub struct Machine<M: StateMachine> {
+    stage: M::Stage,
+    meta: M::Meta,
+}
+
+impl<M: StateMachine> Machine<M> {
+    /// A machine in the initial stage with default metadata.
+    pub fn new() -> Self {
+        Machine {
+            stage: M::INITIAL,
+            meta: M::Meta::default(),
+        }
+    }
+
+    /// Decodes a machine previously written by [`Machine::encode`].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Machine)` - The decoded machine, or a new one if `bytes` is empty
+    /// * `Err(Error::StateVersionMismatch)` - If the stored version differs from `M::VERSION`
+    /// * `Err(Error::InvalidDecoding)` - If the stage id is unknown or the metadata is malformed
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut decoder = StateDecoder::new(bytes);
+        if decoder.remaining() == 0 {
+            return Result::Ok(Self::new());
+        }
+        if decoder.read::<u8>()? != M::VERSION {
+            return Result::Err(StateVersionMismatch);
+        }
+        let stage = match M::Stage::from_id(decoder.read::<u8>()?) {
+            Some(stage) => stage,
+            None => return Result::Err(InvalidDecoding),
+        };
+        let meta = decoder.read()?;
+        if decoder.remaining() != 0 {
+            return Result::Err(InvalidDecoding);
+        }
+        Result::Ok(Machine { stage, meta })
+    }
+
+    /// Loads the machine from the current escrow's `Data` field.
+    pub fn load() -> Result<Self> {
+        match get_current_escrow().get_data() {
+            Result::Ok(data) => Self::from_bytes(&data.data[..data.len]),
+            Result::Err(FieldNotFound) => Result::Ok(Self::new()),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    /// Encodes the version, stage id and metadata.
+    pub fn encode(&self) -> Result<ContractData> {
+        let mut encoder = StateEncoder::new();
+        for byte in [M::VERSION, self.stage.id()] {
+            encoder.write(&byte)?;
+        }
+        encoder.write(&self.meta)?;
+        Result::Ok(encoder.into_contract_data())
+    }
+
+    /// Writes the machine to the current escrow's `Data` field.
+    pub fn store(&self) -> Result<()> {
+        CurrentEscrow::update_current_escrow_data(self.encode()?)
+    }
+
+    pub fn stage(&self) -> M::Stage {
+        self.stage
+    }
+
+    pub fn meta(&self) -> &M::Meta {
+        &self.meta
+    }
+
+    pub fn meta_mut(&mut self) -> &mut M::Meta {
+        &mut self.meta
+    }
+
+    /// Whether `finish()` should release the escrow in the current stage.
+    pub fn finish_result(&self) -> bool {
+        self.stage.allows_finish()
+    }
+
+    /// Takes at most one transition out of the current stage, tracing it if taken.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - If a guard passed and the stage changed
+    /// * `Ok(false)` - If no transition out of the current stage is allowed
+    /// * `Err(Error)` - The first error returned by a guard
+    pub fn step(&mut self) -> Result<bool> {
+        for transition in M::TRANSITIONS {
+            if transition.from != self.stage {
+                continue;
+            }
+            if (transition.guard)(&mut self.meta)? {
+                trace_transition(self.stage, transition.to);
+                self.stage = transition.to;
+                return Result::Ok(true);
+            }
+        }
+        Result::Ok(false)
+    }
+
+    /// Takes transitions until none is allowed, returning how many were taken.
+    ///
+    /// At most `M::TRANSITIONS.len()` transitions are taken per call, so cycles cannot loop forever.
+    pub fn advance(&mut self) -> Result<usize> {
+        let mut taken = 0;
+        while taken < M::TRANSITIONS.len() {
+            if !self.step()? {
+                break;
+            }
+            taken += 1;
+        }
+        Result::Ok(taken)
+    }
+}
+
+impl<M: StateMachine> Default for Machine<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Runs one `finish()` attempt: loads the machine, advances it, persists it if anything changed
+/// and returns whether the escrow may finish.
+pub fn run<M: StateMachine>() -> Result<bool> {
+    let mut machine = Machine::<M>::load()?;
+    let stored = machine.encode()?;
+    machine.advance()?;
+    let updated = machine.encode()?;
+    if updated.data[..updated.len] != stored.data[..stored.len] {
+        CurrentEscrow::update_current_escrow_data(updated)?;
+    }
+    Result::Ok(machine.finish_result())
+}
+
+/// Traces `from -> to` as a single line.
+fn trace_transition<S: Stage>(from: S, to: S) {
+    let mut buf = [0u8; 96];
+    let mut writer = ByteWriter::new(&mut buf);
+    // Overlong names are truncated rather than failing the transition.
+    for part in [from.name(), " -> ", to.name()] {
+        let bytes = part.as_bytes();
+        let len = bytes.len().min(writer.remaining());
+        let _ = writer.write_bytes(&bytes[..len]);
+    }
+    let _ = trace_data(
+        "StateMachine transition:",
+        writer.as_bytes(),
+        DataRepr::AsUTF8,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Step {
+        Approval,
+        Oracle,
+        Done,
+    }
+
+    impl Stage for Step {
+        fn id(self) -> u8 {
+            self as u8
+        }
+
+        fn from_id(id: u8) -> Option<Self> {
+            [Step::Approval, Step::Oracle, Step::Done]
+                .get(id as usize)
+                .copied()
+        }
+
+        fn name(self) -> &'static str {
+            match self {
+                Step::Approval => "Approval",
+                Step::Oracle => "Oracle",
+                Step::Done => "Done",
+            }
+        }
+
+        fn allows_finish(self) -> bool {
+            self == Step::Done
+        }
+    }
+
+    fn two_approvals(count: &mut u32) -> Result<bool> {
+        *count += 1;
+        Result::Ok(*count >= 2)
+    }
+
+    fn always(_: &mut u32) -> Result<bool> {
+        Result::Ok(true)
+    }
+
+    struct Release;
+
+    impl StateMachine for Release {
+        type Stage = Step;
+        type Meta = u32;
+        const INITIAL: Step = Step::Approval;
+        const TRANSITIONS: &'static [Transition<Step, u32>] = &[
+            Transition {
+                from: Step::Approval,
+                to: Step::Oracle,
+                guard: two_approvals,
+            },
+            Transition {
+                from: Step::Oracle,
+                to: Step::Done,
+                guard: always,
+            },
+        ];
+    }
+
+    #[test]
+    fn test_advance_persists_guard_metadata() {
+        let mut machine = Machine::<Release>::from_bytes(&[]).unwrap();
+        assert_eq!(machine.stage(), Step::Approval);
+
+        // First call: the guard records an approval but does not pass
+        assert_eq!(machine.advance().unwrap(), 0);
+        assert!(!machine.finish_result());
+        let data = machine.encode().unwrap();
+        assert_eq!(&data.data[..data.len], &[1, 0, 0, 0, 0, 1]);
+
+        // Second call: both remaining transitions are taken
+        let mut machine = Machine::<Release>::from_bytes(&data.data[..data.len]).unwrap();
+        assert_eq!(machine.advance().unwrap(), 2);
+        assert_eq!(machine.stage(), Step::Done);
+        assert_eq!(*machine.meta(), 2);
+        assert!(machine.finish_result());
+
+        // No transitions leave the final stage
+        assert!(!machine.step().unwrap());
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_bad_data() {
+        let error = Machine::<Release>::from_bytes(&[2, 0, 0, 0, 0, 0])
+            .err()
+            .unwrap();
+        assert_eq!(error.code(), StateVersionMismatch.code());
+        let error = Machine::<Release>::from_bytes(&[1, 9, 0, 0, 0, 0])
+            .err()
+            .unwrap();
+        assert_eq!(error.code(), InvalidDecoding.code());
+        assert!(Machine::<Release>::from_bytes(&[1, 0, 0]).is_err());
+
+        // The test host returns a zero-filled Data field, which has version 0
+        assert!(run::<Release>().is_err());
+    }
+}
@@ -3,15 +3,28 @@
 //! This type wraps a 20-byte AccountID and is returned by many accessors.
 //! See also: <https://xrpl.org/docs/references/protocol/common-fields#accountid-fields>
 
+use crate::core::base58::{decode_check, encode_check};
 use crate::core::current_tx::CurrentTxFieldGetter;
 use crate::core::ledger_objects::LedgerObjectFieldGetter;
+use crate::core::locator::Locator;
 use crate::host::field_helpers::{
     get_fixed_size_field_with_expected_bytes, get_fixed_size_field_with_expected_bytes_optional,
+    get_fixed_size_nested_field_with_expected_bytes,
+    get_fixed_size_nested_field_with_expected_bytes_optional,
+};
+use crate::host::{
+    Error, Result, get_current_ledger_obj_field, get_current_ledger_obj_nested_field,
+    get_ledger_obj_field, get_ledger_obj_nested_field, get_tx_field, get_tx_nested_field,
 };
-use crate::host::{Result, get_current_ledger_obj_field, get_ledger_obj_field, get_tx_field};
 
 pub const ACCOUNT_ID_SIZE: usize = 20;
 
+/// Maximum length of an r-address (classic address).
+pub const R_ADDRESS_MAX_SIZE: usize = 35;
+
+/// Version byte prefixed to an AccountID before base58check encoding.
+const ACCOUNT_ID_VERSION: u8 = 0x00;
+
 /// A 20-byte account identifier on the XRP Ledger.
 ///
 /// AccountIDs are derived from a public key and uniquely identify accounts on the ledger.
@@ -27,6 +40,59 @@ pub const ACCOUNT_ID_SIZE: usize = 20;
 #[repr(C)]
 pub struct AccountID(pub [u8; ACCOUNT_ID_SIZE]);
 
+impl AccountID {
+    /// Encodes the account as an r-address (classic address) into `out` and returns it.
+    ///
+    /// This runs base58check with a built-in SHA-256 and needs no allocation. For addresses known
+    /// at compile time, use the [`r_address!`](crate::r_address) macro instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use xrpl_wasm_stdlib::core::types::account_id::{AccountID, R_ADDRESS_MAX_SIZE};
+    /// use xrpl_wasm_stdlib::r_address;
+    ///
+    /// let account = AccountID(r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"));
+    /// let mut buf = [0u8; R_ADDRESS_MAX_SIZE];
+    /// assert_eq!(account.to_r_address(&mut buf), "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+    /// ```
+    pub fn to_r_address<'a>(&self, out: &'a mut [u8; R_ADDRESS_MAX_SIZE]) -> &'a str {
+        let mut payload = [0u8; 1 + ACCOUNT_ID_SIZE];
+        payload[0] = ACCOUNT_ID_VERSION;
+        payload[1..].copy_from_slice(&self.0);
+        // 21 bytes plus the checksum never encode to more than 35 base58 characters.
+        let len = encode_check(&payload, out).unwrap_or(0);
+        // The base58 alphabet is ASCII.
+        unsafe { core::str::from_utf8_unchecked(&out[..len]) }
+    }
+
+    /// Parses an r-address (classic address), e.g. one read from a memo.
+    ///
+    /// Returns [`Error::InvalidAccount`] if `address` is not valid base58, its checksum does not
+    /// match, or it does not encode a 20-byte AccountID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+    ///
+    /// let account = AccountID::from_r_address(b"rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").unwrap();
+    /// assert_eq!(account.0[0], 0xB5);
+    /// assert!(AccountID::from_r_address(b"rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTi").is_err());
+    /// ```
+    pub fn from_r_address(address: &[u8]) -> Result<AccountID> {
+        let mut payload = [0u8; 1 + ACCOUNT_ID_SIZE];
+        match decode_check(address, &mut payload) {
+            Some(len) if len == payload.len() && payload[0] == ACCOUNT_ID_VERSION => {
+                let mut account = [0u8; ACCOUNT_ID_SIZE];
+                account.copy_from_slice(&payload[1..]);
+                Ok(AccountID(account))
+            }
+            _ => Err(Error::InvalidAccount),
+        }
+    }
+}
+
 impl From<[u8; ACCOUNT_ID_SIZE]> for AccountID {
     fn from(value: [u8; ACCOUNT_ID_SIZE]) -> Self {
         AccountID(value)
@@ -47,46 +113,85 @@ impl From<[u8; ACCOUNT_ID_SIZE]> for AccountID {
 impl LedgerObjectFieldGetter for AccountID {
     #[inline]
     fn get_from_current_ledger_obj(field_code: i32) -> Result<Self> {
-        match get_fixed_size_field_with_expected_bytes::<ACCOUNT_ID_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes::<ACCOUNT_ID_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_current_ledger_obj_field(fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.into()),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.into())
     }
 
     #[inline]
     fn get_from_current_ledger_obj_optional(field_code: i32) -> Result<Option<Self>> {
-        match get_fixed_size_field_with_expected_bytes_optional::<ACCOUNT_ID_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes_optional::<ACCOUNT_ID_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_current_ledger_obj_field(fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.map(|b| b.into())),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
     }
 
     #[inline]
     fn get_from_ledger_obj(register_num: i32, field_code: i32) -> Result<Self> {
-        match get_fixed_size_field_with_expected_bytes::<ACCOUNT_ID_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes::<ACCOUNT_ID_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_ledger_obj_field(register_num, fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.into()),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.into())
     }
 
     #[inline]
     fn get_from_ledger_obj_optional(register_num: i32, field_code: i32) -> Result<Option<Self>> {
-        match get_fixed_size_field_with_expected_bytes_optional::<ACCOUNT_ID_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes_optional::<ACCOUNT_ID_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_ledger_obj_field(register_num, fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.map(|b| b.into())),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested(locator: &Locator) -> Result<Self> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes::<ACCOUNT_ID_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(buffer.into())
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes_optional::<ACCOUNT_ID_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested(register_num: i32, locator: &Locator) -> Result<Self> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes::<ACCOUNT_ID_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(buffer.into())
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested_optional(
+        register_num: i32,
+        locator: &Locator,
+    ) -> Result<Option<Self>> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes_optional::<ACCOUNT_ID_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
     }
 }
 
@@ -104,23 +209,104 @@ impl LedgerObjectFieldGetter for AccountID {
 impl CurrentTxFieldGetter for AccountID {
     #[inline]
     fn get_from_current_tx(field_code: i32) -> Result<Self> {
-        match get_fixed_size_field_with_expected_bytes::<ACCOUNT_ID_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes::<ACCOUNT_ID_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_tx_field(fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.into()),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.into())
     }
 
     #[inline]
     fn get_from_current_tx_optional(field_code: i32) -> Result<Option<Self>> {
-        match get_fixed_size_field_with_expected_bytes_optional::<ACCOUNT_ID_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes_optional::<ACCOUNT_ID_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_tx_field(fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.map(|b| b.into())),
-            Result::Err(e) => Result::Err(e),
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested(locator: &Locator) -> Result<Self> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes::<ACCOUNT_ID_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )?;
+        Result::Ok(buffer.into())
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes_optional::<ACCOUNT_ID_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GENESIS: &[u8] = b"rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+
+    #[test]
+    fn test_r_address_known_accounts() {
+        let mut buf = [0u8; R_ADDRESS_MAX_SIZE];
+        let genesis = AccountID(crate::r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"));
+        assert_eq!(genesis.to_r_address(&mut buf).as_bytes(), GENESIS);
+        assert_eq!(AccountID::from_r_address(GENESIS), Ok(genesis));
+
+        // ACCOUNT_ZERO and ACCOUNT_ONE have leading zero bytes, which encode as `r`
+        let zero = AccountID([0u8; 20]);
+        assert_eq!(zero.to_r_address(&mut buf), "rrrrrrrrrrrrrrrrrrrrrhoLvTp");
+        assert_eq!(
+            AccountID::from_r_address(b"rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+            Ok(zero)
+        );
+        let mut one = [0u8; 20];
+        one[19] = 1;
+        assert_eq!(
+            AccountID(one).to_r_address(&mut buf),
+            "rrrrrrrrrrrrrrrrrrrrBZbvji"
+        );
+        assert_eq!(
+            AccountID::from_r_address(b"rrrrrrrrrrrrrrrrrrrrBZbvji"),
+            Ok(AccountID(one))
+        );
+    }
+
+    #[test]
+    fn test_r_address_roundtrip() {
+        let mut buf = [0u8; R_ADDRESS_MAX_SIZE];
+        for fill in [0x01u8, 0x7F, 0xFF] {
+            let account = AccountID([fill; 20]);
+            let address = account.to_r_address(&mut buf);
+            assert!(address.starts_with('r'));
+            assert_eq!(AccountID::from_r_address(address.as_bytes()), Ok(account));
         }
     }
+
+    #[test]
+    fn test_from_r_address_rejects_invalid() {
+        // Bad checksum (last character changed)
+        assert_eq!(
+            AccountID::from_r_address(b"rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTi"),
+            Err(Error::InvalidAccount)
+        );
+        // Not in the alphabet
+        assert_eq!(
+            AccountID::from_r_address(b"0Hb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+            Err(Error::InvalidAccount)
+        );
+        // Truncated, empty, or with trailing data
+        assert!(AccountID::from_r_address(&GENESIS[..GENESIS.len() - 1]).is_err());
+        assert!(AccountID::from_r_address(b"").is_err());
+        assert!(AccountID::from_r_address(b"rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh ").is_err());
+        // Valid base58check, but not an AccountID (a node public key)
+        assert!(
+            AccountID::from_r_address(b"n9KAa2zVWjPHgfzsE3iZ8HAbzJtPrnoh4H2M2HgE7dfqtvyEb1KJ")
+                .is_err()
+        );
+    }
 }
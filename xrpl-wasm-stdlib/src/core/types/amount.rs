@@ -1,18 +1,37 @@
+use core::cmp::Ordering;
+
+use crate::core::constants::MAX_DROPS;
 use crate::core::current_tx::CurrentTxFieldGetter;
 use crate::core::ledger_objects::LedgerObjectFieldGetter;
+use crate::core::locator::Locator;
 use crate::core::types::account_id::AccountID;
 use crate::core::types::currency::Currency;
 use crate::core::types::mpt_id::MptId;
 use crate::core::types::opaque_float::OpaqueFloat;
 use crate::host;
-use crate::host::Error::InternalError;
-use crate::host::Result::{Err, Ok};
-use crate::host::field_helpers::{get_variable_size_field, get_variable_size_field_optional};
-use crate::host::trace::trace_num;
-use crate::host::{Result, get_current_ledger_obj_field, get_ledger_obj_field, get_tx_field};
+use crate::host::Error::{
+    AmountOverflow, AssetMismatch, InternalError, InvalidDecoding, InvalidParams,
+};
+use crate::host::error_codes::match_result_code;
+use crate::host::field_helpers::{
+    get_variable_size_field, get_variable_size_field_optional, get_variable_size_nested_field,
+    get_variable_size_nested_field_optional,
+};
+use crate::host::trace::trace_error_num;
+use crate::host::{
+    FLOAT_ROUNDING_MODES_TO_NEAREST, Result, float_add, float_compare, float_subtract,
+    get_current_ledger_obj_field, get_current_ledger_obj_nested_field, get_ledger_obj_field,
+    get_ledger_obj_nested_field, get_tx_field, get_tx_nested_field,
+};
 
 pub const AMOUNT_SIZE: usize = 48;
 
+/// Length of a serialized XRP amount.
+pub const XRP_AMOUNT_SIZE: usize = 8;
+
+/// Length of a serialized MPT amount.
+pub const MPT_AMOUNT_SIZE: usize = 33;
+
 /// A zero-cost abstraction for XRPL tokens. Tokens conform to the following binary layout:
 ///
 /// ```markdown
@@ -112,6 +131,49 @@ pub enum Amount {
 
 const MASK_57_BIT: u64 = 0x01FFFFFFFFFFFFFFu64;
 
+/// Mantissa bits of an IOU amount's `OpaqueFloat`; all zero only for the value zero.
+const MASK_IOU_MANTISSA: u64 = 0x003FFFFFFFFFFFFFu64;
+
+/// Sign bit (bit 62) of an IOU amount's `OpaqueFloat`, set for positive values.
+const IOU_SIGN_BIT: u8 = 0x40;
+
+/// Mantissa range of a normalized, non-zero IOU amount (16 decimal digits).
+const IOU_MIN_MANTISSA: u64 = 1_000_000_000_000_000;
+const IOU_MAX_MANTISSA: u64 = 9_999_999_999_999_999;
+
+/// Exponent range of a normalized, non-zero IOU amount; stored with a bias of 97.
+const IOU_MIN_EXPONENT: i32 = -96;
+const IOU_MAX_EXPONENT: i32 = 80;
+
+/// The canonical IOU zero: the not-XRP bit alone.
+const IOU_ZERO: u64 = 0x8000000000000000u64;
+
+/// Normalizes an IOU value the way rippled's `STAmount::canonicalize` does.
+fn normalize_iou(amount: &OpaqueFloat) -> host::Result<OpaqueFloat> {
+    let value = u64::from_be_bytes(amount.0);
+    let mut mantissa = value & MASK_IOU_MANTISSA;
+    let mut exponent = ((value >> 54) & 0xFF) as i32 - 97;
+
+    while mantissa != 0 && mantissa < IOU_MIN_MANTISSA && exponent > IOU_MIN_EXPONENT {
+        mantissa *= 10;
+        exponent -= 1;
+    }
+    while mantissa > IOU_MAX_MANTISSA {
+        mantissa /= 10;
+        exponent += 1;
+    }
+    if mantissa < IOU_MIN_MANTISSA || exponent < IOU_MIN_EXPONENT {
+        return Ok(OpaqueFloat(IOU_ZERO.to_be_bytes()));
+    }
+    if exponent > IOU_MAX_EXPONENT {
+        return Err(AmountOverflow);
+    }
+
+    let sign = value & ((IOU_SIGN_BIT as u64) << 56);
+    let normalized = IOU_ZERO | sign | (((exponent + 97) as u64) << 54) | mantissa;
+    Ok(OpaqueFloat(normalized.to_be_bytes()))
+}
+
 impl Amount {
     /// Converts a Amount to STAmount bytes format.
     ///
@@ -121,13 +183,59 @@ impl Amount {
     /// - MPT: Flag byte (0b_0110_0000) in byte 0, raw amount in bytes 1-9, MptId in bytes 9-33 + 15 bytes padding
     /// - IOU: OpaqueFloat in first 8 bytes, Currency in bytes 8-28, AccountID in bytes 28-48
     ///
-    /// Returns a tuple of (bytes, length) where length is always 48.
+    /// Returns a tuple of (bytes, length) where length is always 48. This is the layout the host's
+    /// `trace_amount` expects; use [`to_canonical_bytes`](Self::to_canonical_bytes) to serialize.
     pub fn to_stamount_bytes(&self) -> ([u8; AMOUNT_SIZE], usize) {
+        let (bytes, _len) = self.encode();
+        (bytes, AMOUNT_SIZE)
+    }
+
+    /// Serializes the amount as rippled does: 8 bytes for XRP, 33 for MPT and 48 for IOU.
+    ///
+    /// Returns a tuple of (bytes, length); only `bytes[..length]` is the encoding, and it is always
+    /// accepted by [`from_canonical_bytes`](Self::from_canonical_bytes). Zero is written with the
+    /// positive sign, and an IOU value is normalized to a 16-digit mantissa first (truncating extra
+    /// digits, as rippled does; a value too small to normalize becomes zero).
+    ///
+    /// Returns [`AmountOverflow`](host::Error::AmountOverflow) if an XRP amount exceeds
+    /// [`MAX_DROPS`], an MPT amount exceeds `i64::MAX` or an IOU exceeds the largest normalized
+    /// value, and [`InvalidParams`](host::Error::InvalidParams) if an IOU uses the XRP currency or
+    /// the zero issuer.
+    pub fn to_canonical_bytes(&self) -> host::Result<([u8; AMOUNT_SIZE], usize)> {
+        match self {
+            Amount::XRP { num_drops } if num_drops.unsigned_abs() > MAX_DROPS => {
+                Err(AmountOverflow)
+            }
+            Amount::MPT { num_units, .. } if *num_units > i64::MAX as u64 => Err(AmountOverflow),
+            Amount::IOU {
+                amount,
+                issuer,
+                currency,
+            } => {
+                if currency.is_xrp()
+                    || *currency == Currency::from_code(*b"XRP")
+                    || issuer.0 == [0u8; 20]
+                {
+                    return Err(InvalidParams);
+                }
+                let normalized = Amount::IOU {
+                    amount: normalize_iou(amount)?,
+                    issuer: *issuer,
+                    currency: *currency,
+                };
+                Ok(normalized.encode())
+            }
+            _ => Ok(self.encode()),
+        }
+    }
+
+    /// Writes the amount in rippled's layout without validating or normalizing it.
+    fn encode(&self) -> ([u8; AMOUNT_SIZE], usize) {
         let mut bytes = [0u8; AMOUNT_SIZE];
 
         match self {
             Amount::XRP { num_drops } => {
-                // For tracing, XRP encodes the drop amount with the sign bit
+                // XRP encodes the drop amount with the sign bit
                 // Bit 6 is set to 1 for positive amounts, 0 for negative
                 let abs_drops = num_drops.unsigned_abs();
                 let mut value = abs_drops;
@@ -135,7 +243,7 @@ impl Amount {
                     value |= 0x4000000000000000u64; // Set bit 6 for positive
                 }
                 bytes[0..8].copy_from_slice(&value.to_be_bytes());
-                // Remaining 40 bytes stay as zeros (padding)
+                (bytes, XRP_AMOUNT_SIZE)
             }
 
             Amount::MPT {
@@ -143,11 +251,11 @@ impl Amount {
                 is_positive,
                 mpt_id,
             } => {
-                // MPT format for tracing: flag byte + amount + mpt_id
+                // MPT format: flag byte + amount + mpt_id
                 let mut control_byte = 0u8;
 
                 // Set the sign bit (bit 6)
-                if *is_positive {
+                if *is_positive || *num_units == 0 {
                     control_byte |= 0x40; // Set bit 6
                 }
 
@@ -160,7 +268,7 @@ impl Amount {
                 bytes[0] = control_byte;
                 bytes[1..9].copy_from_slice(&num_units.to_be_bytes());
                 bytes[9..33].copy_from_slice(mpt_id.as_bytes());
-                // Remaining 15 bytes stay as zeros (padding)
+                (bytes, MPT_AMOUNT_SIZE)
             }
 
             Amount::IOU {
@@ -168,34 +276,108 @@ impl Amount {
                 issuer,
                 currency,
             } => {
-                // IOU format for tracing: opaque float + currency + issuer
-                bytes[0..8].copy_from_slice(&amount.0);
+                // IOU format: opaque float + currency + issuer
+                if self.is_zero() {
+                    bytes[0] = 0x80;
+                } else {
+                    bytes[0..8].copy_from_slice(&amount.0);
+                }
                 bytes[8..28].copy_from_slice(currency.as_bytes());
                 bytes[28..48].copy_from_slice(&issuer.0);
-                // No padding needed - uses all 48 bytes
+                (bytes, AMOUNT_SIZE)
             }
         }
+    }
 
-        (bytes, AMOUNT_SIZE)
+    /// The length of a serialized amount, determined by its first byte.
+    pub const fn serialized_len(first_byte: u8) -> usize {
+        if first_byte & 0x80 != 0 {
+            AMOUNT_SIZE // IOU: 8-byte value + currency + issuer
+        } else if first_byte & 0x20 != 0 {
+            MPT_AMOUNT_SIZE // MPT: flag byte + 8-byte value + MPT ID
+        } else {
+            XRP_AMOUNT_SIZE
+        }
+    }
+
+    /// Parses an amount as rippled serializes it, rejecting anything rippled would not produce.
+    ///
+    /// `bytes` must be exactly the 8-byte XRP, 33-byte MPT or 48-byte IOU form. Returns
+    /// [`Error::InvalidDecoding`](host::Error::InvalidDecoding) if:
+    /// - the length doesn't match the form its first byte announces;
+    /// - an XRP amount exceeds [`MAX_DROPS`], or an MPT amount exceeds `i64::MAX`;
+    /// - reserved bits are set, or zero carries the negative sign;
+    /// - an IOU value is not normalized (a mantissa of 16 digits and an exponent from -96 to 80,
+    ///   or the canonical zero);
+    /// - an IOU uses the XRP currency (all zeros or the code `"XRP"`) or the zero issuer.
+    ///
+    /// Use this for amounts that don't come from the host, e.g. ones read from a memo or a
+    /// signed payload.
+    pub fn from_canonical_bytes(bytes: &[u8]) -> host::Result<Self> {
+        let Some(&byte0) = bytes.first() else {
+            return Err(InvalidDecoding);
+        };
+        let len = Self::serialized_len(byte0);
+        if bytes.len() != len {
+            return Err(InvalidDecoding);
+        }
+        let value = u64::from_be_bytes([
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+        ]);
+        let is_positive = byte0 & 0x40 != 0;
+
+        let is_canonical = match len {
+            XRP_AMOUNT_SIZE => {
+                let num_drops = value & !0xC000000000000000u64;
+                num_drops <= MAX_DROPS && (is_positive || num_drops != 0)
+            }
+            MPT_AMOUNT_SIZE => {
+                let num_units = u64::from_be_bytes([
+                    bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7], bytes[8],
+                ]);
+                byte0 & 0x1F == 0 && num_units <= i64::MAX as u64 && (is_positive || num_units != 0)
+            }
+            _ => {
+                let mantissa = value & MASK_IOU_MANTISSA;
+                let exponent = ((value >> 54) & 0xFF) as i32 - 97;
+                let value_ok = if mantissa == 0 {
+                    value == IOU_ZERO
+                } else {
+                    (IOU_MIN_MANTISSA..=IOU_MAX_MANTISSA).contains(&mantissa)
+                        && (IOU_MIN_EXPONENT..=IOU_MAX_EXPONENT).contains(&exponent)
+                };
+                let currency = &bytes[8..28];
+                value_ok
+                    && currency != [0u8; 20]
+                    && currency != Currency::from_code(*b"XRP").as_bytes()
+                    && bytes[28..48] != [0u8; 20]
+            }
+        };
+        if !is_canonical {
+            return Err(InvalidDecoding);
+        }
+        Self::from_bytes(bytes)
     }
 
     /// Parses a Amount from a byte array.
     ///
-    /// The byte array can be one of three formats:
+    /// The byte array can be one of three formats, either exactly or padded to 48 bytes as
+    /// [`to_stamount_bytes`](Self::to_stamount_bytes) produces:
     /// - XRP: 8 bytes
     /// - MPT: 33 bytes
     /// - IOU: 48 bytes
     ///
-    /// Returns None if the byte array is not a valid Amount.
+    /// Returns an error if the byte array is too short for its format. The value itself is not
+    /// validated; use [`from_canonical_bytes`](Self::from_canonical_bytes) for untrusted input.
     pub fn from_bytes(bytes: &[u8]) -> host::Result<Self> {
-        // TODO: Move to trait!
-
-        if bytes.len() != 48 {
+        let Some(&byte0) = bytes.first() else {
+            return Err(InternalError);
+        };
+        if bytes.len() != Self::serialized_len(byte0) && bytes.len() != AMOUNT_SIZE {
             return Err(InternalError);
         }
 
-        let byte0 = bytes[0]; // Get the first byte for flag extraction
-
+        // Extract flags from the first byte
         // Extract flags using bitwise operations
         let is_iou = byte0 & 0x80 == 0x80; // Bit 7 (Most Significant Bit)
         let is_xrp_or_mpt = !is_iou;
@@ -278,13 +460,278 @@ impl Amount {
     }
 }
 
+/// Arithmetic and comparison.
+///
+/// Both operands must be the same asset (see [`Amount::same_asset`]), otherwise these return
+/// [`Error::AssetMismatch`](host::Error::AssetMismatch). XRP drops use `i64` math bounded by
+/// [`MAX_DROPS`] and MPT units are bounded by `i64::MAX` like rippled's, both returning
+/// [`Error::AmountOverflow`](host::Error::AmountOverflow) when out of range. IOU amounts are
+/// computed by the host float functions, so their results match rippled exactly.
+///
+/// # Example
+///
+/// ```no_run
+/// use core::cmp::Ordering;
+/// use xrpl_wasm_stdlib::core::types::amount::Amount;
+/// # fn check(escrowed: &Amount, oracle_value: &Amount) -> xrpl_wasm_stdlib::host::Result<bool> {
+/// // The escrow must hold at least twice the oracle value
+/// let threshold = oracle_value.checked_add(oracle_value)?;
+/// Ok(escrowed.compare(&threshold)? != Ordering::Less)
+/// # }
+/// ```
+impl Amount {
+    /// Returns `true` if both amounts are XRP, the same IOU (currency and issuer), or the same
+    /// MPT issuance.
+    pub fn same_asset(&self, other: &Amount) -> bool {
+        match (self, other) {
+            (Amount::XRP { .. }, Amount::XRP { .. }) => true,
+            (
+                Amount::IOU {
+                    issuer, currency, ..
+                },
+                Amount::IOU {
+                    issuer: other_issuer,
+                    currency: other_currency,
+                    ..
+                },
+            ) => issuer == other_issuer && currency == other_currency,
+            (
+                Amount::MPT { mpt_id, .. },
+                Amount::MPT {
+                    mpt_id: other_id, ..
+                },
+            ) => mpt_id == other_id,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the amount is zero, whatever its sign bit.
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Amount::XRP { num_drops } => *num_drops == 0,
+            Amount::IOU { amount, .. } => u64::from_be_bytes(amount.0) & MASK_IOU_MANTISSA == 0,
+            Amount::MPT { num_units, .. } => *num_units == 0,
+        }
+    }
+
+    /// Returns `true` if the amount is greater than zero.
+    pub fn is_positive(&self) -> bool {
+        match self {
+            Amount::XRP { num_drops } => *num_drops > 0,
+            Amount::IOU { amount, .. } => amount.0[0] & IOU_SIGN_BIT != 0 && !self.is_zero(),
+            Amount::MPT {
+                num_units,
+                is_positive,
+                ..
+            } => *is_positive && *num_units > 0,
+        }
+    }
+
+    /// Returns the amount with its sign flipped. Zero stays zero.
+    pub fn negate(&self) -> Amount {
+        match self {
+            Amount::XRP { num_drops } => Amount::XRP {
+                num_drops: -num_drops,
+            },
+            Amount::IOU {
+                amount,
+                issuer,
+                currency,
+            } => {
+                let mut amount = *amount;
+                if !self.is_zero() {
+                    amount.0[0] ^= IOU_SIGN_BIT;
+                }
+                Amount::IOU {
+                    amount,
+                    issuer: *issuer,
+                    currency: *currency,
+                }
+            }
+            Amount::MPT {
+                num_units,
+                is_positive,
+                mpt_id,
+            } => Amount::MPT {
+                num_units: *num_units,
+                is_positive: !is_positive || *num_units == 0,
+                mpt_id: *mpt_id,
+            },
+        }
+    }
+
+    /// Returns `self + other`.
+    pub fn checked_add(&self, other: &Amount) -> Result<Amount> {
+        match (self, other) {
+            (Amount::XRP { num_drops }, Amount::XRP { num_drops: other }) => {
+                checked_drops(num_drops.checked_add(*other))
+            }
+            (
+                Amount::IOU {
+                    amount,
+                    issuer,
+                    currency,
+                },
+                Amount::IOU {
+                    amount: other_amount,
+                    ..
+                },
+            ) if self.same_asset(other) => {
+                let mut result = [0u8; 8];
+                let result_code = unsafe {
+                    float_add(
+                        amount.0.as_ptr(),
+                        8,
+                        other_amount.0.as_ptr(),
+                        8,
+                        result.as_mut_ptr(),
+                        8,
+                        FLOAT_ROUNDING_MODES_TO_NEAREST,
+                    )
+                };
+                match_result_code(result_code, || Amount::IOU {
+                    amount: OpaqueFloat(result),
+                    issuer: *issuer,
+                    currency: *currency,
+                })
+            }
+            (
+                Amount::MPT {
+                    num_units,
+                    is_positive,
+                    mpt_id,
+                },
+                Amount::MPT {
+                    num_units: other_units,
+                    is_positive: other_is_positive,
+                    mpt_id: other_id,
+                },
+            ) if mpt_id == other_id => {
+                let (num_units, is_positive) = add_signed_units(
+                    (*num_units, *is_positive),
+                    (*other_units, *other_is_positive),
+                )
+                .ok_or(AmountOverflow)?;
+                Ok(Amount::MPT {
+                    num_units,
+                    is_positive,
+                    mpt_id: *mpt_id,
+                })
+            }
+            _ => Err(AssetMismatch),
+        }
+    }
+
+    /// Returns `self - other`.
+    pub fn checked_sub(&self, other: &Amount) -> Result<Amount> {
+        match (self, other) {
+            (Amount::XRP { num_drops }, Amount::XRP { num_drops: other }) => {
+                checked_drops(num_drops.checked_sub(*other))
+            }
+            (
+                Amount::IOU {
+                    amount,
+                    issuer,
+                    currency,
+                },
+                Amount::IOU {
+                    amount: other_amount,
+                    ..
+                },
+            ) if self.same_asset(other) => {
+                let mut result = [0u8; 8];
+                let result_code = unsafe {
+                    float_subtract(
+                        amount.0.as_ptr(),
+                        8,
+                        other_amount.0.as_ptr(),
+                        8,
+                        result.as_mut_ptr(),
+                        8,
+                        FLOAT_ROUNDING_MODES_TO_NEAREST,
+                    )
+                };
+                match_result_code(result_code, || Amount::IOU {
+                    amount: OpaqueFloat(result),
+                    issuer: *issuer,
+                    currency: *currency,
+                })
+            }
+            (Amount::MPT { .. }, Amount::MPT { .. }) => self.checked_add(&other.negate()),
+            _ => Err(AssetMismatch),
+        }
+    }
+
+    /// Compares two amounts of the same asset by value.
+    ///
+    /// Unlike `==`, which compares representations, this treats every zero as equal.
+    pub fn compare(&self, other: &Amount) -> Result<Ordering> {
+        if !self.same_asset(other) {
+            return Err(AssetMismatch);
+        }
+        match (self, other) {
+            (Amount::XRP { num_drops }, Amount::XRP { num_drops: other }) => {
+                Ok(num_drops.cmp(other))
+            }
+            (Amount::IOU { amount, .. }, Amount::IOU { amount: other, .. }) => {
+                let result_code =
+                    unsafe { float_compare(amount.0.as_ptr(), 8, other.0.as_ptr(), 8) };
+                match result_code {
+                    1 => Ok(Ordering::Greater),
+                    2 => Ok(Ordering::Less),
+                    code => match_result_code(code, || Ordering::Equal),
+                }
+            }
+            _ => Ok(signed_units(self).cmp(&signed_units(other))),
+        }
+    }
+}
+
+/// Bounds an XRP result by the total supply of drops.
+fn checked_drops(num_drops: Option<i64>) -> Result<Amount> {
+    match num_drops {
+        Some(num_drops) if num_drops.unsigned_abs() <= MAX_DROPS => Ok(Amount::XRP { num_drops }),
+        _ => Err(AmountOverflow),
+    }
+}
+
+/// Adds two sign-magnitude MPT amounts, returning `None` if the magnitude exceeds `i64::MAX`.
+fn add_signed_units(a: (u64, bool), b: (u64, bool)) -> Option<(u64, bool)> {
+    let ((a, a_positive), (b, b_positive)) = (a, b);
+    let (num_units, is_positive) = if a_positive == b_positive {
+        let sum = a.checked_add(b)?;
+        (sum, a_positive || sum == 0)
+    } else {
+        // Opposite signs: the larger magnitude keeps its sign, and zero is positive.
+        match a.cmp(&b) {
+            Ordering::Greater => (a - b, a_positive),
+            Ordering::Less => (b - a, b_positive),
+            Ordering::Equal => (0, true),
+        }
+    };
+    (num_units <= i64::MAX as u64).then_some((num_units, is_positive))
+}
+
+/// The signed value of an MPT amount, with every zero equal.
+fn signed_units(amount: &Amount) -> i128 {
+    match amount {
+        Amount::MPT {
+            num_units,
+            is_positive: false,
+            ..
+        } => -(*num_units as i128),
+        Amount::MPT { num_units, .. } => *num_units as i128,
+        _ => 0,
+    }
+}
+
 impl From<[u8; AMOUNT_SIZE]> for Amount {
     fn from(bytes: [u8; AMOUNT_SIZE]) -> Self {
         // Use the existing from_bytes method with a slice reference
         match Self::from_bytes(&bytes) {
             Ok(amount) => amount,
             Err(error) => {
-                let _ = trace_num("Error parsing amount", error.code() as i64);
+                let _ = trace_error_num("Error parsing amount", error.code() as i64);
                 panic!("Invalid Amount byte array");
             }
         }
@@ -300,17 +747,16 @@ impl From<[u8; AMOUNT_SIZE]> for Amount {
 /// # Buffer Management
 ///
 /// Uses a 48-byte buffer to accommodate the largest possible amount representation.
-/// The Amount type handles the parsing of different amount formats internally.
-/// No strict byte count validation is performed since amounts can vary in size.
+/// The host writes the 8-, 33- or 48-byte serialized form, and a length that doesn't match the
+/// form is returned as an error.
 impl LedgerObjectFieldGetter for Amount {
     #[inline]
     fn get_from_current_ledger_obj(field_code: i32) -> Result<Self> {
-        match get_variable_size_field::<AMOUNT_SIZE, _>(field_code, |fc, buf, size| unsafe {
-            get_current_ledger_obj_field(fc, buf, size)
-        }) {
-            Result::Ok((buffer, _len)) => Result::Ok(Amount::from(buffer)),
-            Result::Err(e) => Result::Err(e),
-        }
+        let (buffer, len) =
+            get_variable_size_field::<AMOUNT_SIZE, _>(field_code, |fc, buf, size| unsafe {
+                get_current_ledger_obj_field(fc, buf, size)
+            })?;
+        Amount::from_bytes(&buffer[..len])
     }
 
     #[inline]
@@ -318,20 +764,19 @@ impl LedgerObjectFieldGetter for Amount {
         match get_variable_size_field_optional::<AMOUNT_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_current_ledger_obj_field(fc, buf, size) },
-        ) {
-            Result::Ok(opt) => Result::Ok(opt.map(|(buffer, _len)| Amount::from(buffer))),
-            Result::Err(e) => Result::Err(e),
+        )? {
+            Some((buffer, len)) => Amount::from_bytes(&buffer[..len]).map(Some),
+            None => Result::Ok(None),
         }
     }
 
     #[inline]
     fn get_from_ledger_obj(register_num: i32, field_code: i32) -> Result<Self> {
-        match get_variable_size_field::<AMOUNT_SIZE, _>(field_code, |fc, buf, size| unsafe {
-            get_ledger_obj_field(register_num, fc, buf, size)
-        }) {
-            Result::Ok((buffer, _len)) => Result::Ok(Amount::from(buffer)),
-            Result::Err(e) => Result::Err(e),
-        }
+        let (buffer, len) =
+            get_variable_size_field::<AMOUNT_SIZE, _>(field_code, |fc, buf, size| unsafe {
+                get_ledger_obj_field(register_num, fc, buf, size)
+            })?;
+        Amount::from_bytes(&buffer[..len])
     }
 
     #[inline]
@@ -339,9 +784,60 @@ impl LedgerObjectFieldGetter for Amount {
         match get_variable_size_field_optional::<AMOUNT_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_ledger_obj_field(register_num, fc, buf, size) },
-        ) {
-            Result::Ok(opt) => Result::Ok(opt.map(|(buffer, _len)| Amount::from(buffer))),
-            Result::Err(e) => Result::Err(e),
+        )? {
+            Some((buffer, len)) => Amount::from_bytes(&buffer[..len]).map(Some),
+            None => Result::Ok(None),
+        }
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested(locator: &Locator) -> Result<Self> {
+        let (buffer, len) = get_variable_size_nested_field::<AMOUNT_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            },
+        )?;
+        Amount::from_bytes(&buffer[..len])
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        match get_variable_size_nested_field_optional::<AMOUNT_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            },
+        )? {
+            Some((buffer, len)) => Amount::from_bytes(&buffer[..len]).map(Some),
+            None => Result::Ok(None),
+        }
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested(register_num: i32, locator: &Locator) -> Result<Self> {
+        let (buffer, len) = get_variable_size_nested_field::<AMOUNT_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            },
+        )?;
+        Amount::from_bytes(&buffer[..len])
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested_optional(
+        register_num: i32,
+        locator: &Locator,
+    ) -> Result<Option<Self>> {
+        match get_variable_size_nested_field_optional::<AMOUNT_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            },
+        )? {
+            Some((buffer, len)) => Amount::from_bytes(&buffer[..len]).map(Some),
+            None => Result::Ok(None),
         }
     }
 }
@@ -356,17 +852,16 @@ impl LedgerObjectFieldGetter for Amount {
 /// # Buffer Management
 ///
 /// Uses a 48-byte buffer (AMOUNT_SIZE) to accommodate the largest possible amount
-/// representation. The Amount type handles the parsing of different amount formats
-/// internally. No strict byte count validation is performed since amounts can vary in size.
+/// representation. The host writes the 8-, 33- or 48-byte serialized form, and a length that
+/// doesn't match the form is returned as an error.
 impl CurrentTxFieldGetter for Amount {
     #[inline]
     fn get_from_current_tx(field_code: i32) -> Result<Self> {
-        match get_variable_size_field::<AMOUNT_SIZE, _>(field_code, |fc, buf, size| unsafe {
-            get_tx_field(fc, buf, size)
-        }) {
-            Result::Ok((buffer, _len)) => Result::Ok(Amount::from(buffer)),
-            Result::Err(e) => Result::Err(e),
-        }
+        let (buffer, len) =
+            get_variable_size_field::<AMOUNT_SIZE, _>(field_code, |fc, buf, size| unsafe {
+                get_tx_field(fc, buf, size)
+            })?;
+        Amount::from_bytes(&buffer[..len])
     }
 
     #[inline]
@@ -374,9 +869,29 @@ impl CurrentTxFieldGetter for Amount {
         match get_variable_size_field_optional::<AMOUNT_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_tx_field(fc, buf, size) },
-        ) {
-            Result::Ok(opt) => Result::Ok(opt.map(|(buffer, _len)| Amount::from(buffer))),
-            Result::Err(e) => Result::Err(e),
+        )? {
+            Some((buffer, len)) => Amount::from_bytes(&buffer[..len]).map(Some),
+            None => Result::Ok(None),
+        }
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested(locator: &Locator) -> Result<Self> {
+        let (buffer, len) = get_variable_size_nested_field::<AMOUNT_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )?;
+        Amount::from_bytes(&buffer[..len])
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        match get_variable_size_nested_field_optional::<AMOUNT_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )? {
+            Some((buffer, len)) => Amount::from_bytes(&buffer[..len]).map(Some),
+            None => Result::Ok(None),
         }
     }
 }
@@ -831,4 +1346,302 @@ mod tests {
         let parsed_large_xrp = Amount::from_bytes(&large_xrp_bytes).unwrap();
         assert_eq!(parsed_large_xrp, large_xrp);
     }
+
+    fn usd(amount: [u8; 8]) -> Amount {
+        Amount::IOU {
+            amount: OpaqueFloat(amount),
+            issuer: AccountID::from([1u8; 20]),
+            currency: Currency::from(*b"USD"),
+        }
+    }
+
+    fn mpt(num_units: u64, is_positive: bool) -> Amount {
+        Amount::MPT {
+            num_units,
+            is_positive,
+            mpt_id: MptId::from([7u8; 24]),
+        }
+    }
+
+    #[test]
+    fn test_same_asset() {
+        let one = crate::core::types::opaque_float::FLOAT_ONE;
+        let xrp = Amount::XRP { num_drops: 1 };
+        assert!(xrp.same_asset(&Amount::XRP { num_drops: -5 }));
+        assert!(usd(one).same_asset(&usd([0x80, 0, 0, 0, 0, 0, 0, 0])));
+        assert!(mpt(1, true).same_asset(&mpt(2, false)));
+
+        let other_issuer = Amount::IOU {
+            amount: OpaqueFloat(one),
+            issuer: AccountID::from([2u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        let other_mpt = Amount::MPT {
+            num_units: 1,
+            is_positive: true,
+            mpt_id: MptId::from([8u8; 24]),
+        };
+        assert!(!usd(one).same_asset(&other_issuer));
+        assert!(!mpt(1, true).same_asset(&other_mpt));
+        assert!(!xrp.same_asset(&usd(one)));
+        assert_eq!(xrp.checked_add(&usd(one)), Err(AssetMismatch));
+        assert_eq!(mpt(1, true).checked_sub(&other_mpt), Err(AssetMismatch));
+        assert_eq!(usd(one).compare(&other_issuer), Err(AssetMismatch));
+    }
+
+    #[test]
+    fn test_sign_and_zero() {
+        use crate::core::types::opaque_float::{FLOAT_NEGATIVE_ONE, FLOAT_ONE};
+        let zero = [0x80, 0, 0, 0, 0, 0, 0, 0];
+
+        assert!(Amount::XRP { num_drops: 0 }.is_zero());
+        assert!(Amount::XRP { num_drops: 1 }.is_positive());
+        assert!(!Amount::XRP { num_drops: -1 }.is_positive());
+        assert_eq!(
+            Amount::XRP { num_drops: 5 }.negate(),
+            Amount::XRP { num_drops: -5 }
+        );
+
+        assert!(usd(zero).is_zero());
+        assert!(!usd(zero).is_positive());
+        assert_eq!(usd(zero).negate(), usd(zero));
+        assert!(usd(FLOAT_ONE).is_positive());
+        assert!(!usd(FLOAT_NEGATIVE_ONE).is_positive());
+        assert_eq!(usd(FLOAT_ONE).negate(), usd(FLOAT_NEGATIVE_ONE));
+
+        assert!(mpt(0, false).is_zero());
+        assert!(!mpt(0, true).is_positive());
+        assert!(!mpt(3, false).is_positive());
+        assert_eq!(mpt(3, true).negate(), mpt(3, false));
+        assert_eq!(mpt(0, true).negate(), mpt(0, true));
+    }
+
+    #[test]
+    fn test_xrp_arithmetic() {
+        let xrp = |num_drops| Amount::XRP { num_drops };
+        assert_eq!(xrp(5).checked_add(&xrp(-7)), Ok(xrp(-2)));
+        assert_eq!(xrp(5).checked_sub(&xrp(7)), Ok(xrp(-2)));
+
+        let max = MAX_DROPS as i64;
+        assert_eq!(xrp(max).checked_add(&xrp(0)), Ok(xrp(max)));
+        assert_eq!(xrp(max).checked_add(&xrp(1)), Err(AmountOverflow));
+        assert_eq!(xrp(-max).checked_sub(&xrp(1)), Err(AmountOverflow));
+        assert_eq!(xrp(i64::MAX).checked_add(&xrp(1)), Err(AmountOverflow));
+
+        assert_eq!(xrp(1).compare(&xrp(2)), Ok(Ordering::Less));
+        assert_eq!(xrp(-1).compare(&xrp(-2)), Ok(Ordering::Greater));
+        assert_eq!(xrp(3).compare(&xrp(3)), Ok(Ordering::Equal));
+    }
+
+    #[test]
+    fn test_mpt_arithmetic() {
+        assert_eq!(mpt(5, true).checked_add(&mpt(7, true)), Ok(mpt(12, true)));
+        assert_eq!(mpt(5, true).checked_sub(&mpt(7, true)), Ok(mpt(2, false)));
+        assert_eq!(mpt(5, false).checked_add(&mpt(5, true)), Ok(mpt(0, true)));
+        assert_eq!(mpt(5, false).checked_sub(&mpt(5, false)), Ok(mpt(0, true)));
+        let max = i64::MAX as u64;
+        assert_eq!(
+            mpt(max - 1, true).checked_add(&mpt(1, true)),
+            Ok(mpt(max, true))
+        );
+        assert_eq!(
+            mpt(max, true).checked_add(&mpt(1, true)),
+            Err(AmountOverflow)
+        );
+        assert_eq!(
+            mpt(max, false).checked_sub(&mpt(1, true)),
+            Err(AmountOverflow)
+        );
+        assert_eq!(
+            mpt(u64::MAX, true).checked_add(&mpt(1, true)),
+            Err(AmountOverflow)
+        );
+        assert_eq!(
+            mpt(max, true).checked_sub(&mpt(1, true)),
+            Ok(mpt(max - 1, true))
+        );
+
+        assert_eq!(mpt(2, false).compare(&mpt(1, true)), Ok(Ordering::Less));
+        assert_eq!(mpt(2, false).compare(&mpt(3, false)), Ok(Ordering::Greater));
+        assert_eq!(mpt(0, false).compare(&mpt(0, true)), Ok(Ordering::Equal));
+        assert_eq!(
+            mpt(u64::MAX, true).compare(&mpt(1, true)),
+            Ok(Ordering::Greater)
+        );
+    }
+
+    #[test]
+    fn test_iou_arithmetic_uses_host() {
+        use crate::core::types::opaque_float::FLOAT_ONE;
+        // The test host reports success without computing anything, so this only checks that
+        // the result keeps the issue and that comparison maps the host's result code.
+        let sum = usd(FLOAT_ONE).checked_add(&usd(FLOAT_ONE)).unwrap();
+        assert!(sum.same_asset(&usd(FLOAT_ONE)));
+        assert!(usd(FLOAT_ONE).checked_sub(&usd(FLOAT_ONE)).is_ok());
+        assert_eq!(usd(FLOAT_ONE).compare(&usd(FLOAT_ONE)), Ok(Ordering::Equal));
+    }
+
+    fn iou_bytes(value: u64) -> [u8; AMOUNT_SIZE] {
+        let mut bytes = [0u8; AMOUNT_SIZE];
+        bytes[0..8].copy_from_slice(&value.to_be_bytes());
+        bytes[8..28].copy_from_slice(Currency::from(*b"USD").as_bytes());
+        bytes[28..48].copy_from_slice(&[1u8; 20]);
+        bytes
+    }
+
+    #[test]
+    fn test_canonical_round_trip() {
+        use crate::core::types::opaque_float::{FLOAT_NEGATIVE_ONE, FLOAT_ONE};
+        let amounts = [
+            Amount::XRP { num_drops: 0 },
+            Amount::XRP { num_drops: 1 },
+            Amount::XRP { num_drops: -1 },
+            Amount::XRP {
+                num_drops: MAX_DROPS as i64,
+            },
+            mpt(0, true),
+            mpt(42, true),
+            mpt(42, false),
+            mpt(i64::MAX as u64, true),
+            usd([0x80, 0, 0, 0, 0, 0, 0, 0]),
+            usd(FLOAT_ONE),
+            usd(FLOAT_NEGATIVE_ONE),
+        ];
+        for amount in amounts {
+            let (bytes, len) = amount.to_canonical_bytes().unwrap();
+            assert_eq!(len, Amount::serialized_len(bytes[0]));
+            assert_eq!(Amount::from_canonical_bytes(&bytes[..len]), Ok(amount));
+        }
+
+        let (bytes, len) = Amount::XRP { num_drops: 1 }.to_canonical_bytes().unwrap();
+        assert_eq!(bytes[..len], [0x40, 0, 0, 0, 0, 0, 0, 1]);
+        let (bytes, len) = mpt(1, true).to_canonical_bytes().unwrap();
+        assert_eq!((bytes[0], len), (0x60, MPT_AMOUNT_SIZE));
+
+        // Zero is always written as positive
+        let (bytes, _) = mpt(0, false).to_canonical_bytes().unwrap();
+        assert_eq!(bytes[0], 0x60);
+        let (bytes, _) = usd([0xC0, 0, 0, 0, 0, 0, 0, 0])
+            .to_canonical_bytes()
+            .unwrap();
+        assert_eq!(bytes[..8], [0x80, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_to_canonical_bytes_normalizes_and_validates() {
+        let positive = 0xC000000000000000u64;
+        let negative = 0x8000000000000000u64;
+        let exponent = |e: i32| ((e + 97) as u64) << 54;
+
+        // 1 as an unnormalized mantissa of 1 round-trips as 1e15 * 10^-15
+        let one = usd((positive | exponent(0) | 1).to_be_bytes());
+        let (bytes, len) = one.to_canonical_bytes().unwrap();
+        let expected = positive | exponent(-15) | 1_000_000_000_000_000;
+        assert_eq!(bytes[..8], expected.to_be_bytes());
+        let decoded = Amount::from_canonical_bytes(&bytes[..len]).unwrap();
+        assert_eq!(decoded, usd(expected.to_be_bytes()));
+
+        // A 17-digit mantissa drops its last digit, keeping the sign
+        let long = usd((negative | exponent(-16) | 12_345_678_901_234_567).to_be_bytes());
+        let (bytes, len) = long.to_canonical_bytes().unwrap();
+        let expected = negative | exponent(-15) | 1_234_567_890_123_456;
+        assert_eq!(bytes[..8], expected.to_be_bytes());
+        assert!(Amount::from_canonical_bytes(&bytes[..len]).is_ok());
+
+        // Too small to normalize becomes the canonical zero; too large overflows
+        let tiny = usd((positive | exponent(-96) | 1).to_be_bytes());
+        let (bytes, _) = tiny.to_canonical_bytes().unwrap();
+        assert_eq!(bytes[..8], IOU_ZERO.to_be_bytes());
+        let huge = usd((positive | exponent(80) | 10_000_000_000_000_000).to_be_bytes());
+        assert_eq!(huge.to_canonical_bytes(), Err(AmountOverflow));
+
+        // Out-of-range XRP and MPT amounts, and IOUs of XRP or from the zero issuer
+        let xrp = Amount::XRP {
+            num_drops: MAX_DROPS as i64 + 1,
+        };
+        assert_eq!(xrp.to_canonical_bytes(), Err(AmountOverflow));
+        assert_eq!(
+            mpt(i64::MAX as u64 + 1, true).to_canonical_bytes(),
+            Err(AmountOverflow)
+        );
+        let value = OpaqueFloat(expected.to_be_bytes());
+        let xrp_iou = Amount::IOU {
+            amount: value,
+            issuer: AccountID::from([1u8; 20]),
+            currency: Currency::from(*b"XRP"),
+        };
+        assert_eq!(xrp_iou.to_canonical_bytes(), Err(InvalidParams));
+        let no_issuer = Amount::IOU {
+            amount: value,
+            issuer: AccountID::from([0u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        assert_eq!(no_issuer.to_canonical_bytes(), Err(InvalidParams));
+    }
+
+    #[test]
+    fn test_canonical_rejects_invalid() {
+        let invalid = |bytes: &[u8]| Amount::from_canonical_bytes(bytes) == Err(InvalidDecoding);
+
+        // Lengths: empty, padded XRP, short MPT and IOU
+        assert!(invalid(&[]));
+        assert!(invalid(&[0x40; AMOUNT_SIZE]));
+        assert!(invalid(&[0x60; 20]));
+        assert!(invalid(&[0xC0; 40]));
+
+        // XRP: negative zero, and more than the total supply
+        assert!(invalid(&[0u8; XRP_AMOUNT_SIZE]));
+        assert!(invalid(
+            &(0x4000000000000000u64 | (MAX_DROPS + 1)).to_be_bytes()
+        ));
+
+        // MPT: reserved bits, negative zero, and more than i64::MAX
+        let mut bytes = [0u8; MPT_AMOUNT_SIZE];
+        bytes[0] = 0x61;
+        assert!(invalid(&bytes));
+        bytes[0] = 0x20;
+        assert!(invalid(&bytes));
+        bytes[0] = 0x60;
+        bytes[1..9].copy_from_slice(&(i64::MAX as u64 + 1).to_be_bytes());
+        assert!(invalid(&bytes));
+
+        // IOU: non-canonical zero, unnormalized mantissa, exponent out of range
+        let positive = 0xC000000000000000u64;
+        let exponent = |e: i32| ((e + 97) as u64) << 54;
+        assert!(invalid(&iou_bytes(positive)));
+        assert!(invalid(&iou_bytes(
+            positive | exponent(-15) | 999_999_999_999_999
+        )));
+        assert!(invalid(&iou_bytes(
+            positive | exponent(-15) | 10_000_000_000_000_000
+        )));
+        assert!(invalid(&iou_bytes(
+            positive | exponent(81) | 1_000_000_000_000_000
+        )));
+        assert!(invalid(&iou_bytes(
+            positive | exponent(-97) | 1_000_000_000_000_000
+        )));
+        let valid = iou_bytes(positive | exponent(80) | 9_999_999_999_999_999);
+        assert!(Amount::from_canonical_bytes(&valid).is_ok());
+
+        // IOU: XRP currency or zero issuer
+        let mut bytes = iou_bytes(positive | exponent(-15) | 1_000_000_000_000_000);
+        assert!(Amount::from_canonical_bytes(&bytes).is_ok());
+        bytes[8..28].copy_from_slice(&[0u8; 20]);
+        assert!(invalid(&bytes));
+        bytes[8..28].copy_from_slice(Currency::from(*b"XRP").as_bytes());
+        assert!(invalid(&bytes));
+        let mut bytes = iou_bytes(positive | exponent(-15) | 1_000_000_000_000_000);
+        bytes[28..48].copy_from_slice(&[0u8; 20]);
+        assert!(invalid(&bytes));
+    }
+
+    #[test]
+    fn test_from_bytes_accepts_exact_and_padded_forms() {
+        let xrp = Amount::XRP { num_drops: 7 };
+        let (bytes, len) = xrp.to_canonical_bytes().unwrap();
+        assert_eq!(Amount::from_bytes(&bytes[..len]), Ok(xrp.clone()));
+        assert_eq!(Amount::from_bytes(&bytes), Ok(xrp));
+        assert!(Amount::from_bytes(&bytes[..len + 1]).is_err());
+    }
 }
@@ -1,3 +1,31 @@
+//! Views over STArray fields (e.g. `Memos`, `Signers`, `PriceDataSeries`).
+//!
+//! An [`ArrayView`] is rooted at the current transaction, the current ledger object, or a cached
+//! ledger object slot. It knows its length and hands out [`ArrayElement`] handles, which read typed
+//! fields by extending the array's [`Locator`] internally. Arrays nest: an element can open another
+//! array (e.g. `PriceDataSeries` inside an Oracle).
+//!
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::current_tx;
+//! use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+//! use xrpl_wasm_stdlib::sfield;
+//! use xrpl_wasm_stdlib::host::ResultExt;
+//!
+//! let signers = current_tx::get_array(sfield::Signers).unwrap_or_panic();
+//! for signer in signers.iter() {
+//!     let _account: AccountID = signer.get_field(sfield::Account).unwrap_or_panic();
+//!     let _weight: u16 = signer.get_field(sfield::SignerWeight).unwrap_or_panic();
+//! }
+//! ```
+
+use crate::core::current_tx;
+use crate::core::current_tx::CurrentTxFieldGetter;
+use crate::core::ledger_objects::LedgerObjectFieldGetter;
+use crate::core::ledger_objects::{current_ledger_object, ledger_object};
+use crate::core::locator::Locator;
+use crate::host::Error::LocatorMalformed;
+use crate::host::Result;
+
 /// Placeholder type for array SFields.
 ///
 /// Array types in XRPL (like Signers, Memos, etc.) cannot be directly retrieved
@@ -5,9 +33,300 @@
 /// specific array elements.
 ///
 /// This type intentionally does NOT implement `LedgerObjectFieldGetter` to prevent compile-time
-/// misuse. If you need to access array elements, use `Locator` to navigate to
-/// specific fields within the array.
-///
-/// TODO: explore using the Locator under the hood here
+/// misuse. If you need to access array elements, use an [`ArrayView`].
 #[derive(Debug, Eq, PartialEq)]
 pub struct Array;
+
+/// The object an [`ArrayView`] reads from.
+pub trait ArrayRoot: Copy {
+    /// Returns the number of elements in the array identified by `locator`.
+    fn get_nested_array_len(&self, locator: &Locator) -> Result<usize>;
+}
+
+/// Root for arrays in the current transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrentTxRoot;
+
+/// Root for arrays in the current ledger object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrentLedgerObjectRoot;
+
+/// Root for arrays in a ledger object cached in a slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LedgerObjectRoot {
+    pub slot_num: i32,
+}
+
+impl ArrayRoot for CurrentTxRoot {
+    #[inline]
+    fn get_nested_array_len(&self, locator: &Locator) -> Result<usize> {
+        current_tx::get_nested_array_len(locator)
+    }
+}
+
+impl ArrayRoot for CurrentLedgerObjectRoot {
+    #[inline]
+    fn get_nested_array_len(&self, locator: &Locator) -> Result<usize> {
+        current_ledger_object::get_nested_array_len(locator)
+    }
+}
+
+impl ArrayRoot for LedgerObjectRoot {
+    #[inline]
+    fn get_nested_array_len(&self, locator: &Locator) -> Result<usize> {
+        ledger_object::get_nested_array_len(self.slot_num, locator)
+    }
+}
+
+/// A view over an STArray field, rooted at `R`.
+///
+/// The length is read once when the view is created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArrayView<R: ArrayRoot> {
+    root: R,
+    locator: Locator,
+    len: usize,
+}
+
+impl<R: ArrayRoot> ArrayView<R> {
+    /// Creates a view over the array that `locator` points at.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ArrayView)` - If the locator points at an array
+    /// * `Err(Error)` - If the array is missing or the locator does not point at an array
+    pub fn new(root: R, locator: Locator) -> Result<Self> {
+        let len = root.get_nested_array_len(&locator)?;
+        Result::Ok(Self { root, locator, len })
+    }
+
+    /// The number of elements in the array.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The locator of the array itself.
+    pub fn locator(&self) -> &Locator {
+        &self.locator
+    }
+
+    /// Returns a handle to the element at `index`, or `None` if it is out of range.
+    pub fn get(&self, index: usize) -> Option<ArrayElement<R>> {
+        if index >= self.len {
+            return None;
+        }
+        let mut locator = self.locator.clone();
+        if !locator.pack(index as i32) {
+            return None;
+        }
+        Some(ArrayElement {
+            root: self.root,
+            locator,
+        })
+    }
+
+    /// Iterates over handles to every element of the array.
+    pub fn iter(&self) -> ArrayIter<'_, R> {
+        ArrayIter {
+            view: self,
+            index: 0,
+        }
+    }
+}
+
+impl<'a, R: ArrayRoot> IntoIterator for &'a ArrayView<R> {
+    type Item = ArrayElement<R>;
+    type IntoIter = ArrayIter<'a, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the elements of an [`ArrayView`].
+pub struct ArrayIter<'a, R: ArrayRoot> {
+    view: &'a ArrayView<R>,
+    index: usize,
+}
+
+impl<R: ArrayRoot> Iterator for ArrayIter<'_, R> {
+    type Item = ArrayElement<R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let element = self.view.get(self.index);
+        if element.is_some() {
+            self.index += 1;
+        }
+        element
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.view.len.saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+/// A handle to a single element (an inner object) of an [`ArrayView`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArrayElement<R: ArrayRoot> {
+    root: R,
+    locator: Locator,
+}
+
+impl<R: ArrayRoot> ArrayElement<R> {
+    /// The locator of this element (the array path plus the element index).
+    pub fn locator(&self) -> &Locator {
+        &self.locator
+    }
+
+    /// Opens an array nested inside this element.
+    pub fn get_array(&self, field_code: i32) -> Result<ArrayView<R>> {
+        ArrayView::new(self.root, self.field_locator(field_code)?)
+    }
+
+    fn field_locator(&self, field_code: i32) -> Result<Locator> {
+        let mut locator = self.locator.clone();
+        if locator.pack(field_code) {
+            Result::Ok(locator)
+        } else {
+            Result::Err(LocatorMalformed)
+        }
+    }
+}
+
+impl ArrayElement<CurrentTxRoot> {
+    /// Reads a required field of this element.
+    #[inline]
+    pub fn get_field<T: CurrentTxFieldGetter>(&self, field_code: i32) -> Result<T> {
+        T::get_from_current_tx_nested(&self.field_locator(field_code)?)
+    }
+
+    /// Reads an optional field of this element.
+    #[inline]
+    pub fn get_field_optional<T: CurrentTxFieldGetter>(
+        &self,
+        field_code: i32,
+    ) -> Result<Option<T>> {
+        T::get_from_current_tx_nested_optional(&self.field_locator(field_code)?)
+    }
+}
+
+impl ArrayElement<CurrentLedgerObjectRoot> {
+    /// Reads a required field of this element.
+    #[inline]
+    pub fn get_field<T: LedgerObjectFieldGetter>(&self, field_code: i32) -> Result<T> {
+        T::get_from_current_ledger_obj_nested(&self.field_locator(field_code)?)
+    }
+
+    /// Reads an optional field of this element.
+    #[inline]
+    pub fn get_field_optional<T: LedgerObjectFieldGetter>(
+        &self,
+        field_code: i32,
+    ) -> Result<Option<T>> {
+        T::get_from_current_ledger_obj_nested_optional(&self.field_locator(field_code)?)
+    }
+}
+
+impl ArrayElement<LedgerObjectRoot> {
+    /// Reads a required field of this element.
+    #[inline]
+    pub fn get_field<T: LedgerObjectFieldGetter>(&self, field_code: i32) -> Result<T> {
+        T::get_from_ledger_obj_nested(self.root.slot_num, &self.field_locator(field_code)?)
+    }
+
+    /// Reads an optional field of this element.
+    #[inline]
+    pub fn get_field_optional<T: LedgerObjectFieldGetter>(
+        &self,
+        field_code: i32,
+    ) -> Result<Option<T>> {
+        let locator = self.field_locator(field_code)?;
+        T::get_from_ledger_obj_nested_optional(self.root.slot_num, &locator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::account_id::AccountID;
+    use crate::core::types::amount::Amount;
+    use crate::locator;
+    use crate::sfield;
+
+    #[test]
+    fn test_element_locators_extend_array_locator() {
+        // The test host echoes the locator length back as the array length
+        let view =
+            ArrayView::new(LedgerObjectRoot { slot_num: 1 }, locator!(PriceDataSeries)).unwrap();
+        assert_eq!(view.len(), 4);
+
+        let element = view.get(2).unwrap();
+        assert_eq!(element.locator(), &locator!(PriceDataSeries[2]));
+        assert!(view.get(4).is_none());
+    }
+
+    #[test]
+    fn test_iter_yields_every_element() {
+        let view = ArrayView::new(CurrentLedgerObjectRoot, locator!(SignerEntries)).unwrap();
+        let mut count = 0;
+        for (i, element) in view.iter().enumerate() {
+            let mut expected = locator!(SignerEntries);
+            expected.pack(i as i32);
+            assert_eq!(element.locator(), &expected);
+            count += 1;
+        }
+        assert_eq!(count, view.len());
+        assert_eq!(view.iter().size_hint(), (4, Some(4)));
+    }
+
+    #[test]
+    fn test_nested_arrays() {
+        let outer = ArrayView::new(CurrentLedgerObjectRoot, locator!(PriceDataSeries)).unwrap();
+        let inner = outer
+            .get(0)
+            .unwrap()
+            .get_array(sfield::AcceptedCredentials)
+            .unwrap();
+        assert_eq!(
+            inner.locator(),
+            &locator!(PriceDataSeries[0].AcceptedCredentials)
+        );
+        assert_eq!(inner.len(), inner.locator().len());
+    }
+
+    #[test]
+    fn test_typed_element_reads() {
+        let view =
+            ArrayView::new(LedgerObjectRoot { slot_num: 1 }, locator!(SignerEntries)).unwrap();
+        let entry = view.get(0).unwrap();
+        assert!(entry.get_field::<AccountID>(sfield::Account).is_ok());
+        assert!(entry.get_field::<u16>(sfield::SignerWeight).is_ok());
+        assert!(
+            entry
+                .get_field_optional::<Amount>(sfield::Amount)
+                .unwrap()
+                .is_some()
+        );
+
+        // The test host reports an empty array for the current transaction
+        let memos = ArrayView::new(CurrentTxRoot, locator!(Memos)).unwrap();
+        assert!(memos.is_empty());
+        assert!(memos.get(0).is_none());
+    }
+
+    #[test]
+    fn test_element_field_locator_overflow() {
+        let deep = Locator::from_path(&[sfield::Memos; 16]);
+        let element = ArrayElement {
+            root: CurrentTxRoot,
+            locator: deep,
+        };
+        let error = element.get_field::<u32>(sfield::Flags).err().unwrap();
+        assert_eq!(error.code(), LocatorMalformed.code());
+    }
+}
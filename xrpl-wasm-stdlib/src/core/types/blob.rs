@@ -1,7 +1,14 @@
 use crate::core::current_tx::CurrentTxFieldGetter;
 use crate::core::ledger_objects::LedgerObjectFieldGetter;
-use crate::host::field_helpers::{get_variable_size_field, get_variable_size_field_optional};
-use crate::host::{Result, get_current_ledger_obj_field, get_ledger_obj_field, get_tx_field};
+use crate::core::locator::Locator;
+use crate::host::field_helpers::{
+    get_variable_size_field, get_variable_size_field_optional, get_variable_size_nested_field,
+    get_variable_size_nested_field_optional,
+};
+use crate::host::{
+    Result, get_current_ledger_obj_field, get_current_ledger_obj_nested_field,
+    get_ledger_obj_field, get_ledger_obj_nested_field, get_tx_field, get_tx_nested_field,
+};
 
 /// Default blob size for general use (memos, etc.)
 pub const DEFAULT_BLOB_SIZE: usize = 1024;
@@ -164,42 +171,77 @@ pub const EMPTY_BLOB: EmptyBlob = Blob {
 impl<const N: usize> LedgerObjectFieldGetter for Blob<N> {
     #[inline]
     fn get_from_current_ledger_obj(field_code: i32) -> Result<Self> {
-        match get_variable_size_field::<N, _>(field_code, |fc, buf, size| unsafe {
+        let (data, len) = get_variable_size_field::<N, _>(field_code, |fc, buf, size| unsafe {
             get_current_ledger_obj_field(fc, buf, size)
-        }) {
-            Result::Ok((data, len)) => Result::Ok(Blob { data, len }),
-            Result::Err(e) => Result::Err(e),
-        }
+        })?;
+        Result::Ok(Blob { data, len })
     }
 
     #[inline]
     fn get_from_current_ledger_obj_optional(field_code: i32) -> Result<Option<Self>> {
-        match get_variable_size_field_optional::<N, _>(field_code, |fc, buf, size| unsafe {
+        let opt = get_variable_size_field_optional::<N, _>(field_code, |fc, buf, size| unsafe {
             get_current_ledger_obj_field(fc, buf, size)
-        }) {
-            Result::Ok(opt) => Result::Ok(opt.map(|(data, len)| Blob { data, len })),
-            Result::Err(e) => Result::Err(e),
-        }
+        })?;
+        Result::Ok(opt.map(|(data, len)| Blob { data, len }))
     }
 
     #[inline]
     fn get_from_ledger_obj(register_num: i32, field_code: i32) -> Result<Self> {
-        match get_variable_size_field::<N, _>(field_code, |fc, buf, size| unsafe {
+        let (data, len) = get_variable_size_field::<N, _>(field_code, |fc, buf, size| unsafe {
             get_ledger_obj_field(register_num, fc, buf, size)
-        }) {
-            Result::Ok((data, len)) => Result::Ok(Blob { data, len }),
-            Result::Err(e) => Result::Err(e),
-        }
+        })?;
+        Result::Ok(Blob { data, len })
     }
 
     #[inline]
     fn get_from_ledger_obj_optional(register_num: i32, field_code: i32) -> Result<Option<Self>> {
-        match get_variable_size_field_optional::<N, _>(field_code, |fc, buf, size| unsafe {
+        let opt = get_variable_size_field_optional::<N, _>(field_code, |fc, buf, size| unsafe {
             get_ledger_obj_field(register_num, fc, buf, size)
-        }) {
-            Result::Ok(opt) => Result::Ok(opt.map(|(data, len)| Blob { data, len })),
-            Result::Err(e) => Result::Err(e),
-        }
+        })?;
+        Result::Ok(opt.map(|(data, len)| Blob { data, len }))
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested(locator: &Locator) -> Result<Self> {
+        let (data, len) =
+            get_variable_size_nested_field::<N, _>(locator, |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            })?;
+        Result::Ok(Blob { data, len })
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        let opt = get_variable_size_nested_field_optional::<N, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(opt.map(|(data, len)| Blob { data, len }))
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested(register_num: i32, locator: &Locator) -> Result<Self> {
+        let (data, len) =
+            get_variable_size_nested_field::<N, _>(locator, |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            })?;
+        Result::Ok(Blob { data, len })
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested_optional(
+        register_num: i32,
+        locator: &Locator,
+    ) -> Result<Option<Self>> {
+        let opt = get_variable_size_nested_field_optional::<N, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(opt.map(|(data, len)| Blob { data, len }))
     }
 }
 
@@ -222,22 +264,36 @@ impl<const N: usize> LedgerObjectFieldGetter for Blob<N> {
 impl<const N: usize> CurrentTxFieldGetter for Blob<N> {
     #[inline]
     fn get_from_current_tx(field_code: i32) -> Result<Self> {
-        match get_variable_size_field::<N, _>(field_code, |fc, buf, size| unsafe {
+        let (data, len) = get_variable_size_field::<N, _>(field_code, |fc, buf, size| unsafe {
             get_tx_field(fc, buf, size)
-        }) {
-            Result::Ok((data, len)) => Result::Ok(Blob { data, len }),
-            Result::Err(e) => Result::Err(e),
-        }
+        })?;
+        Result::Ok(Blob { data, len })
     }
 
     #[inline]
     fn get_from_current_tx_optional(field_code: i32) -> Result<Option<Self>> {
-        match get_variable_size_field_optional::<N, _>(field_code, |fc, buf, size| unsafe {
+        let opt = get_variable_size_field_optional::<N, _>(field_code, |fc, buf, size| unsafe {
             get_tx_field(fc, buf, size)
-        }) {
-            Result::Ok(opt) => Result::Ok(opt.map(|(data, len)| Blob { data, len })),
-            Result::Err(e) => Result::Err(e),
-        }
+        })?;
+        Result::Ok(opt.map(|(data, len)| Blob { data, len }))
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested(locator: &Locator) -> Result<Self> {
+        let (data, len) =
+            get_variable_size_nested_field::<N, _>(locator, |loc, loc_len, buf, size| unsafe {
+                get_tx_nested_field(loc, loc_len, buf, size)
+            })?;
+        Result::Ok(Blob { data, len })
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        let opt = get_variable_size_nested_field_optional::<N, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )?;
+        Result::Ok(opt.map(|(data, len)| Blob { data, len }))
     }
 }
 
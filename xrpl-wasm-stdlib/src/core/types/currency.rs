@@ -1,18 +1,67 @@
+use crate::core::current_tx::CurrentTxFieldGetter;
 use crate::core::ledger_objects::LedgerObjectFieldGetter;
+use crate::core::locator::Locator;
+use crate::core::types::uint::UInt;
 use crate::host::field_helpers::{
     get_fixed_size_field_with_expected_bytes, get_fixed_size_field_with_expected_bytes_optional,
+    get_fixed_size_nested_field_with_expected_bytes,
+    get_fixed_size_nested_field_with_expected_bytes_optional,
+};
+use crate::host::{
+    Result, get_current_ledger_obj_field, get_current_ledger_obj_nested_field,
+    get_ledger_obj_field, get_ledger_obj_nested_field, get_tx_field, get_tx_nested_field,
 };
-use crate::host::{Result, get_current_ledger_obj_field, get_ledger_obj_field};
 
 pub const CURRENCY_SIZE: usize = 20;
 pub const STANDARD_CURRENCY_SIZE: usize = 3; // For standard currencies like USD, EUR, etc.
 
+/// Maximum length of a currency code written by [`Currency::write_code`] (40 hex digits).
+pub const CURRENCY_CODE_MAX_SIZE: usize = CURRENCY_SIZE * 2;
+
+/// Where the 3-character code sits in a standard currency.
+const STANDARD_CODE_RANGE: core::ops::Range<usize> = 12..15;
+
+/// Characters allowed in a 3-character code besides ASCII letters and digits.
+const CODE_SYMBOLS: &[u8] = b"?!@#$%^&*<>(){}[]|";
+
+/// How the 160 bits of a [`Currency`] are to be interpreted, based on its first byte.
+///
+/// See: <https://xrpl.org/docs/references/protocol/data-types/currency-formats>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurrencyKind {
+    /// All zeros: XRP, the native asset.
+    Xrp,
+    /// First byte `0x00`: a 3-character code in bytes 12-14, all other bytes zero.
+    Standard,
+    /// First byte `0x01`: a deprecated interest-bearing (demurrage) currency with its
+    /// 3-character code in bytes 1-3.
+    Demurrage,
+    /// Any other first byte: an arbitrary 160-bit code.
+    NonStandard,
+}
+
 /// Represents a currency code in the XRPL, which is a 20-byte identifier.
 ///
 /// Currency codes in XRPL can be either:
 /// - **Standard currencies**: 3-character ASCII codes (e.g., "USD", "EUR") stored in bytes 12-14
 /// - **Non-standard currencies**: Full 20-byte hex values for custom tokens
 ///
+/// Use the [`currency!`](crate::currency) macro to write either form as a constant:
+///
+/// ```
+/// use xrpl_wasm_stdlib::core::types::currency::Currency;
+/// use xrpl_wasm_stdlib::currency;
+///
+/// const USD: Currency = currency!("USD");
+/// assert_eq!(&USD.0[12..15], b"USD");
+/// ```
+///
+/// XRP is the native asset rather than an issued currency, so it is rejected at compile time:
+///
+/// ```compile_fail
+/// const XRP: xrpl_wasm_stdlib::core::types::currency::Currency = xrpl_wasm_stdlib::currency!("XRP");
+/// ```
+///
 /// ## Derived Traits
 ///
 /// - `Copy`: Efficient for this 20-byte struct, enabling implicit copying
@@ -32,6 +81,98 @@ impl Currency {
     pub fn as_bytes(&self) -> &[u8; CURRENCY_SIZE] {
         &self.0
     }
+
+    /// Creates a standard currency from its 3-character code, e.g. `Currency::from_code(*b"USD")`.
+    pub const fn from_code(code: [u8; STANDARD_CURRENCY_SIZE]) -> Self {
+        let mut bytes = [0u8; CURRENCY_SIZE];
+        bytes[12] = code[0];
+        bytes[13] = code[1];
+        bytes[14] = code[2];
+        Currency(bytes)
+    }
+
+    /// Classifies the currency by its first byte. Use [`is_valid`](Self::is_valid) to also check
+    /// the reserved bytes.
+    pub fn kind(&self) -> CurrencyKind {
+        match self.0[0] {
+            0x00 if self.is_xrp() => CurrencyKind::Xrp,
+            0x00 => CurrencyKind::Standard,
+            0x01 => CurrencyKind::Demurrage,
+            _ => CurrencyKind::NonStandard,
+        }
+    }
+
+    /// Returns `true` for the all-zero currency, which denotes XRP.
+    pub fn is_xrp(&self) -> bool {
+        self.0 == [0u8; CURRENCY_SIZE]
+    }
+
+    /// Returns the 3-character code of a valid standard currency (e.g. `"USD"`), or of a
+    /// demurrage currency, and `None` otherwise.
+    pub fn code(&self) -> Option<&str> {
+        let code = match self.kind() {
+            CurrencyKind::Standard if self.is_valid() => &self.0[STANDARD_CODE_RANGE],
+            CurrencyKind::Demurrage if is_valid_code(&self.0[1..4]) => &self.0[1..4],
+            _ => return None,
+        };
+        // Valid codes are ASCII.
+        core::str::from_utf8(code).ok()
+    }
+
+    /// Returns `true` if the currency is well formed for its [`kind`](Self::kind).
+    ///
+    /// A standard currency must have all reserved bytes zero and a code made of ASCII letters,
+    /// digits and `?!@#$%^&*<>(){}[]|`, other than `"XRP"` (which the ledger rejects to avoid
+    /// confusion with the native asset). XRP and non-standard currencies are always valid.
+    pub fn is_valid(&self) -> bool {
+        match self.kind() {
+            CurrencyKind::Xrp | CurrencyKind::NonStandard => true,
+            CurrencyKind::Standard => {
+                let code = &self.0[STANDARD_CODE_RANGE];
+                self.0[..STANDARD_CODE_RANGE.start]
+                    .iter()
+                    .chain(&self.0[STANDARD_CODE_RANGE.end..])
+                    .all(|&b| b == 0)
+                    && is_valid_code(code)
+                    && code != b"XRP"
+            }
+            CurrencyKind::Demurrage => is_valid_code(&self.0[1..4]),
+        }
+    }
+
+    /// Writes a readable form of the currency into `out` and returns it: `"XRP"`, the
+    /// 3-character code of a valid standard currency, or 40 uppercase hex digits otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use xrpl_wasm_stdlib::core::types::currency::{CURRENCY_CODE_MAX_SIZE, Currency};
+    ///
+    /// let mut buf = [0u8; CURRENCY_CODE_MAX_SIZE];
+    /// assert_eq!(Currency::from_code(*b"EUR").write_code(&mut buf), "EUR");
+    /// assert_eq!(Currency::new([0u8; 20]).write_code(&mut buf), "XRP");
+    /// ```
+    pub fn write_code<'a>(&self, out: &'a mut [u8; CURRENCY_CODE_MAX_SIZE]) -> &'a str {
+        let len = match (self.kind(), self.code()) {
+            (CurrencyKind::Xrp, _) => {
+                out[..3].copy_from_slice(b"XRP");
+                3
+            }
+            (CurrencyKind::Standard, Some(code)) => {
+                out[..3].copy_from_slice(code.as_bytes());
+                3
+            }
+            // `out` always fits the 40 digits
+            _ => return UInt(self.0).write_hex_upper(out).unwrap_or_default(),
+        };
+        // Only ASCII is written above.
+        unsafe { core::str::from_utf8_unchecked(&out[..len]) }
+    }
+}
+
+fn is_valid_code(code: &[u8]) -> bool {
+    code.iter()
+        .all(|b| b.is_ascii_alphanumeric() || CODE_SYMBOLS.contains(b))
 }
 
 impl From<[u8; CURRENCY_SIZE]> for Currency {
@@ -43,9 +184,7 @@ impl From<[u8; CURRENCY_SIZE]> for Currency {
 // Implement From<[u8; 3]> to create Currency from the standard currency array type
 impl From<[u8; STANDARD_CURRENCY_SIZE]> for Currency {
     fn from(bytes: [u8; STANDARD_CURRENCY_SIZE]) -> Self {
-        let mut arr = [0u8; CURRENCY_SIZE];
-        arr[12..15].copy_from_slice(&bytes);
-        Self(arr)
+        Self::from_code(bytes)
     }
 }
 
@@ -102,6 +241,108 @@ impl LedgerObjectFieldGetter for Currency {
             Result::Err(e) => Result::Err(e),
         }
     }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested(locator: &Locator) -> Result<Self> {
+        match get_fixed_size_nested_field_with_expected_bytes::<CURRENCY_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            },
+        ) {
+            Result::Ok(buffer) => Result::Ok(buffer.into()),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        match get_fixed_size_nested_field_with_expected_bytes_optional::<CURRENCY_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            },
+        ) {
+            Result::Ok(buffer) => Result::Ok(buffer.map(|b| b.into())),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested(register_num: i32, locator: &Locator) -> Result<Self> {
+        match get_fixed_size_nested_field_with_expected_bytes::<CURRENCY_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            },
+        ) {
+            Result::Ok(buffer) => Result::Ok(buffer.into()),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested_optional(
+        register_num: i32,
+        locator: &Locator,
+    ) -> Result<Option<Self>> {
+        match get_fixed_size_nested_field_with_expected_bytes_optional::<CURRENCY_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            },
+        ) {
+            Result::Ok(buffer) => Result::Ok(buffer.map(|b| b.into())),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+}
+
+/// Implementation of `CurrentTxFieldGetter` for XRPL currency codes.
+///
+/// This implementation handles 20-byte currency code fields in XRPL transactions, such as the
+/// `Currency` of an issue inside an `STIssue` path.
+///
+/// # Buffer Management
+///
+/// Uses a 20-byte buffer and validates that exactly 20 bytes are returned
+/// from the host function to ensure data integrity.
+impl CurrentTxFieldGetter for Currency {
+    #[inline]
+    fn get_from_current_tx(field_code: i32) -> Result<Self> {
+        let buffer = get_fixed_size_field_with_expected_bytes::<CURRENCY_SIZE, _>(
+            field_code,
+            |fc, buf, size| unsafe { get_tx_field(fc, buf, size) },
+        )?;
+        Result::Ok(buffer.into())
+    }
+
+    #[inline]
+    fn get_from_current_tx_optional(field_code: i32) -> Result<Option<Self>> {
+        let buffer = get_fixed_size_field_with_expected_bytes_optional::<CURRENCY_SIZE, _>(
+            field_code,
+            |fc, buf, size| unsafe { get_tx_field(fc, buf, size) },
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested(locator: &Locator) -> Result<Self> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes::<CURRENCY_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )?;
+        Result::Ok(buffer.into())
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes_optional::<CURRENCY_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
+    }
 }
 
 #[cfg(test)]
@@ -159,4 +400,78 @@ mod tests {
         // Verify the bytes
         assert_eq!(currency.as_bytes(), &expected);
     }
+
+    #[test]
+    fn test_currency_kinds() {
+        assert_eq!(
+            Currency::new([0u8; CURRENCY_SIZE]).kind(),
+            CurrencyKind::Xrp
+        );
+        assert_eq!(Currency::from_code(*b"USD").kind(), CurrencyKind::Standard);
+
+        let mut demurrage = [0u8; CURRENCY_SIZE];
+        demurrage[0] = 0x01;
+        demurrage[1..4].copy_from_slice(b"XAU");
+        demurrage[4..8].copy_from_slice(&[0x17, 0xD4, 0x8C, 0x5F]); // start date
+        assert_eq!(Currency(demurrage).kind(), CurrencyKind::Demurrage);
+        assert_eq!(Currency(demurrage).code(), Some("XAU"));
+
+        let mut rlusd = [0u8; CURRENCY_SIZE];
+        rlusd[..5].copy_from_slice(b"RLUSD");
+        assert_eq!(Currency(rlusd).kind(), CurrencyKind::NonStandard);
+        assert_eq!(Currency(rlusd).code(), None);
+        assert!(Currency(rlusd).is_valid());
+    }
+
+    #[test]
+    fn test_currency_validation() {
+        assert!(Currency::from_code(*b"USD").is_valid());
+        assert!(Currency::from_code(*b"$?!").is_valid());
+        assert_eq!(Currency::from_code(*b"usd").code(), Some("usd"));
+
+        // "XRP" in the standard slot, non-code characters, and non-zero reserved bytes
+        assert!(!Currency::from_code(*b"XRP").is_valid());
+        assert!(!Currency::from_code(*b"U D").is_valid());
+        let mut reserved = Currency::from_code(*b"USD");
+        reserved.0[19] = 1;
+        assert!(!reserved.is_valid());
+        assert_eq!(reserved.kind(), CurrencyKind::Standard);
+        assert_eq!(reserved.code(), None);
+    }
+
+    #[test]
+    fn test_currency_write_code() {
+        let mut buf = [0u8; CURRENCY_CODE_MAX_SIZE];
+        assert_eq!(Currency::from_code(*b"USD").write_code(&mut buf), "USD");
+        assert_eq!(
+            Currency::new([0u8; CURRENCY_SIZE]).write_code(&mut buf),
+            "XRP"
+        );
+        assert_eq!(
+            Currency::from_code(*b"XRP").write_code(&mut buf),
+            "0000000000000000000000005852500000000000"
+        );
+        assert_eq!(
+            Currency::new([0xAB; CURRENCY_SIZE]).write_code(&mut buf),
+            "ABABABABABABABABABABABABABABABABABABABAB"
+        );
+    }
+
+    #[test]
+    fn test_currency_from_current_tx() {
+        use crate::core::current_tx::CurrentTxFieldGetter;
+        use crate::sfield;
+
+        let usd = Currency::from(*b"USD");
+        crate::host::set_tx_field(sfield::TakerPaysCurrency, usd.as_bytes());
+
+        assert_eq!(
+            Currency::get_from_current_tx(sfield::TakerPaysCurrency).unwrap(),
+            usd
+        );
+        assert_eq!(
+            Currency::get_from_current_tx_optional(sfield::TakerPaysCurrency).unwrap(),
+            Some(usd)
+        );
+    }
 }
@@ -1,9 +1,25 @@
+use core::cmp::Ordering;
+
+use crate::core::codec::NO_ACCOUNT;
+use crate::core::current_tx::CurrentTxFieldGetter;
 use crate::core::ledger_objects::LedgerObjectFieldGetter;
-use crate::core::types::account_id::AccountID;
-use crate::core::types::currency::Currency;
+use crate::core::locator::Locator;
+use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::core::types::currency::{CURRENCY_SIZE, Currency};
 use crate::core::types::mpt_id::MptId;
-use crate::host::field_helpers::{get_variable_size_field, get_variable_size_field_optional};
-use crate::host::{Result, get_current_ledger_obj_field, get_ledger_obj_field};
+use crate::host::Error::InvalidDecoding;
+use crate::host::field_helpers::{
+    get_variable_size_field, get_variable_size_field_optional, get_variable_size_nested_field,
+    get_variable_size_nested_field_optional,
+};
+use crate::host::{
+    Result, get_current_ledger_obj_field, get_current_ledger_obj_nested_field,
+    get_ledger_obj_field, get_ledger_obj_nested_field, get_tx_field, get_tx_nested_field,
+};
+
+/// Length of the largest serialized `STIssue`: an MPT's issuer, the no-account marker and its
+/// 4-byte sequence.
+pub const ISSUE_MAX_SIZE: usize = ACCOUNT_ID_SIZE * 2 + 4;
 
 /// Struct to represent an Issue of type XRP. Exists so that other structs can restrict type
 /// information to XRP in their declarations (this is not possible with just the `Issue` enum below).
@@ -48,6 +64,14 @@ impl IouIssue {
     pub fn as_bytes(&self) -> &[u8] {
         &self._bytes
     }
+
+    pub fn issuer(&self) -> AccountID {
+        self.issuer
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
 }
 
 /// Struct to represent an Issue of type MPT. Exists so that other structs can restrict type
@@ -83,6 +107,9 @@ impl MptIssue {
 /// - `Debug, Clone`: Standard traits for development and consistency
 ///
 /// Note: `Copy` is intentionally not derived because the `IOU` variant is 40 bytes.
+///
+/// Issues are ordered as rippled orders them (e.g. to pick the two sides of an AMM): MPTs come
+/// first, ordered by ID, then XRP and IOUs ordered by currency and then issuer.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[repr(C)]
 pub enum Issue {
@@ -91,6 +118,10 @@ pub enum Issue {
     MPT(MptIssue),
 }
 
+/// rippled's name for an issue that may be an MPT, as in the `Asset` and `Asset2` fields of AMM
+/// and Vault transactions.
+pub type Asset = Issue;
+
 impl Issue {
     pub fn as_bytes(&self) -> &[u8] {
         match self {
@@ -107,37 +138,114 @@ impl Issue {
     ///
     /// # Arguments
     ///
-    /// * `buffer` - A 40-byte buffer containing the issue data
+    /// * `buffer` - A 44-byte buffer containing the issue data
     /// * `len` - The actual number of bytes written to the buffer
     ///
     /// # Returns
     ///
     /// Returns `Result<Issue>` where:
     /// * `Ok(Issue::XRP(...))` - If len is 20 (XRP issue)
-    /// * `Ok(Issue::MPT(...))` - If len is 24 (MPT issue)
+    /// * `Ok(Issue::MPT(...))` - If len is 24 (MPT ID) or 44 (`STIssue` form)
     /// * `Ok(Issue::IOU(...))` - If len is 40 (IOU issue)
     /// * `Err(Error)` - If len is not one of the expected values
     #[inline]
-    pub fn from_buffer(buffer: [u8; 40], len: usize) -> Result<Self> {
+    pub fn from_buffer(buffer: [u8; ISSUE_MAX_SIZE], len: usize) -> Result<Self> {
         match len {
-            20 => Result::Ok(Issue::XRP(XrpIssue {})),
             24 => {
                 let mpt_bytes: [u8; 24] = buffer[..24].try_into().unwrap_or([0u8; 24]);
-                let mpt_id = MptId::from(mpt_bytes);
-                Result::Ok(Issue::MPT(MptIssue::new(mpt_id)))
+                Result::Ok(Issue::MPT(MptIssue::new(MptId::from(mpt_bytes))))
+            }
+            len if len <= ISSUE_MAX_SIZE => Self::from_stissue_bytes(&buffer[..len]),
+            _ => Result::Err(crate::host::Error::InvalidDecoding),
+        }
+    }
+
+    /// Parses a serialized `STIssue`, as found in transactions and other binary data.
+    ///
+    /// Returns [`Error::InvalidDecoding`](crate::host::Error::InvalidDecoding) unless `bytes` is
+    /// exactly one of:
+    /// * 20 zero bytes for XRP;
+    /// * a non-XRP currency followed by a non-zero issuer (40 bytes) for an IOU;
+    /// * the issuer, the no-account marker (`0x00..01`) and the sequence (44 bytes) for an MPT.
+    ///
+    /// The MPT sequence is little-endian on the wire, unlike in the MPT ID: rippled copies the
+    /// first 4 bytes of the ID into a native `uint32_t` before serializing it big-endian, which
+    /// reverses them on the little-endian hosts it runs on. xrpl.js writes the same bytes.
+    pub fn from_stissue_bytes(bytes: &[u8]) -> Result<Self> {
+        let is_zero = |bytes: &[u8]| bytes.iter().all(|&b| b == 0);
+        match bytes.len() {
+            20 if is_zero(bytes) => Result::Ok(Issue::XRP(XrpIssue {})),
+            40 if !is_zero(&bytes[..20]) && !is_zero(&bytes[20..]) => {
+                let mut currency = [0u8; CURRENCY_SIZE];
+                currency.copy_from_slice(&bytes[..20]);
+                let mut issuer = [0u8; ACCOUNT_ID_SIZE];
+                issuer.copy_from_slice(&bytes[20..40]);
+                Result::Ok(Issue::IOU(IouIssue::new(issuer.into(), currency.into())))
             }
-            40 => {
-                let currency_bytes: [u8; 20] = buffer[..20].try_into().unwrap_or([0u8; 20]);
-                let issuer_bytes: [u8; 20] = buffer[20..40].try_into().unwrap_or([0u8; 20]);
-                let currency = Currency::from(currency_bytes);
-                let issuer = AccountID::from(issuer_bytes);
-                Result::Ok(Issue::IOU(IouIssue::new(issuer, currency)))
+            ISSUE_MAX_SIZE if bytes[20..40] == NO_ACCOUNT => {
+                // The MPT ID is the sequence followed by the issuer; STIssue stores the issuer first.
+                let sequence = u32::from_le_bytes([bytes[40], bytes[41], bytes[42], bytes[43]]);
+                let mut mpt_id = [0u8; 24];
+                mpt_id[..4].copy_from_slice(&sequence.to_be_bytes());
+                mpt_id[4..].copy_from_slice(&bytes[..20]);
+                Result::Ok(Issue::MPT(MptIssue::new(MptId::from(mpt_id))))
+            }
+            _ => Result::Err(InvalidDecoding),
+        }
+    }
+
+    /// Serializes the issue as an `STIssue` (see [`from_stissue_bytes`](Self::from_stissue_bytes)).
+    ///
+    /// Returns a tuple of (bytes, length); only `bytes[..length]` is the encoding.
+    pub fn to_stissue_bytes(&self) -> ([u8; ISSUE_MAX_SIZE], usize) {
+        let mut bytes = [0u8; ISSUE_MAX_SIZE];
+        let len = match self {
+            Issue::XRP(_) => 20,
+            Issue::IOU(iou) => {
+                bytes[..40].copy_from_slice(iou.as_bytes());
+                40
+            }
+            Issue::MPT(mpt) => {
+                let mpt_id = mpt.mpt_id();
+                let id = mpt_id.as_bytes();
+                bytes[..20].copy_from_slice(&id[4..]);
+                bytes[20..40].copy_from_slice(&NO_ACCOUNT);
+                let sequence = u32::from_be_bytes([id[0], id[1], id[2], id[3]]);
+                bytes[40..].copy_from_slice(&sequence.to_le_bytes());
+                ISSUE_MAX_SIZE
+            }
+        };
+        (bytes, len)
+    }
+
+    /// The currency and issuer rippled orders XRP and IOU issues by; XRP has neither.
+    fn currency_and_issuer(&self) -> ([u8; CURRENCY_SIZE], [u8; ACCOUNT_ID_SIZE]) {
+        match self {
+            Issue::IOU(iou) => (iou.currency.0, iou.issuer.0),
+            _ => ([0u8; CURRENCY_SIZE], [0u8; ACCOUNT_ID_SIZE]),
+        }
+    }
+}
+
+impl Ord for Issue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Issue::MPT(mpt), Issue::MPT(other)) => {
+                mpt.mpt_id.as_bytes().cmp(other.mpt_id.as_bytes())
             }
-            _ => Result::Err(crate::host::Error::from_code(len as i32)),
+            (Issue::MPT(_), _) => Ordering::Less,
+            (_, Issue::MPT(_)) => Ordering::Greater,
+            _ => self.currency_and_issuer().cmp(&other.currency_and_issuer()),
         }
     }
 }
 
+impl PartialOrd for Issue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 /// Implementation of `LedgerObjectFieldGetter` for XRPL issues.
 ///
 /// This implementation handles issue fields in XRPL ledger objects.
@@ -145,17 +253,17 @@ impl Issue {
 ///
 /// # Buffer Management
 ///
-/// Uses a 40-byte buffer to accommodate all Issue types:
+/// Uses a 44-byte buffer to accommodate all Issue types:
 /// - XRP: 20 bytes (all zeros)
 /// - IOU: 40 bytes (20 bytes currency + 20 bytes issuer)
-/// - MPT: 24 bytes (4 bytes sequence + 20 bytes issuer)
+/// - MPT: 24 bytes (4 bytes sequence + 20 bytes issuer), or the 44-byte `STIssue` form
 ///
 /// The implementation detects the Issue type based on the number of bytes returned
 /// from the host function.
 impl LedgerObjectFieldGetter for Issue {
     #[inline]
     fn get_from_current_ledger_obj(field_code: i32) -> Result<Self> {
-        match get_variable_size_field::<40, _>(field_code, |fc, buf, size| unsafe {
+        match get_variable_size_field::<ISSUE_MAX_SIZE, _>(field_code, |fc, buf, size| unsafe {
             get_current_ledger_obj_field(fc, buf, size)
         }) {
             Result::Ok((buffer, len)) => Issue::from_buffer(buffer, len),
@@ -165,9 +273,10 @@ impl LedgerObjectFieldGetter for Issue {
 
     #[inline]
     fn get_from_current_ledger_obj_optional(field_code: i32) -> Result<Option<Self>> {
-        match get_variable_size_field_optional::<40, _>(field_code, |fc, buf, size| unsafe {
-            get_current_ledger_obj_field(fc, buf, size)
-        }) {
+        match get_variable_size_field_optional::<ISSUE_MAX_SIZE, _>(
+            field_code,
+            |fc, buf, size| unsafe { get_current_ledger_obj_field(fc, buf, size) },
+        ) {
             Result::Ok(Some((buffer, len))) => match Issue::from_buffer(buffer, len) {
                 Result::Ok(issue) => Result::Ok(Some(issue)),
                 Result::Err(e) => Result::Err(e),
@@ -179,7 +288,7 @@ impl LedgerObjectFieldGetter for Issue {
 
     #[inline]
     fn get_from_ledger_obj(register_num: i32, field_code: i32) -> Result<Self> {
-        match get_variable_size_field::<40, _>(field_code, |fc, buf, size| unsafe {
+        match get_variable_size_field::<ISSUE_MAX_SIZE, _>(field_code, |fc, buf, size| unsafe {
             get_ledger_obj_field(register_num, fc, buf, size)
         }) {
             Result::Ok((buffer, len)) => Issue::from_buffer(buffer, len),
@@ -189,9 +298,40 @@ impl LedgerObjectFieldGetter for Issue {
 
     #[inline]
     fn get_from_ledger_obj_optional(register_num: i32, field_code: i32) -> Result<Option<Self>> {
-        match get_variable_size_field_optional::<40, _>(field_code, |fc, buf, size| unsafe {
-            get_ledger_obj_field(register_num, fc, buf, size)
-        }) {
+        match get_variable_size_field_optional::<ISSUE_MAX_SIZE, _>(
+            field_code,
+            |fc, buf, size| unsafe { get_ledger_obj_field(register_num, fc, buf, size) },
+        ) {
+            Result::Ok(Some((buffer, len))) => match Issue::from_buffer(buffer, len) {
+                Result::Ok(issue) => Result::Ok(Some(issue)),
+                Result::Err(e) => Result::Err(e),
+            },
+            Result::Ok(None) => Result::Ok(None),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested(locator: &Locator) -> Result<Self> {
+        match get_variable_size_nested_field::<ISSUE_MAX_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            },
+        ) {
+            Result::Ok((buffer, len)) => Issue::from_buffer(buffer, len),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        match get_variable_size_nested_field_optional::<ISSUE_MAX_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            },
+        ) {
             Result::Ok(Some((buffer, len))) => match Issue::from_buffer(buffer, len) {
                 Result::Ok(issue) => Result::Ok(Some(issue)),
                 Result::Err(e) => Result::Err(e),
@@ -200,4 +340,184 @@ impl LedgerObjectFieldGetter for Issue {
             Result::Err(e) => Result::Err(e),
         }
     }
+
+    #[inline]
+    fn get_from_ledger_obj_nested(register_num: i32, locator: &Locator) -> Result<Self> {
+        match get_variable_size_nested_field::<ISSUE_MAX_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            },
+        ) {
+            Result::Ok((buffer, len)) => Issue::from_buffer(buffer, len),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested_optional(
+        register_num: i32,
+        locator: &Locator,
+    ) -> Result<Option<Self>> {
+        match get_variable_size_nested_field_optional::<ISSUE_MAX_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            },
+        ) {
+            Result::Ok(Some((buffer, len))) => match Issue::from_buffer(buffer, len) {
+                Result::Ok(issue) => Result::Ok(Some(issue)),
+                Result::Err(e) => Result::Err(e),
+            },
+            Result::Ok(None) => Result::Ok(None),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+}
+
+/// Implementation of `CurrentTxFieldGetter` for XRPL issues.
+///
+/// This implementation handles `STIssue` fields in XRPL transactions, such as `Asset` and `Asset2`
+/// in AMM and Vault transactions. Supports all three Issue variants: XRP, IOU, and MPT.
+///
+/// # Buffer Management
+///
+/// Uses a 44-byte buffer, the size of the largest (MPT) issue, and detects the Issue type based on
+/// the number of bytes returned from the host function (see [`Issue::from_buffer`]).
+impl CurrentTxFieldGetter for Issue {
+    #[inline]
+    fn get_from_current_tx(field_code: i32) -> Result<Self> {
+        let (buffer, len) =
+            get_variable_size_field::<ISSUE_MAX_SIZE, _>(field_code, |fc, buf, size| unsafe {
+                get_tx_field(fc, buf, size)
+            })?;
+        Issue::from_buffer(buffer, len)
+    }
+
+    #[inline]
+    fn get_from_current_tx_optional(field_code: i32) -> Result<Option<Self>> {
+        match get_variable_size_field_optional::<ISSUE_MAX_SIZE, _>(
+            field_code,
+            |fc, buf, size| unsafe { get_tx_field(fc, buf, size) },
+        )? {
+            Some((buffer, len)) => Issue::from_buffer(buffer, len).map(Some),
+            None => Result::Ok(None),
+        }
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested(locator: &Locator) -> Result<Self> {
+        let (buffer, len) = get_variable_size_nested_field::<ISSUE_MAX_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )?;
+        Issue::from_buffer(buffer, len)
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        match get_variable_size_nested_field_optional::<ISSUE_MAX_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )? {
+            Some((buffer, len)) => Issue::from_buffer(buffer, len).map(Some),
+            None => Result::Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUER: AccountID = AccountID([0xAB; 20]);
+
+    fn iou(code: &[u8; 3], issuer: AccountID) -> Issue {
+        Issue::IOU(IouIssue::new(issuer, Currency::from(*code)))
+    }
+
+    fn mpt(sequence: u32) -> Issue {
+        Issue::MPT(MptIssue::new(MptId::new(sequence, ISSUER)))
+    }
+
+    #[test]
+    fn test_stissue_round_trip() {
+        for issue in [Issue::XRP(XrpIssue {}), iou(b"USD", ISSUER), mpt(5)] {
+            let (bytes, len) = issue.to_stissue_bytes();
+            assert_eq!(Issue::from_stissue_bytes(&bytes[..len]), Ok(issue.clone()));
+            assert_eq!(Issue::from_buffer(bytes, len), Ok(issue));
+        }
+    }
+
+    #[test]
+    fn test_stissue_mpt_wire_format() {
+        // MPT issuance 0000012F... (sequence 303) as rippled and xrpl.js serialize it: issuer,
+        // no-account marker, then the sequence little-endian
+        let mpt_id = crate::mpt_id!("0000012FAE123A8556F3CF91154711376AFB0F894F832B3D");
+        let wire = crate::decode_hex_20(b"AE123A8556F3CF91154711376AFB0F894F832B3D").unwrap();
+        let mut expected = [0u8; ISSUE_MAX_SIZE];
+        expected[..20].copy_from_slice(&wire);
+        expected[39] = 0x01;
+        expected[40..].copy_from_slice(&[0x2F, 0x01, 0x00, 0x00]);
+
+        let issue = Issue::MPT(MptIssue::new(mpt_id));
+        assert_eq!(issue.to_stissue_bytes(), (expected, ISSUE_MAX_SIZE));
+        assert_eq!(Issue::from_stissue_bytes(&expected), Ok(issue));
+        assert_eq!(mpt_id.get_sequence_num(), 303);
+    }
+
+    #[test]
+    fn test_stissue_rejects_invalid() {
+        let (iou_bytes, _) = iou(b"USD", ISSUER).to_stissue_bytes();
+        // Non-zero XRP, truncated IOU, IOU with the XRP currency or no issuer
+        assert_eq!(Issue::from_stissue_bytes(&[1u8; 20]), Err(InvalidDecoding));
+        assert!(Issue::from_stissue_bytes(&iou_bytes[..39]).is_err());
+        let mut bytes = iou_bytes;
+        bytes[..20].fill(0);
+        assert!(Issue::from_stissue_bytes(&bytes[..40]).is_err());
+        let mut bytes = iou_bytes;
+        bytes[20..40].fill(0);
+        assert!(Issue::from_stissue_bytes(&bytes[..40]).is_err());
+        // 44 bytes without the no-account marker
+        assert!(Issue::from_stissue_bytes(&iou_bytes).is_err());
+    }
+
+    #[test]
+    fn test_issue_ordering_matches_rippled() {
+        let xrp = Issue::XRP(XrpIssue {});
+        let low_issuer = AccountID([0x01; 20]);
+
+        // MPTs first, then XRP (the zero currency), then IOUs by currency, then issuer
+        let mut issues = [
+            iou(b"USD", ISSUER),
+            xrp.clone(),
+            mpt(2),
+            iou(b"USD", low_issuer),
+            iou(b"EUR", ISSUER),
+            mpt(1),
+        ];
+        issues.sort();
+        assert_eq!(
+            issues,
+            [
+                mpt(1),
+                mpt(2),
+                xrp.clone(),
+                iou(b"EUR", ISSUER),
+                iou(b"USD", low_issuer),
+                iou(b"USD", ISSUER),
+            ]
+        );
+        assert_eq!(xrp.cmp(&Issue::XRP(XrpIssue {})), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_issue_from_current_tx() {
+        use crate::sfield;
+
+        // The test host reports a full 44-byte buffer, which only parses as an MPT issue if it
+        // carries the no-account marker; this checks the getter is wired up, not the host data.
+        let _ = Issue::get_from_current_tx(sfield::Asset);
+        let _ = Issue::get_from_current_tx_optional(sfield::Asset2);
+    }
 }
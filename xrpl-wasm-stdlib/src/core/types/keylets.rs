@@ -1,7 +1,8 @@
-use crate::core::types::account_id::AccountID;
+use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
 use crate::core::types::currency::Currency;
 use crate::core::types::issue::Issue;
 use crate::core::types::mpt_id::MptId;
+use crate::core::types::nft::NFToken;
 use crate::host;
 use crate::host::Result;
 use crate::host::error_codes::match_result_code_with_expected_bytes;
@@ -686,6 +687,53 @@ pub fn nft_offer_keylet(owner: &AccountID, seq: i32) -> Result<KeyletBytes> {
     })
 }
 
+/// Returns the smallest possible NFTokenPage keylet for `owner`.
+///
+/// NFTokenPage keys are not hashed: they are the owner's account ID followed by 96 bits taken from
+/// the NFTokenIDs stored on the page, so this is computed locally without a host call. No page is
+/// ever stored at this key; it only bounds the owner's range of pages from below.
+pub fn nftpage_min_keylet(owner: &AccountID) -> KeyletBytes {
+    let mut keylet: KeyletBytes = [0; XRPL_KEYLET_SIZE];
+    keylet[..ACCOUNT_ID_SIZE].copy_from_slice(&owner.0);
+    keylet
+}
+
+/// Returns the largest possible NFTokenPage keylet for `owner`.
+///
+/// An account that holds at least one NFToken always has a page at this key: it is the last page of
+/// the owner's doubly linked list, and the starting point for walking it through `PreviousPageMin`.
+///
+/// # Example
+///
+/// ```rust
+/// use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+/// use xrpl_wasm_stdlib::core::types::keylets::{nftpage_max_keylet, nftpage_min_keylet};
+/// use xrpl_wasm_stdlib::r_address;
+///
+/// let owner = AccountID::from(r_address!("rJoxBSzpXhPtAuqFmqxQtGKjA13jUJWthE"));
+/// let min = nftpage_min_keylet(&owner);
+/// let max = nftpage_max_keylet(&owner);
+/// assert_eq!(min[..20], owner.0);
+/// assert_eq!(max[..20], owner.0);
+/// assert!(min < max);
+/// ```
+pub fn nftpage_max_keylet(owner: &AccountID) -> KeyletBytes {
+    let mut keylet: KeyletBytes = [0xFF; XRPL_KEYLET_SIZE];
+    keylet[..ACCOUNT_ID_SIZE].copy_from_slice(&owner.0);
+    keylet
+}
+
+/// Returns the NFTokenPage key that `token` sorts at within `owner`'s pages.
+///
+/// This is the owner's account ID followed by the low 96 bits of the NFTokenID. Because a page's
+/// key is the upper bound of the tokens it holds, the token lives on the first page whose key is
+/// greater than or equal to this value, which is not necessarily a page stored at this exact key.
+pub fn nftpage_keylet(owner: &AccountID, token: &NFToken) -> KeyletBytes {
+    let mut keylet = nftpage_min_keylet(owner);
+    keylet[ACCOUNT_ID_SIZE..].copy_from_slice(&token.as_bytes()[ACCOUNT_ID_SIZE..]);
+    keylet
+}
+
 /// Generates an offer keylet for a given owner and sequence in the XRP Ledger.
 ///
 /// Offer keylets are used to reference offer entries in the XRP Ledger's state data.
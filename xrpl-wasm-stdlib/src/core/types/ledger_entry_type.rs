@@ -0,0 +1,107 @@
+/// The type of any given XRPL ledger entry.
+///
+/// This enum maps to the `LedgerEntryType` codes used in the XRPL protocol (the value of the
+/// `sfLedgerEntryType` field of every ledger object).
+///
+/// ## Derived Traits
+///
+/// - `Debug`: Useful for development and debugging
+/// - `Clone`, `Copy`: The enum is only 2 bytes, so copies are cheap
+/// - `PartialEq, Eq`: Enable ledger entry type comparisons
+#[repr(u16)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LedgerEntryType {
+    Invalid = 0,
+    NFTokenOffer = 0x0037,
+    Check = 0x0043,
+    DID = 0x0049,
+    NegativeUNL = 0x004e,
+    NFTokenPage = 0x0050,
+    SignerList = 0x0053,
+    Ticket = 0x0054,
+    AccountRoot = 0x0061,
+    DirectoryNode = 0x0064,
+    Amendments = 0x0066,
+    LedgerHashes = 0x0068,
+    Bridge = 0x0069,
+    Offer = 0x006f,
+    DepositPreauth = 0x0070,
+    XChainOwnedClaimID = 0x0071,
+    RippleState = 0x0072,
+    FeeSettings = 0x0073,
+    XChainOwnedCreateAccountClaimID = 0x0074,
+    Escrow = 0x0075,
+    PayChannel = 0x0078,
+    AMM = 0x0079,
+    MPTokenIssuance = 0x007e,
+    MPToken = 0x007f,
+    Oracle = 0x0080,
+    Credential = 0x0081,
+    PermissionedDomain = 0x0082,
+    Delegate = 0x0083,
+    Vault = 0x0084,
+}
+
+impl From<u16> for LedgerEntryType {
+    fn from(value: u16) -> Self {
+        match value {
+            0x0037 => LedgerEntryType::NFTokenOffer,
+            0x0043 => LedgerEntryType::Check,
+            0x0049 => LedgerEntryType::DID,
+            0x004e => LedgerEntryType::NegativeUNL,
+            0x0050 => LedgerEntryType::NFTokenPage,
+            0x0053 => LedgerEntryType::SignerList,
+            0x0054 => LedgerEntryType::Ticket,
+            0x0061 => LedgerEntryType::AccountRoot,
+            0x0064 => LedgerEntryType::DirectoryNode,
+            0x0066 => LedgerEntryType::Amendments,
+            0x0068 => LedgerEntryType::LedgerHashes,
+            0x0069 => LedgerEntryType::Bridge,
+            0x006f => LedgerEntryType::Offer,
+            0x0070 => LedgerEntryType::DepositPreauth,
+            0x0071 => LedgerEntryType::XChainOwnedClaimID,
+            0x0072 => LedgerEntryType::RippleState,
+            0x0073 => LedgerEntryType::FeeSettings,
+            0x0074 => LedgerEntryType::XChainOwnedCreateAccountClaimID,
+            0x0075 => LedgerEntryType::Escrow,
+            0x0078 => LedgerEntryType::PayChannel,
+            0x0079 => LedgerEntryType::AMM,
+            0x007e => LedgerEntryType::MPTokenIssuance,
+            0x007f => LedgerEntryType::MPToken,
+            0x0080 => LedgerEntryType::Oracle,
+            0x0081 => LedgerEntryType::Credential,
+            0x0082 => LedgerEntryType::PermissionedDomain,
+            0x0083 => LedgerEntryType::Delegate,
+            0x0084 => LedgerEntryType::Vault,
+
+            // If the value doesn't match any known variant, return Invalid
+            _ => LedgerEntryType::Invalid,
+        }
+    }
+}
+
+impl From<LedgerEntryType> for u16 {
+    fn from(value: LedgerEntryType) -> Self {
+        value as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_u16_roundtrip() {
+        for entry_type in [
+            LedgerEntryType::AccountRoot,
+            LedgerEntryType::Escrow,
+            LedgerEntryType::NFTokenPage,
+            LedgerEntryType::Oracle,
+            LedgerEntryType::Vault,
+        ] {
+            assert_eq!(LedgerEntryType::from(u16::from(entry_type)), entry_type);
+        }
+        assert_eq!(LedgerEntryType::from(0x0075), LedgerEntryType::Escrow);
+        assert_eq!(LedgerEntryType::from(0xFFFF), LedgerEntryType::Invalid);
+    }
+}
@@ -6,6 +6,7 @@ pub mod contract_data;
 pub mod currency;
 pub mod issue;
 pub mod keylets;
+pub mod ledger_entry_type;
 pub mod mpt_id;
 pub mod nft;
 pub mod object;
@@ -13,3 +14,4 @@ pub mod opaque_float;
 pub mod public_key;
 pub mod transaction_type;
 pub mod uint;
+pub mod x_address;
@@ -32,6 +32,11 @@ impl MptId {
         MptId(bytes)
     }
 
+    /// Creates an MptId from its 24 raw bytes, e.g. as written by the `mpt_id!` macro.
+    pub const fn from_bytes(bytes: [u8; MPT_ID_SIZE]) -> Self {
+        MptId(bytes)
+    }
+
     /// Gets the sequence number part of the MptId.
     pub fn get_sequence_num(&self) -> u32 {
         // Transform the first 4 bytes of self.0 into a u32.
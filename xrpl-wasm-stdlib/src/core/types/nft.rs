@@ -16,6 +16,9 @@
 //! |    └─> Transfer Fee (16 bits)
 //! └─> Flags (16 bits)
 //! ```
+//!
+//! Every field can be read with a host call (e.g. [`NFToken::taxon`]) or all at once, locally, with
+//! [`NFToken::decode`].
 
 use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
 use crate::core::types::blob::{URI_BLOB_SIZE, UriBlob};
@@ -117,6 +120,30 @@ impl From<NftFlags> for u16 {
     }
 }
 
+/// Multiplier of the linear congruential generator rippled uses to scramble taxons.
+const TAXON_CIPHER_MULTIPLIER: u32 = 384_160_001;
+
+/// Increment of the linear congruential generator rippled uses to scramble taxons.
+const TAXON_CIPHER_INCREMENT: u32 = 2459;
+
+/// The fields of an NFTokenID, as returned by [`NFToken::decode`].
+///
+/// ## Derived Traits
+///
+/// - `Copy`: Efficient for this 32-byte struct, enabling implicit copying
+/// - `PartialEq, Eq`: Enable comparisons
+/// - `Debug, Clone`: Standard traits for development and consistency
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NftIdParts {
+    pub flags: NftFlags,
+    /// In 1/100,000 units, see [`NFToken::transfer_fee`].
+    pub transfer_fee: u16,
+    pub issuer: AccountID,
+    /// The taxon the issuer chose, already unscrambled.
+    pub taxon: u32,
+    pub token_sequence: u32,
+}
+
 /// Represents an NFToken (Non-Fungible Token) on the XRP Ledger.
 ///
 /// The `NFToken` type wraps a 32-byte NFTokenID and provides methods to extract
@@ -175,6 +202,41 @@ impl NFToken {
         NFT_ID_SIZE
     }
 
+    /// Decodes every field of the NFTokenID without calling the host.
+    ///
+    /// The fields are plain bit fields, except the taxon, which rippled XORs with a value derived
+    /// from the token sequence so that an issuer's NFTs spread across directory pages; this
+    /// reverses that the same way rippled does. The host getters ([`flags`](Self::flags),
+    /// [`taxon`](Self::taxon), ...) return the same values and remain available for
+    /// consensus-critical code that should defer to rippled's implementation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use xrpl_wasm_stdlib::nft_id;
+    ///
+    /// let parts = nft_id!("000B0539C35B55AA096BA6D87A6E6C965A6534150DC56E5E12C5D09E0000000C").decode();
+    /// assert!(parts.flags.is_transferable());
+    /// assert_eq!(parts.transfer_fee, 1337);
+    /// assert_eq!(parts.taxon, 1337);
+    /// assert_eq!(parts.token_sequence, 12);
+    /// ```
+    pub fn decode(&self) -> NftIdParts {
+        let id = &self.0;
+        let mut issuer = [0u8; ACCOUNT_ID_SIZE];
+        issuer.copy_from_slice(&id[4..24]);
+        let scrambled_taxon = u32::from_be_bytes([id[24], id[25], id[26], id[27]]);
+        let token_sequence = u32::from_be_bytes([id[28], id[29], id[30], id[31]]);
+
+        NftIdParts {
+            flags: NftFlags::new(u16::from_be_bytes([id[0], id[1]])),
+            transfer_fee: u16::from_be_bytes([id[2], id[3]]),
+            issuer: AccountID(issuer),
+            taxon: scrambled_taxon ^ taxon_cipher(token_sequence),
+            token_sequence,
+        }
+    }
+
     /// Retrieves the flags associated with this NFToken.
     ///
     /// Flags are stored in the first 2 bytes of the NFTokenID (big-endian).
@@ -334,6 +396,13 @@ impl NFToken {
     }
 }
 
+/// The value rippled XORs a taxon with for a given token sequence (`cipheredTaxon`).
+fn taxon_cipher(token_sequence: u32) -> u32 {
+    TAXON_CIPHER_MULTIPLIER
+        .wrapping_mul(token_sequence)
+        .wrapping_add(TAXON_CIPHER_INCREMENT)
+}
+
 impl From<[u8; NFT_ID_SIZE]> for NFToken {
     fn from(value: [u8; NFT_ID_SIZE]) -> Self {
         NFToken(value)
@@ -590,4 +659,39 @@ mod tests {
         let uri = result.unwrap();
         assert!(uri.len <= URI_BLOB_SIZE);
     }
+
+    #[test]
+    fn test_nft_decode() {
+        let nft =
+            crate::nft_id!("000B0539C35B55AA096BA6D87A6E6C965A6534150DC56E5E12C5D09E0000000C");
+        let parts = nft.decode();
+        assert_eq!(parts.flags.as_u16(), 0x000B);
+        assert!(parts.flags.is_burnable() && parts.flags.is_only_xrp());
+        assert!(!parts.flags.is_trust_line() && parts.flags.is_transferable());
+        assert_eq!(parts.transfer_fee, 1337);
+        assert_eq!(
+            parts.issuer,
+            AccountID(crate::r_address!("rJoxBSzpXhPtAuqFmqxQtGKjA13jUJWthE"))
+        );
+        assert_eq!(parts.taxon, 1337);
+        assert_eq!(parts.token_sequence, 12);
+    }
+
+    #[test]
+    fn test_nft_taxon_cipher_round_trips() {
+        // Scrambling is an XOR, so applying the cipher to a scrambled taxon restores it
+        for (taxon, sequence) in [
+            (0u32, 0u32),
+            (1337, 12),
+            (u32::MAX, u32::MAX),
+            (42, 1 << 31),
+        ] {
+            let mut id = [0u8; NFT_ID_SIZE];
+            id[24..28].copy_from_slice(&(taxon ^ taxon_cipher(sequence)).to_be_bytes());
+            id[28..32].copy_from_slice(&sequence.to_be_bytes());
+            let parts = NFToken(id).decode();
+            assert_eq!((parts.taxon, parts.token_sequence), (taxon, sequence));
+        }
+        assert_eq!(taxon_cipher(0), TAXON_CIPHER_INCREMENT);
+    }
 }
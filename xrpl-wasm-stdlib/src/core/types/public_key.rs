@@ -1,8 +1,11 @@
 use crate::core::current_tx::CurrentTxFieldGetter;
+use crate::core::locator::Locator;
 use crate::host::field_helpers::{
     get_fixed_size_field_with_expected_bytes, get_fixed_size_field_with_expected_bytes_optional,
+    get_fixed_size_nested_field_with_expected_bytes,
+    get_fixed_size_nested_field_with_expected_bytes_optional,
 };
-use crate::host::{Result, get_tx_field};
+use crate::host::{Result, get_tx_field, get_tx_nested_field};
 
 pub const PUBLIC_KEY_BUFFER_SIZE: usize = 33;
 
@@ -59,24 +62,38 @@ impl From<&[u8]> for PublicKey {
 impl CurrentTxFieldGetter for PublicKey {
     #[inline]
     fn get_from_current_tx(field_code: i32) -> Result<Self> {
-        match get_fixed_size_field_with_expected_bytes::<33, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes::<33, _>(
             field_code,
             |fc, buf, size| unsafe { get_tx_field(fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.into()),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.into())
     }
 
     #[inline]
     fn get_from_current_tx_optional(field_code: i32) -> Result<Option<Self>> {
-        match get_fixed_size_field_with_expected_bytes_optional::<33, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes_optional::<33, _>(
             field_code,
             |fc, buf, size| unsafe { get_tx_field(fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.map(|b| b.into())),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested(locator: &Locator) -> Result<Self> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes::<33, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )?;
+        Result::Ok(buffer.into())
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes_optional::<33, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
     }
 }
 
@@ -1,8 +1,11 @@
 use crate::core::current_tx::CurrentTxFieldGetter;
+use crate::core::locator::Locator;
 use crate::host::field_helpers::{
     get_fixed_size_field_with_expected_bytes, get_fixed_size_field_with_expected_bytes_optional,
+    get_fixed_size_nested_field_with_expected_bytes,
+    get_fixed_size_nested_field_with_expected_bytes_optional,
 };
-use crate::host::{Result, get_tx_field};
+use crate::host::{Result, get_tx_field, get_tx_nested_field};
 
 /// The type of any given XRPL transaction.
 ///
@@ -168,22 +171,37 @@ impl From<TransactionType> for [u8; 2] {
 impl CurrentTxFieldGetter for TransactionType {
     #[inline]
     fn get_from_current_tx(field_code: i32) -> Result<Self> {
-        match get_fixed_size_field_with_expected_bytes::<2, _>(field_code, |fc, buf, size| unsafe {
-            get_tx_field(fc, buf, size)
-        }) {
-            Result::Ok(buffer) => Result::Ok(i16::from_le_bytes(buffer).into()),
-            Result::Err(e) => Result::Err(e),
-        }
+        let buffer =
+            get_fixed_size_field_with_expected_bytes::<2, _>(field_code, |fc, buf, size| unsafe {
+                get_tx_field(fc, buf, size)
+            })?;
+        Result::Ok(i16::from_le_bytes(buffer).into())
     }
 
     #[inline]
     fn get_from_current_tx_optional(field_code: i32) -> Result<Option<Self>> {
-        match get_fixed_size_field_with_expected_bytes_optional::<2, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes_optional::<2, _>(
             field_code,
             |fc, buf, size| unsafe { get_tx_field(fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.map(|b| i16::from_le_bytes(b).into())),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.map(|b| i16::from_le_bytes(b).into()))
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested(locator: &Locator) -> Result<Self> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes::<2, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )?;
+        Result::Ok(i16::from_le_bytes(buffer).into())
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes_optional::<2, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )?;
+        Result::Ok(buffer.map(|b| i16::from_le_bytes(b).into()))
     }
 }
@@ -2,10 +2,17 @@
 
 use crate::core::current_tx::CurrentTxFieldGetter;
 use crate::core::ledger_objects::LedgerObjectFieldGetter;
+use crate::core::locator::Locator;
 use crate::host::field_helpers::{
     get_fixed_size_field_with_expected_bytes, get_fixed_size_field_with_expected_bytes_optional,
+    get_fixed_size_nested_field_with_expected_bytes,
+    get_fixed_size_nested_field_with_expected_bytes_optional,
 };
-use crate::host::{Result, get_current_ledger_obj_field, get_ledger_obj_field, get_tx_field};
+use crate::host::{
+    Error, Result, get_current_ledger_obj_field, get_current_ledger_obj_nested_field,
+    get_ledger_obj_field, get_ledger_obj_nested_field, get_tx_field, get_tx_nested_field,
+};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
 
 /// A generic unsigned integer type with configurable byte size.
 ///
@@ -20,11 +27,13 @@ use crate::host::{Result, get_current_ledger_obj_field, get_ledger_obj_field, ge
 /// ## Derived Traits
 ///
 /// - `PartialEq, Eq`: Essential for comparisons and use in collections
+/// - `PartialOrd, Ord`: The bytes are big-endian, so this is numeric order (and rippled's order of
+///   `base_uint` keys)
 /// - `Debug, Clone`: Standard traits for development and consistency
 ///
 /// Note: `Copy` is intentionally not derived because `N` can be arbitrarily large.
 #[repr(C)]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct UInt<const N: usize>(pub [u8; N]);
 
 impl<const N: usize> From<[u8; N]> for UInt<N> {
@@ -34,12 +43,225 @@ impl<const N: usize> From<[u8; N]> for UInt<N> {
 }
 
 impl<const N: usize> UInt<N> {
+    /// The value with every bit cleared.
+    pub const ZERO: Self = Self([0; N]);
+
+    /// The value with every bit set.
+    pub const MAX: Self = Self([0xFF; N]);
+
     /// Returns the inner bytes as a reference to the inner array.
     pub fn as_bytes(&self) -> &[u8; N] {
         &self.0
     }
+
+    /// Returns `true` if every bit is cleared.
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|byte| *byte == 0)
+    }
+
+    /// Returns a mask with the `count` least significant bits set.
+    ///
+    /// `count` saturates at the width of the type (`8 * N` bits).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use xrpl_wasm_stdlib::core::types::uint::Hash256;
+    ///
+    /// // The part of an NFTokenPage key taken from an NFTokenID
+    /// let page_mask = Hash256::low_bits(96);
+    /// assert_eq!(page_mask.0[..20], [0x00; 20]);
+    /// assert_eq!(page_mask.0[20..], [0xFF; 12]);
+    /// assert_eq!(!page_mask, Hash256::high_bits(160));
+    /// ```
+    pub fn low_bits(count: usize) -> Self {
+        let mut mask = Self::ZERO;
+        let count = count.min(N * 8);
+        let full = count / 8;
+        mask.0[N - full..].fill(0xFF);
+        if count % 8 != 0 {
+            mask.0[N - full - 1] = (1u8 << (count % 8)) - 1;
+        }
+        mask
+    }
+
+    /// Returns a mask with the `count` most significant bits set.
+    ///
+    /// `count` saturates at the width of the type (`8 * N` bits).
+    pub fn high_bits(count: usize) -> Self {
+        !Self::low_bits(N * 8 - count.min(N * 8))
+    }
+
+    /// Adds `value` to this big-endian integer, returning `None` on overflow.
+    ///
+    /// This is the increment used to step through keys, e.g. to the next directory page.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use xrpl_wasm_stdlib::core::types::uint::UInt;
+    ///
+    /// let value = UInt::<4>::from([0x00, 0x00, 0x00, 0xFF]);
+    /// assert_eq!(value.checked_add_u64(1), Some(UInt::from([0x00, 0x00, 0x01, 0x00])));
+    /// assert_eq!(UInt::<4>::MAX.checked_add_u64(1), None);
+    /// ```
+    pub fn checked_add_u64(&self, value: u64) -> Option<Self> {
+        let mut result = self.clone();
+        // Carry holds the part of `value` (plus the running carry) not yet added
+        let mut carry = value as u128;
+        for byte in result.0.iter_mut().rev() {
+            if carry == 0 {
+                break;
+            }
+            let sum = *byte as u128 + (carry & 0xFF);
+            *byte = sum as u8;
+            carry = (carry >> 8) + (sum >> 8);
+        }
+        if carry == 0 { Some(result) } else { None }
+    }
+
+    /// Parses exactly `2 * N` hex digits (either case) into a value.
+    ///
+    /// Returns `None` if the length is wrong or a character is not a hex digit. For literals,
+    /// prefer the [`hash256!`](crate::hash256) macro, which decodes at compile time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use xrpl_wasm_stdlib::core::types::uint::UInt;
+    ///
+    /// let value = UInt::<2>::from_hex(b"0aFF").unwrap();
+    /// assert_eq!(value.0, [0x0A, 0xFF]);
+    /// assert_eq!(UInt::<2>::from_hex(b"0aF"), None);
+    /// assert_eq!(UInt::<2>::from_hex(b"0aFG"), None);
+    /// ```
+    pub fn from_hex(hex: &[u8]) -> Option<Self> {
+        if hex.len() != N * 2 {
+            return None;
+        }
+        let mut out = Self::ZERO;
+        for (byte, digits) in out.0.iter_mut().zip(hex.chunks_exact(2)) {
+            let high = crate::hex_char_to_nibble(digits[0])?;
+            let low = crate::hex_char_to_nibble(digits[1])?;
+            *byte = (high << 4) | low;
+        }
+        Some(out)
+    }
+
+    /// Writes the value into `out` as `2 * N` uppercase hex digits and returns them.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&str)` - The hex digits, at the start of `out`
+    /// * `Err(Error::BufferTooSmall)` - If `out` is shorter than `2 * N` bytes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use xrpl_wasm_stdlib::core::types::uint::UInt;
+    ///
+    /// let mut buf = [0u8; 8];
+    /// let value = UInt::<2>::from([0x0A, 0xFF]);
+    /// assert_eq!(value.write_hex_upper(&mut buf).unwrap(), "0AFF");
+    /// assert_eq!(value.write_hex_lower(&mut buf).unwrap(), "0aff");
+    /// ```
+    pub fn write_hex_upper<'a>(&self, out: &'a mut [u8]) -> Result<&'a str> {
+        self.write_hex(out, false)
+    }
+
+    /// Writes the value into `out` as `2 * N` lowercase hex digits and returns them.
+    ///
+    /// See [`write_hex_upper`](Self::write_hex_upper).
+    pub fn write_hex_lower<'a>(&self, out: &'a mut [u8]) -> Result<&'a str> {
+        self.write_hex(out, true)
+    }
+
+    fn write_hex<'a>(&self, out: &'a mut [u8], lowercase: bool) -> Result<&'a str> {
+        let Some(out) = out.get_mut(..N * 2) else {
+            return Result::Err(Error::BufferTooSmall);
+        };
+        crate::encode_hex_upper(&self.0, out);
+        if lowercase {
+            out.make_ascii_lowercase();
+        }
+        // Only ASCII is written above.
+        Result::Ok(unsafe { core::str::from_utf8_unchecked(out) })
+    }
+}
+
+impl<const N: usize> Default for UInt<N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
 }
 
+impl<const N: usize> Not for UInt<N> {
+    type Output = Self;
+
+    fn not(mut self) -> Self {
+        for byte in self.0.iter_mut() {
+            *byte = !*byte;
+        }
+        self
+    }
+}
+
+impl<const N: usize> Not for &UInt<N> {
+    type Output = UInt<N>;
+
+    fn not(self) -> UInt<N> {
+        !self.clone()
+    }
+}
+
+/// Implements a bytewise binary operator, and its assigning form, for owned and borrowed `UInt`s.
+macro_rules! impl_bitwise_op {
+    ($op:ident, $method:ident, $assign_op:ident, $assign_method:ident) => {
+        impl<const N: usize> $assign_op<&UInt<N>> for UInt<N> {
+            fn $assign_method(&mut self, rhs: &UInt<N>) {
+                for (byte, rhs) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    $assign_op::$assign_method(byte, *rhs);
+                }
+            }
+        }
+
+        impl<const N: usize> $assign_op for UInt<N> {
+            fn $assign_method(&mut self, rhs: UInt<N>) {
+                $assign_op::$assign_method(self, &rhs);
+            }
+        }
+
+        impl<const N: usize> $op<&UInt<N>> for UInt<N> {
+            type Output = UInt<N>;
+
+            fn $method(mut self, rhs: &UInt<N>) -> UInt<N> {
+                $assign_op::$assign_method(&mut self, rhs);
+                self
+            }
+        }
+
+        impl<const N: usize> $op for UInt<N> {
+            type Output = UInt<N>;
+
+            fn $method(self, rhs: UInt<N>) -> UInt<N> {
+                $op::$method(self, &rhs)
+            }
+        }
+
+        impl<const N: usize> $op for &UInt<N> {
+            type Output = UInt<N>;
+
+            fn $method(self, rhs: &UInt<N>) -> UInt<N> {
+                $op::$method(self.clone(), rhs)
+            }
+        }
+    };
+}
+
+impl_bitwise_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
+impl_bitwise_op!(BitOr, bitor, BitOrAssign, bitor_assign);
+impl_bitwise_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
+
 // Keep the existing constants for compatibility
 pub const UINT128_SIZE: usize = 16;
 pub const UINT160_SIZE: usize = 20;
@@ -77,46 +299,85 @@ pub type Hash256 = UInt256;
 impl LedgerObjectFieldGetter for Hash128 {
     #[inline]
     fn get_from_current_ledger_obj(field_code: i32) -> Result<Self> {
-        match get_fixed_size_field_with_expected_bytes::<HASH128_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes::<HASH128_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_current_ledger_obj_field(fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.into()),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.into())
     }
 
     #[inline]
     fn get_from_current_ledger_obj_optional(field_code: i32) -> Result<Option<Self>> {
-        match get_fixed_size_field_with_expected_bytes_optional::<HASH128_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes_optional::<HASH128_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_current_ledger_obj_field(fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.map(|b| b.into())),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
     }
 
     #[inline]
     fn get_from_ledger_obj(register_num: i32, field_code: i32) -> Result<Self> {
-        match get_fixed_size_field_with_expected_bytes::<HASH128_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes::<HASH128_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_ledger_obj_field(register_num, fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.into()),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.into())
     }
 
     #[inline]
     fn get_from_ledger_obj_optional(register_num: i32, field_code: i32) -> Result<Option<Self>> {
-        match get_fixed_size_field_with_expected_bytes_optional::<HASH128_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes_optional::<HASH128_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_ledger_obj_field(register_num, fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.map(|b| b.into())),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested(locator: &Locator) -> Result<Self> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes::<HASH128_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(buffer.into())
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes_optional::<HASH128_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested(register_num: i32, locator: &Locator) -> Result<Self> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes::<HASH128_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(buffer.into())
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested_optional(
+        register_num: i32,
+        locator: &Locator,
+    ) -> Result<Option<Self>> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes_optional::<HASH128_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
     }
 }
 
@@ -133,46 +394,85 @@ impl LedgerObjectFieldGetter for Hash128 {
 impl LedgerObjectFieldGetter for Hash256 {
     #[inline]
     fn get_from_current_ledger_obj(field_code: i32) -> Result<Self> {
-        match get_fixed_size_field_with_expected_bytes::<HASH256_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes::<HASH256_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_current_ledger_obj_field(fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.into()),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.into())
     }
 
     #[inline]
     fn get_from_current_ledger_obj_optional(field_code: i32) -> Result<Option<Self>> {
-        match get_fixed_size_field_with_expected_bytes_optional::<HASH256_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes_optional::<HASH256_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_current_ledger_obj_field(fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.map(|b| b.into())),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
     }
 
     #[inline]
     fn get_from_ledger_obj(register_num: i32, field_code: i32) -> Result<Self> {
-        match get_fixed_size_field_with_expected_bytes::<HASH256_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes::<HASH256_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_ledger_obj_field(register_num, fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.into()),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.into())
     }
 
     #[inline]
     fn get_from_ledger_obj_optional(register_num: i32, field_code: i32) -> Result<Option<Self>> {
-        match get_fixed_size_field_with_expected_bytes_optional::<HASH256_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes_optional::<HASH256_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_ledger_obj_field(register_num, fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.map(|b| b.into())),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested(locator: &Locator) -> Result<Self> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes::<HASH256_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(buffer.into())
+    }
+
+    #[inline]
+    fn get_from_current_ledger_obj_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes_optional::<HASH256_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_current_ledger_obj_nested_field(loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested(register_num: i32, locator: &Locator) -> Result<Self> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes::<HASH256_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(buffer.into())
+    }
+
+    #[inline]
+    fn get_from_ledger_obj_nested_optional(
+        register_num: i32,
+        locator: &Locator,
+    ) -> Result<Option<Self>> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes_optional::<HASH256_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe {
+                get_ledger_obj_nested_field(register_num, loc, loc_len, buf, size)
+            },
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
     }
 }
 
@@ -189,24 +489,38 @@ impl LedgerObjectFieldGetter for Hash256 {
 impl CurrentTxFieldGetter for Hash256 {
     #[inline]
     fn get_from_current_tx(field_code: i32) -> Result<Self> {
-        match get_fixed_size_field_with_expected_bytes::<HASH256_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes::<HASH256_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_tx_field(fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.into()),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.into())
     }
 
     #[inline]
     fn get_from_current_tx_optional(field_code: i32) -> Result<Option<Self>> {
-        match get_fixed_size_field_with_expected_bytes_optional::<HASH256_SIZE, _>(
+        let buffer = get_fixed_size_field_with_expected_bytes_optional::<HASH256_SIZE, _>(
             field_code,
             |fc, buf, size| unsafe { get_tx_field(fc, buf, size) },
-        ) {
-            Result::Ok(buffer) => Result::Ok(buffer.map(|b| b.into())),
-            Result::Err(e) => Result::Err(e),
-        }
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested(locator: &Locator) -> Result<Self> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes::<HASH256_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )?;
+        Result::Ok(buffer.into())
+    }
+
+    #[inline]
+    fn get_from_current_tx_nested_optional(locator: &Locator) -> Result<Option<Self>> {
+        let buffer = get_fixed_size_nested_field_with_expected_bytes_optional::<HASH256_SIZE, _>(
+            locator,
+            |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+        )?;
+        Result::Ok(buffer.map(|b| b.into()))
     }
 }
 
@@ -562,4 +876,98 @@ mod tests {
         assert_eq!(uint2.as_bytes(), &[2u8; 16]);
         assert_eq!(uint3.as_bytes(), &[3u8; 16]);
     }
+
+    #[test]
+    fn test_uint_ordering_is_numeric() {
+        let low = UInt::<2>::from([0x00, 0xFF]);
+        let high = UInt::<2>::from([0x01, 0x00]);
+        assert!(low < high);
+        assert!(UInt::<2>::ZERO < low);
+        assert!(high < UInt::<2>::MAX);
+        assert_eq!(low.cmp(&low.clone()), core::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn test_uint_bitwise_ops() {
+        let a = UInt::<2>::from([0b1100, 0xF0]);
+        let b = UInt::<2>::from([0b1010, 0x0F]);
+
+        assert_eq!(&a & &b, UInt::from([0b1000, 0x00]));
+        assert_eq!(&a | &b, UInt::from([0b1110, 0xFF]));
+        assert_eq!(&a ^ &b, UInt::from([0b0110, 0xFF]));
+        assert_eq!(!&a, UInt::from([0xF3, 0x0F]));
+        assert_eq!(a.clone() ^ a.clone(), UInt::ZERO);
+
+        let mut c = a.clone();
+        c |= &b;
+        c &= UInt::from([0xFF, 0x0F]);
+        assert_eq!(c, UInt::from([0b1110, 0x0F]));
+    }
+
+    #[test]
+    fn test_uint_masks() {
+        assert_eq!(UInt::<2>::low_bits(0), UInt::ZERO);
+        assert_eq!(UInt::<2>::low_bits(4), UInt::from([0x00, 0x0F]));
+        assert_eq!(UInt::<2>::low_bits(12), UInt::from([0x0F, 0xFF]));
+        assert_eq!(UInt::<2>::low_bits(16), UInt::MAX);
+        assert_eq!(UInt::<2>::low_bits(100), UInt::MAX);
+
+        assert_eq!(UInt::<2>::high_bits(0), UInt::ZERO);
+        assert_eq!(UInt::<2>::high_bits(4), UInt::from([0xF0, 0x00]));
+        assert_eq!(UInt::<2>::high_bits(9), UInt::from([0xFF, 0x80]));
+        assert_eq!(UInt::<2>::high_bits(100), UInt::MAX);
+
+        // An NFTokenPage key splits into the owner (high 160 bits) and the token part (low 96)
+        let key = Hash256::from([0xAB; 32]);
+        let owner = &key & &Hash256::high_bits(160);
+        let token = &key & &Hash256::low_bits(96);
+        assert_eq!(owner.0[..20], [0xAB; 20]);
+        assert_eq!(owner.0[20..], [0; 12]);
+        assert_eq!(owner | token, key);
+    }
+
+    #[test]
+    fn test_uint_checked_add_u64() {
+        assert_eq!(
+            Hash256::ZERO.checked_add_u64(u64::MAX).unwrap().0[24..],
+            [0xFF; 8]
+        );
+
+        // Carries propagate past the low 8 bytes
+        let mut bytes = [0u8; 32];
+        bytes[24..].fill(0xFF);
+        let sum = Hash256::from(bytes).checked_add_u64(1).unwrap();
+        let mut expected = [0u8; 32];
+        expected[23] = 1;
+        assert_eq!(sum.0, expected);
+
+        assert_eq!(Hash256::MAX.checked_add_u64(0), Some(Hash256::MAX));
+        assert_eq!(Hash256::MAX.checked_add_u64(1), None);
+
+        // Values wider than the integer only fit if the extra bytes end up zero
+        assert_eq!(
+            UInt::<2>::ZERO.checked_add_u64(0xFFFF),
+            Some(UInt::<2>::MAX)
+        );
+        assert_eq!(UInt::<2>::ZERO.checked_add_u64(0x1_0000), None);
+        assert_eq!(UInt::<1>::from([0x80]).checked_add_u64(0x80), None);
+    }
+
+    #[test]
+    fn test_uint_hex_round_trip() {
+        let hex = b"6a1f0a2a8db7b0b4eaa38ade4ed9e0f1cf17b5e5f7be8c1c0d20f70f4f8c2e51";
+        let hash = Hash256::from_hex(hex).unwrap();
+        assert_eq!(Some(hash.0), crate::decode_hex_32(hex));
+
+        let mut buf = [0u8; 64];
+        assert_eq!(hash.write_hex_lower(&mut buf).unwrap().as_bytes(), hex);
+        let upper = hash.write_hex_upper(&mut buf).unwrap();
+        assert_eq!(upper.as_bytes(), hex.to_ascii_uppercase().as_slice());
+        assert_eq!(Hash256::from_hex(upper.as_bytes()), Some(hash.clone()));
+
+        assert_eq!(Hash256::from_hex(&hex[..62]), None);
+        assert_eq!(Hash256::from_hex(b"zz"), None);
+        let error = hash.write_hex_upper(&mut [0u8; 63]).err().unwrap();
+        assert_eq!(error.code(), Error::BufferTooSmall.code());
+    }
 }
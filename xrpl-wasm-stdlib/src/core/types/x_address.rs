@@ -0,0 +1,222 @@
+//! X-addresses, which pack a classic address, an optional destination tag and the network into a
+//! single string, as handed out by exchanges and custodians.
+//!
+//! See: <https://xrpaddress.info/>
+
+use crate::core::base58::{decode_check, encode_check};
+use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::host::{Error, Result};
+
+/// Length of an X-address. Every X-address is exactly this long.
+pub const X_ADDRESS_SIZE: usize = 47;
+
+/// Prefix of a mainnet X-address payload, which encodes as a leading `X`.
+const MAINNET_PREFIX: [u8; 2] = [0x05, 0x44];
+
+/// Prefix of a testnet X-address payload, which encodes as a leading `T`.
+const TESTNET_PREFIX: [u8; 2] = [0x04, 0x93];
+
+/// Prefix, AccountID, tag flag and the 8-byte tag field.
+const PAYLOAD_SIZE: usize = 2 + ACCOUNT_ID_SIZE + 1 + 8;
+
+/// A decoded X-address.
+///
+/// `x_address!` produces one for literals. Check an escrow against it with
+/// [`EscrowFields::matches_destination`](crate::core::ledger_objects::traits::EscrowFields::matches_destination),
+/// which also checks the network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XAddress {
+    /// The classic account.
+    pub account: AccountID,
+    /// The destination tag, if any.
+    pub tag: Option<u32>,
+    /// `true` for a testnet (`T...`) address, `false` for a mainnet (`X...`) address.
+    pub is_test: bool,
+}
+
+impl XAddress {
+    pub const fn new(account: AccountID, tag: Option<u32>, is_test: bool) -> Self {
+        XAddress {
+            account,
+            tag,
+            is_test,
+        }
+    }
+
+    /// Parses an X-address, validating its checksum.
+    ///
+    /// Returns [`Error::InvalidAccount`] if `address` is not valid base58check, has an unknown
+    /// network prefix, or uses a 64-bit tag (which the XRPL does not support).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use xrpl_wasm_stdlib::core::types::x_address::XAddress;
+    ///
+    /// let address =
+    ///     XAddress::from_x_address(b"XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC").unwrap();
+    /// assert_eq!(address.tag, Some(1));
+    /// assert!(!address.is_test);
+    /// ```
+    pub fn from_x_address(address: &[u8]) -> Result<XAddress> {
+        let mut payload = [0u8; PAYLOAD_SIZE];
+        if decode_check(address, &mut payload) != Some(PAYLOAD_SIZE) {
+            return Err(Error::InvalidAccount);
+        }
+
+        let is_test = match [payload[0], payload[1]] {
+            MAINNET_PREFIX => false,
+            TESTNET_PREFIX => true,
+            _ => return Err(Error::InvalidAccount),
+        };
+        let mut account = [0u8; ACCOUNT_ID_SIZE];
+        account.copy_from_slice(&payload[2..2 + ACCOUNT_ID_SIZE]);
+
+        let flag = payload[2 + ACCOUNT_ID_SIZE];
+        let tag_bytes = &payload[3 + ACCOUNT_ID_SIZE..];
+        // The tag is a little-endian u32 followed by 4 reserved zero bytes.
+        if tag_bytes[4..] != [0; 4] {
+            return Err(Error::InvalidAccount);
+        }
+        let tag = u32::from_le_bytes([tag_bytes[0], tag_bytes[1], tag_bytes[2], tag_bytes[3]]);
+        let tag = match (flag, tag) {
+            (0, 0) => None,
+            (1, tag) => Some(tag),
+            _ => return Err(Error::InvalidAccount),
+        };
+
+        Ok(XAddress::new(AccountID(account), tag, is_test))
+    }
+
+    /// Encodes the X-address into `out` and returns it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+    /// use xrpl_wasm_stdlib::core::types::x_address::{X_ADDRESS_SIZE, XAddress};
+    /// use xrpl_wasm_stdlib::r_address;
+    ///
+    /// let account = AccountID(r_address!("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf"));
+    /// let mut buf = [0u8; X_ADDRESS_SIZE];
+    /// assert_eq!(
+    ///     XAddress::new(account, None, false).to_x_address(&mut buf),
+    ///     "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb"
+    /// );
+    /// ```
+    pub fn to_x_address<'a>(&self, out: &'a mut [u8; X_ADDRESS_SIZE]) -> &'a str {
+        let mut payload = [0u8; PAYLOAD_SIZE];
+        payload[..2].copy_from_slice(if self.is_test {
+            &TESTNET_PREFIX
+        } else {
+            &MAINNET_PREFIX
+        });
+        payload[2..2 + ACCOUNT_ID_SIZE].copy_from_slice(&self.account.0);
+        if let Some(tag) = self.tag {
+            payload[2 + ACCOUNT_ID_SIZE] = 1;
+            payload[3 + ACCOUNT_ID_SIZE..7 + ACCOUNT_ID_SIZE].copy_from_slice(&tag.to_le_bytes());
+        }
+        // The prefixes are chosen so that every payload encodes to exactly 47 characters.
+        let len = encode_check(&payload, out).unwrap_or(0);
+        // The base58 alphabet is ASCII.
+        unsafe { core::str::from_utf8_unchecked(&out[..len]) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCOUNT: AccountID = AccountID(crate::r_address!("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf"));
+
+    // Vectors from the X-address specification.
+    const VECTORS: [(&[u8], Option<u32>, bool); 7] = [
+        (
+            b"XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb",
+            None,
+            false,
+        ),
+        (
+            b"XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+            Some(1),
+            false,
+        ),
+        (
+            b"XVLhHMPHU98es4dbozjVtdWzVrDjtVoD9z4jAcBVsnb97sM",
+            Some(14),
+            false,
+        ),
+        (
+            b"XVLhHMPHU98es4dbozjVtdWzVrDjtV1N75zgFKga4R1B9Mk",
+            Some(11747),
+            false,
+        ),
+        (
+            b"XVLhHMPHU98es4dbozjVtdWzVrDjtV18pX8yuPT7y4xaEHi",
+            Some(u32::MAX),
+            false,
+        ),
+        (
+            b"TVE26TYGhfLC7tQDno7G8dGtxSkYQn49b3qD26PK7FcGSKE",
+            None,
+            true,
+        ),
+        (
+            b"TVE26TYGhfLC7tQDno7G8dGtxSkYQnSz1uDimDdPYXzSpyw",
+            Some(1),
+            true,
+        ),
+    ];
+
+    #[test]
+    fn test_x_address_vectors() {
+        let mut buf = [0u8; X_ADDRESS_SIZE];
+        for (encoded, tag, is_test) in VECTORS {
+            let address = XAddress::new(ACCOUNT, tag, is_test);
+            assert_eq!(XAddress::from_x_address(encoded), Ok(address));
+            assert_eq!(address.to_x_address(&mut buf).as_bytes(), encoded);
+        }
+    }
+
+    #[test]
+    fn test_x_address_rejects_invalid() {
+        // Bad checksum
+        assert_eq!(
+            XAddress::from_x_address(b"XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXc"),
+            Err(Error::InvalidAccount)
+        );
+        // A classic address is not an X-address
+        assert_eq!(
+            XAddress::from_x_address(b"rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf"),
+            Err(Error::InvalidAccount)
+        );
+        assert!(XAddress::from_x_address(b"").is_err());
+
+        // Well-formed base58check, but a 64-bit tag flag or an unknown prefix
+        let mut payload = [0u8; PAYLOAD_SIZE];
+        payload[..2].copy_from_slice(&MAINNET_PREFIX);
+        payload[2 + ACCOUNT_ID_SIZE] = 2;
+        let mut buf = [0u8; X_ADDRESS_SIZE];
+        let len = encode_check(&payload, &mut buf).unwrap();
+        assert!(XAddress::from_x_address(&buf[..len]).is_err());
+
+        payload[..2].copy_from_slice(&[0x05, 0x45]);
+        payload[2 + ACCOUNT_ID_SIZE] = 0;
+        let len = encode_check(&payload, &mut buf).unwrap();
+        assert!(XAddress::from_x_address(&buf[..len]).is_err());
+    }
+
+    #[test]
+    fn test_x_address_macro_matches_runtime_decoder() {
+        const MAINNET: XAddress =
+            crate::x_address!("XVLhHMPHU98es4dbozjVtdWzVrDjtV1N75zgFKga4R1B9Mk");
+        assert_eq!(MAINNET, XAddress::new(ACCOUNT, Some(11747), false));
+
+        // The network is kept, not dropped
+        let testnet = crate::x_address!("TVE26TYGhfLC7tQDno7G8dGtxSkYQn49b3qD26PK7FcGSKE");
+        let decoded =
+            XAddress::from_x_address(b"TVE26TYGhfLC7tQDno7G8dGtxSkYQn49b3qD26PK7FcGSKE").unwrap();
+        assert_eq!(testnet, decoded);
+        assert!(testnet.is_test);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::host::Error::{InternalError, PointerOutOfBounds};
-use crate::host::trace::trace_num;
-use crate::host::{Error, Result, Result::Err, Result::Ok};
+use crate::host::trace::trace_error_num;
+use crate::host::{Error, Result};
 
 /// Reserved for internal invariant trips, generally unrelated to inputs.
 pub const INTERNAL_ERROR: i32 = -1;
@@ -42,6 +42,21 @@ pub const INDEX_OUT_OF_BOUNDS: i32 = -18;
 pub const INVALID_FLOAT_INPUT: i32 = -19;
 /// An error occurred during floating-point computation.
 pub const INVALID_FLOAT_COMPUTATION: i32 = -20;
+/// The contract state in an escrow's `Data` field was written with a different schema version.
+/// Raised by this library (see [`crate::core::state`]), never by the host.
+pub const STATE_VERSION_MISMATCH: i32 = -1000;
+/// A read ran past the end of the available bytes.
+/// Raised by this library (see [`crate::core::bytes`]), never by the host.
+pub const UNEXPECTED_END_OF_DATA: i32 = -1001;
+/// Two amounts of different assets (XRP, an IOU issue, or an MPT issuance) were combined.
+/// Raised by this library (see [`crate::core::types::amount`]), never by the host.
+pub const ASSET_MISMATCH: i32 = -1002;
+/// An amount calculation went outside the range its asset allows.
+/// Raised by this library (see [`crate::core::types::amount`]), never by the host.
+pub const AMOUNT_OVERFLOW: i32 = -1003;
+/// A key-value store in an escrow's `Data` field has no room left for an entry.
+/// Raised by this library (see [`crate::core::kv_store`]), never by the host.
+pub const KV_STORE_FULL: i32 = -1004;
 
 /// Evaluates a result code and executes a closure on success (result_code > 0).
 ///
@@ -198,16 +213,16 @@ where
         code if code == FIELD_NOT_FOUND => Ok(None),
         // Handle all positive, unexpected values as an internal error.
         code if code >= 0 => {
-            let _ = trace_num(
+            let _ = trace_error_num(
                 "Byte array was expected to have this many bytes: ",
                 expected_num_bytes as i64,
             );
-            let _ = trace_num("Byte array had this many bytes: ", code as i64);
+            let _ = trace_error_num("Byte array had this many bytes: ", code as i64);
             Err(PointerOutOfBounds)
         }
         // Handle all error values overtly.
         code => {
-            let _ = trace_num("Encountered error_code:", code as i64);
+            let _ = trace_error_num("Encountered error_code:", code as i64);
             Err(Error::from_code(code))
         }
     }
@@ -396,6 +411,11 @@ mod tests {
             INDEX_OUT_OF_BOUNDS,
             INVALID_FLOAT_INPUT,
             INVALID_FLOAT_COMPUTATION,
+            STATE_VERSION_MISMATCH,
+            UNEXPECTED_END_OF_DATA,
+            ASSET_MISMATCH,
+            AMOUNT_OVERFLOW,
+            KV_STORE_FULL,
         ];
 
         for &code in &error_codes {
@@ -426,6 +446,11 @@ mod tests {
             INDEX_OUT_OF_BOUNDS,
             INVALID_FLOAT_INPUT,
             INVALID_FLOAT_COMPUTATION,
+            STATE_VERSION_MISMATCH,
+            UNEXPECTED_END_OF_DATA,
+            ASSET_MISMATCH,
+            AMOUNT_OVERFLOW,
+            KV_STORE_FULL,
         ];
 
         // Check that all error codes are unique by comparing each pair
@@ -465,6 +490,11 @@ mod tests {
             INDEX_OUT_OF_BOUNDS,
             INVALID_FLOAT_INPUT,
             INVALID_FLOAT_COMPUTATION,
+            STATE_VERSION_MISMATCH,
+            UNEXPECTED_END_OF_DATA,
+            ASSET_MISMATCH,
+            AMOUNT_OVERFLOW,
+            KV_STORE_FULL,
         ];
 
         for &code in &test_codes {
@@ -478,6 +508,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_error_from_unlisted_code_is_unknown() {
+        for code in [-21, -999, -1100, i32::MIN] {
+            let error = Error::from_code(code);
+            assert_eq!(error, Error::Unknown(code));
+            assert_eq!(error.code(), code);
+            assert_eq!(error.message(), "unknown error");
+        }
+        assert_eq!(Error::from_code(FIELD_NOT_FOUND), Error::FieldNotFound);
+        assert_eq!(i64::from(Error::SlotsFull), SLOTS_FULL as i64);
+    }
+
+    #[test]
+    fn test_error_codes_are_always_negative() {
+        // Success values are not errors, and an error must never read back as a success code
+        for code in [0, 1, i32::MAX] {
+            assert_eq!(Error::from_code(code), Error::InternalError);
+            assert_eq!(Error::Unknown(code).code(), INTERNAL_ERROR);
+        }
+    }
+
+    #[test]
+    fn test_error_categories() {
+        assert!(Error::FieldNotFound.is_not_found());
+        assert!(Error::LedgerObjNotFound.is_not_found());
+        assert!(Error::BufferTooSmall.is_buffer());
+        assert!(Error::EmptySlot.is_slot());
+        assert!(Error::InvalidFloatComputation.is_float());
+        assert!(!Error::InvalidParams.is_not_found());
+        assert!(!Error::Unknown(-21).is_buffer());
+        assert_eq!(Error::SlotOutRange.message(), "slot out of range");
+    }
+
     #[test]
     fn test_closure_execution_count() {
         let mut execution_count = 0;
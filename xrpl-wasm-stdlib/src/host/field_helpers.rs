@@ -1,3 +1,4 @@
+use crate::core::locator::Locator;
 use crate::host::Result;
 use crate::host::error_codes::{
     match_result_code, match_result_code_optional, match_result_code_with_expected_bytes,
@@ -187,3 +188,122 @@ where
         Some((unsafe { buffer.assume_init() }, len))
     })
 }
+
+/// Nested-field variant of `get_fixed_size_field_with_expected_bytes`.
+///
+/// Identical to the flat helper, except that the field is identified by a packed [`Locator`]
+/// instead of a single field code.
+///
+/// # Arguments
+///
+/// * `locator` - The locator identifying which nested field to retrieve
+/// * `host_fn` - A closure that calls the appropriate nested host function
+///   - Takes: (locator_ptr: *const u8, locator_len: usize, buffer_ptr: *mut u8, buffer_size: usize) -> i32
+///   - Returns: result code (number of bytes written or error code)
+///
+/// # Example
+///
+/// ```ignore
+/// let buffer = get_fixed_size_nested_field_with_expected_bytes::<20, _>(
+///     &locator,
+///     |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+/// )?;
+/// ```
+#[inline]
+pub fn get_fixed_size_nested_field_with_expected_bytes<const N: usize, F>(
+    locator: &Locator,
+    host_fn: F,
+) -> Result<[u8; N]>
+where
+    F: FnOnce(*const u8, usize, *mut u8, usize) -> i32,
+{
+    let mut buffer = core::mem::MaybeUninit::<[u8; N]>::uninit();
+    let result_code = host_fn(
+        locator.as_ptr(),
+        locator.len(),
+        buffer.as_mut_ptr().cast(),
+        N,
+    );
+    match_result_code_with_expected_bytes(result_code, N, || unsafe { buffer.assume_init() })
+}
+
+/// Optional variant of `get_fixed_size_nested_field_with_expected_bytes`.
+///
+/// Returns `None` if the field is not found, otherwise behaves identically to the required variant.
+#[inline]
+pub fn get_fixed_size_nested_field_with_expected_bytes_optional<const N: usize, F>(
+    locator: &Locator,
+    host_fn: F,
+) -> Result<Option<[u8; N]>>
+where
+    F: FnOnce(*const u8, usize, *mut u8, usize) -> i32,
+{
+    let mut buffer = core::mem::MaybeUninit::<[u8; N]>::uninit();
+    let result_code = host_fn(
+        locator.as_ptr(),
+        locator.len(),
+        buffer.as_mut_ptr().cast(),
+        N,
+    );
+    match_result_code_with_expected_bytes_optional(result_code, N, || {
+        Some(unsafe { buffer.assume_init() })
+    })
+}
+
+/// Nested-field variant of `get_variable_size_field`.
+///
+/// Identical to the flat helper, except that the field is identified by a packed [`Locator`]
+/// instead of a single field code.
+///
+/// # Example
+///
+/// ```ignore
+/// let (buffer, len) = get_variable_size_nested_field::<48, _>(
+///     &locator,
+///     |loc, loc_len, buf, size| unsafe { get_tx_nested_field(loc, loc_len, buf, size) },
+/// )?;
+/// ```
+#[inline]
+pub fn get_variable_size_nested_field<const N: usize, F>(
+    locator: &Locator,
+    host_fn: F,
+) -> Result<([u8; N], usize)>
+where
+    F: FnOnce(*const u8, usize, *mut u8, usize) -> i32,
+{
+    let mut buffer = core::mem::MaybeUninit::<[u8; N]>::uninit();
+    let result_code = host_fn(
+        locator.as_ptr(),
+        locator.len(),
+        buffer.as_mut_ptr().cast(),
+        N,
+    );
+    match_result_code(result_code, || {
+        let len = result_code as usize;
+        (unsafe { buffer.assume_init() }, len)
+    })
+}
+
+/// Optional variant of `get_variable_size_nested_field`.
+///
+/// Returns `None` if the field is not found, otherwise behaves identically to the required variant.
+#[inline]
+pub fn get_variable_size_nested_field_optional<const N: usize, F>(
+    locator: &Locator,
+    host_fn: F,
+) -> Result<Option<([u8; N], usize)>>
+where
+    F: FnOnce(*const u8, usize, *mut u8, usize) -> i32,
+{
+    let mut buffer = core::mem::MaybeUninit::<[u8; N]>::uninit();
+    let result_code = host_fn(
+        locator.as_ptr(),
+        locator.len(),
+        buffer.as_mut_ptr().cast(),
+        N,
+    );
+    match_result_code_optional(result_code, || {
+        let len = result_code as usize;
+        Some((unsafe { buffer.assume_init() }, len))
+    })
+}
@@ -59,7 +59,10 @@ pub unsafe fn cache_ledger_obj(_keylet_ptr: *const u8, _keylet_len: usize, _cach
 #[allow(unused)]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe fn get_tx_field(_field: i32, _out_buff_ptr: *mut u8, _out_buff_len: usize) -> i32 {
-    _out_buff_len as i32
+    // A top-level field is served like the one-step locator that names it
+    let locator = _field.to_le_bytes();
+    unsafe { nested_field_fixture(locator.as_ptr(), locator.len(), _out_buff_ptr, _out_buff_len) }
+        .unwrap_or(_out_buff_len as i32)
 }
 
 #[allow(unused)]
@@ -83,6 +86,65 @@ pub unsafe fn get_ledger_obj_field(
     _out_buff_len as i32
 }
 
+/// Values served by the nested field stand-ins in unit tests, keyed by the raw locator bytes.
+#[cfg(test)]
+mod nested_fields {
+    extern crate std;
+    use std::{cell::RefCell, vec::Vec};
+
+    std::thread_local! {
+        static FIELDS: RefCell<Vec<(Vec<u8>, Vec<u8>)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub fn set(locator: &[u8], value: &[u8]) {
+        FIELDS.with(|fields| fields.borrow_mut().push((locator.to_vec(), value.to_vec())));
+    }
+
+    /// Copies the value registered for `locator` into `out` and returns the copied length.
+    pub fn copy(locator: &[u8], out: &mut [u8]) -> Option<usize> {
+        FIELDS.with(|fields| {
+            let fields = fields.borrow();
+            let (_, value) = fields.iter().rev().find(|(key, _)| key == locator)?;
+            let len = value.len().min(out.len());
+            out[..len].copy_from_slice(&value[..len]);
+            Some(len)
+        })
+    }
+}
+
+/// Makes the nested field getters return `value` for `locator` on the current test thread.
+#[cfg(test)]
+pub(crate) fn set_nested_field(locator: &crate::core::locator::Locator, value: &[u8]) {
+    nested_fields::set(
+        unsafe { slice::from_raw_parts(locator.as_ptr(), locator.len()) },
+        value,
+    );
+}
+
+/// Makes `get_tx_field` return `value` for `field` on the current test thread.
+#[cfg(test)]
+pub(crate) fn set_tx_field(field: i32, value: &[u8]) {
+    nested_fields::set(&field.to_le_bytes(), value);
+}
+
+/// Serves a value registered with `set_nested_field` or `set_tx_field`, or `None` to fall back to the stub.
+#[allow(unused)]
+unsafe fn nested_field_fixture(
+    locator_ptr: *const u8,
+    locator_len: usize,
+    out_buff_ptr: *mut u8,
+    out_buff_len: usize,
+) -> Option<i32> {
+    #[cfg(test)]
+    {
+        let locator = unsafe { slice::from_raw_parts(locator_ptr, locator_len) };
+        let out = unsafe { slice::from_raw_parts_mut(out_buff_ptr, out_buff_len) };
+        nested_fields::copy(locator, out).map(|len| len as i32)
+    }
+    #[cfg(not(test))]
+    None
+}
+
 #[allow(unused)]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe fn get_tx_nested_field(
@@ -91,7 +153,8 @@ pub unsafe fn get_tx_nested_field(
     _out_buff_ptr: *mut u8,
     _out_buff_len: usize,
 ) -> i32 {
-    _out_buff_len as i32
+    unsafe { nested_field_fixture(_locator_ptr, _locator_len, _out_buff_ptr, _out_buff_len) }
+        .unwrap_or(_out_buff_len as i32)
 }
 
 #[allow(unused)]
@@ -102,7 +165,8 @@ pub unsafe fn get_current_ledger_obj_nested_field(
     _out_buff_ptr: *mut u8,
     _out_buff_len: usize,
 ) -> i32 {
-    _out_buff_len as i32
+    unsafe { nested_field_fixture(_locator_ptr, _locator_len, _out_buff_ptr, _out_buff_len) }
+        .unwrap_or(_out_buff_len as i32)
 }
 
 #[allow(unused)]
@@ -114,7 +178,8 @@ pub unsafe fn get_ledger_obj_nested_field(
     _out_buff_ptr: *mut u8,
     _out_buff_len: usize,
 ) -> i32 {
-    _out_buff_len as i32
+    unsafe { nested_field_fixture(_locator_ptr, _locator_len, _out_buff_ptr, _out_buff_len) }
+        .unwrap_or(_out_buff_len as i32)
 }
 
 #[allow(unused)]
@@ -34,116 +34,52 @@ include!("host_bindings_for_testing.rs");
 include!("host_bindings.rs");
 
 /// `Result` is a type that represents either a success ([`Ok`]) or failure ([`Err`]) result from the host.
-#[must_use]
-pub enum Result<T> {
-    /// Contains the success value
-    Ok(T),
-    /// Contains the error value
-    Err(Error), // TODO: Test if the WASM size is expanded if we use an enum here instead of i32
+///
+/// It is `core::result::Result` with the error fixed to [`Error`], so the `?` operator and all of the
+/// standard combinators work. [`ResultExt`] adds `unwrap_or_panic` and `trace_err`.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Extra methods for [`Result`].
+pub trait ResultExt<T> {
+    /// Returns the contained [`Ok`] value, or traces the error code and panics with the caller's
+    /// location.
+    fn unwrap_or_panic(self) -> T;
+
+    /// Traces `msg` with the error code if the result is an [`Err`], and returns the result
+    /// unchanged so it can be followed by `?`.
+    fn trace_err(self, msg: &str) -> Self;
 }
 
-impl<T> Result<T> {
-    /// Returns `true` if the result is [`Ok`].
-    #[inline]
-    pub fn is_ok(&self) -> bool {
-        matches!(*self, Result::Ok(_))
-    }
-
-    /// Returns `true` if the result is [`Err`].
-    #[inline]
-    pub fn is_err(&self) -> bool {
-        !self.is_ok()
-    }
-
-    /// Converts from `Result<T>` to `Option<T>`.
-    ///
-    /// Converts `self` into an `Option<T>`, consuming `self`,
-    /// and discarding the error, if any.
-    #[inline]
-    pub fn ok(self) -> Option<T> {
-        match self {
-            Result::Ok(x) => Some(x),
-            Result::Err(_) => None,
-        }
-    }
-
-    /// Converts from `Result<T>` to `Option<Error>`.
-    ///
-    /// Converts `self` into an `Option<Error>`, consuming `self`,
-    /// and discarding the success value, if any.
-    #[inline]
-    pub fn err(self) -> Option<Error> {
-        match self {
-            Result::Ok(_) => None,
-            Result::Err(x) => Some(x),
-        }
-    }
-
-    /// Returns the contained [`Ok`] value, consuming the `self` value.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the value is an [`Err`], with a panic message provided by the
-    /// [`Err`]'s value.
+impl<T> ResultExt<T> for Result<T> {
     #[inline]
-    pub fn unwrap(self) -> T {
+    #[track_caller]
+    fn unwrap_or_panic(self) -> T {
         match self {
-            Result::Ok(t) => t,
-            Result::Err(error) => {
-                let _ = trace::trace_num("error_code=", error.code() as i64);
-                panic!(
-                    "called `Result::unwrap()` on an `Err` with code: {}",
+            Ok(t) => t,
+            Err(error) => {
+                let _ = trace::trace_error_num("error_code=", error.code() as i64);
+                core::panic!(
+                    "Failed in {}: error_code={}",
+                    core::panic::Location::caller(),
                     error.code()
-                )
+                );
             }
         }
     }
 
-    /// Returns the contained [`Ok`] value or a provided default.
     #[inline]
-    pub fn unwrap_or(self, default: T) -> T {
-        match self {
-            Result::Ok(t) => t,
-            Result::Err(_) => default,
-        }
-    }
-
-    /// Returns the contained [`Ok`] value or computes it from a closure.
-    #[inline]
-    pub fn unwrap_or_else<F: FnOnce(Error) -> T>(self, op: F) -> T {
-        match self {
-            Result::Ok(t) => t,
-            Result::Err(e) => op(e),
-        }
-    }
-
-    #[inline]
-    pub fn unwrap_or_panic(self) -> T {
-        self.unwrap_or_else(|error| {
-            let _ = trace::trace_num("error_code=", error.code() as i64);
-            core::panic!(
-                "Failed in {}: error_code={}",
-                core::panic::Location::caller(),
-                error.code()
-            );
-        })
-    }
-}
-
-impl From<i64> for Result<u64> {
-    #[inline(always)] // <-- Inline because this function is very small
-    fn from(value: i64) -> Self {
-        match value {
-            res if res >= 0 => Result::Ok(value as _),
-            _ => Result::Err(Error::from_code(value as _)),
+    fn trace_err(self, msg: &str) -> Self {
+        if let Err(error) = &self {
+            let _ = trace::trace_error_num(msg, error.code() as i64);
         }
+        self
     }
 }
 
 /// Possible errors returned by XRPL Programmability APIs.
 ///
 /// Errors are global across all Programmability APIs.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(i32)]
 pub enum Error {
     /// Reserved for internal invariant trips, generally unrelated to inputs.
@@ -227,24 +163,153 @@ pub enum Error {
     /// An error occurred during floating-point computation.
     /// This may indicate overflow, underflow, or other arithmetic errors.
     InvalidFloatComputation = error_codes::INVALID_FLOAT_COMPUTATION,
+
+    /// The contract state stored in an escrow's `Data` field has a different schema version than
+    /// the one being loaded. Raised by this library, never by the host.
+    StateVersionMismatch = error_codes::STATE_VERSION_MISMATCH,
+
+    /// A read ran past the end of the available bytes.
+    /// Returned by `ByteReader` instead of panicking on short input. Raised by this library, never
+    /// by the host.
+    UnexpectedEndOfData = error_codes::UNEXPECTED_END_OF_DATA,
+
+    /// Two amounts of different assets were added, subtracted or compared.
+    /// Raised by this library, never by the host.
+    AssetMismatch = error_codes::ASSET_MISMATCH,
+
+    /// An XRP amount went beyond the total supply of drops, an MPT amount beyond `i64::MAX`, or an
+    /// IOU amount beyond the largest normalized value.
+    /// Raised by this library, never by the host.
+    AmountOverflow = error_codes::AMOUNT_OVERFLOW,
+
+    /// A `KvStore` entry did not fit in the space left in the escrow's `Data` field.
+    /// Raised by this library, never by the host.
+    KvStoreFull = error_codes::KV_STORE_FULL,
+
+    /// A negative code not listed above, for example from a newer host.
+    ///
+    /// Error codes are always negative: [`Error::code`] reports a non-negative payload as
+    /// [`Error::InternalError`] so that an error can never be mistaken for a success code.
+    Unknown(i32) = i32::MIN,
 }
 
 impl Error {
-    // TODO: Use Trait instead?
-    #[inline(always)] // <-- Inline because this function is very small
+    /// Converts a host result code into an [`Error`], falling back to [`Error::Unknown`] for
+    /// negative codes that are not listed.
+    ///
+    /// Non-negative values are success results, not errors; they map to [`Error::InternalError`].
+    #[inline]
     pub fn from_code(code: i32) -> Self {
-        unsafe { core::mem::transmute(code) }
+        match code {
+            error_codes::INTERNAL_ERROR => Error::InternalError,
+            error_codes::FIELD_NOT_FOUND => Error::FieldNotFound,
+            error_codes::BUFFER_TOO_SMALL => Error::BufferTooSmall,
+            error_codes::NO_ARRAY => Error::NoArray,
+            error_codes::NOT_LEAF_FIELD => Error::NotLeafField,
+            error_codes::LOCATOR_MALFORMED => Error::LocatorMalformed,
+            error_codes::SLOT_OUT_RANGE => Error::SlotOutRange,
+            error_codes::SLOTS_FULL => Error::SlotsFull,
+            error_codes::EMPTY_SLOT => Error::EmptySlot,
+            error_codes::LEDGER_OBJ_NOT_FOUND => Error::LedgerObjNotFound,
+            error_codes::INVALID_DECODING => Error::InvalidDecoding,
+            error_codes::DATA_FIELD_TOO_LARGE => Error::DataFieldTooLarge,
+            error_codes::POINTER_OUT_OF_BOUNDS => Error::PointerOutOfBounds,
+            error_codes::NO_MEM_EXPORTED => Error::NoMemoryExported,
+            error_codes::INVALID_PARAMS => Error::InvalidParams,
+            error_codes::INVALID_ACCOUNT => Error::InvalidAccount,
+            error_codes::INVALID_FIELD => Error::InvalidField,
+            error_codes::INDEX_OUT_OF_BOUNDS => Error::IndexOutOfBounds,
+            error_codes::INVALID_FLOAT_INPUT => Error::InvalidFloatInput,
+            error_codes::INVALID_FLOAT_COMPUTATION => Error::InvalidFloatComputation,
+            error_codes::STATE_VERSION_MISMATCH => Error::StateVersionMismatch,
+            error_codes::UNEXPECTED_END_OF_DATA => Error::UnexpectedEndOfData,
+            error_codes::ASSET_MISMATCH => Error::AssetMismatch,
+            error_codes::AMOUNT_OVERFLOW => Error::AmountOverflow,
+            error_codes::KV_STORE_FULL => Error::KvStoreFull,
+            code if code >= 0 => Error::InternalError,
+            code => Error::Unknown(code),
+        }
     }
 
     /// Error code
-    #[inline(always)] // <-- Inline because this function is very small
+    #[inline]
     pub fn code(self) -> i32 {
-        self as _
+        match self {
+            Error::Unknown(code) if code < 0 => code,
+            Error::Unknown(_) => error_codes::INTERNAL_ERROR,
+            // SAFETY: `Error` is `repr(i32)`, so every variant starts with its `i32` discriminant.
+            known => unsafe { *(&known as *const Error as *const i32) },
+        }
+    }
+
+    /// A short, static description of the error, suitable for tracing.
+    pub fn message(self) -> &'static str {
+        match self {
+            Error::InternalError => "internal error",
+            Error::FieldNotFound => "field not found",
+            Error::BufferTooSmall => "buffer too small",
+            Error::NoArray => "not an array",
+            Error::NotLeafField => "not a leaf field",
+            Error::LocatorMalformed => "malformed locator",
+            Error::SlotOutRange => "slot out of range",
+            Error::SlotsFull => "no free slots",
+            Error::EmptySlot => "empty slot",
+            Error::LedgerObjNotFound => "ledger object not found",
+            Error::InvalidDecoding => "invalid decoding",
+            Error::DataFieldTooLarge => "data field too large",
+            Error::PointerOutOfBounds => "pointer out of bounds",
+            Error::NoMemoryExported => "no memory exported",
+            Error::InvalidParams => "invalid parameters",
+            Error::InvalidAccount => "invalid account",
+            Error::InvalidField => "invalid field",
+            Error::IndexOutOfBounds => "index out of bounds",
+            Error::InvalidFloatInput => "invalid float input",
+            Error::InvalidFloatComputation => "invalid float computation",
+            Error::StateVersionMismatch => "state version mismatch",
+            Error::UnexpectedEndOfData => "unexpected end of data",
+            Error::AssetMismatch => "asset mismatch",
+            Error::AmountOverflow => "amount overflow",
+            Error::KvStoreFull => "key-value store full",
+            Error::Unknown(_) => "unknown error",
+        }
+    }
+
+    /// Returns `true` if a field or ledger object does not exist.
+    pub fn is_not_found(self) -> bool {
+        matches!(self, Error::FieldNotFound | Error::LedgerObjNotFound)
+    }
+
+    /// Returns `true` for buffer and memory errors.
+    pub fn is_buffer(self) -> bool {
+        matches!(
+            self,
+            Error::BufferTooSmall
+                | Error::DataFieldTooLarge
+                | Error::PointerOutOfBounds
+                | Error::NoMemoryExported
+                | Error::UnexpectedEndOfData
+        )
+    }
+
+    /// Returns `true` for ledger-object slot errors.
+    pub fn is_slot(self) -> bool {
+        matches!(
+            self,
+            Error::SlotOutRange | Error::SlotsFull | Error::EmptySlot
+        )
+    }
+
+    /// Returns `true` for float input and computation errors.
+    pub fn is_float(self) -> bool {
+        matches!(
+            self,
+            Error::InvalidFloatInput | Error::InvalidFloatComputation
+        )
     }
 }
 
 impl From<Error> for i64 {
     fn from(val: Error) -> Self {
-        val as i64
+        val.code() as i64
     }
 }
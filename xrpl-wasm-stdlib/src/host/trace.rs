@@ -1,7 +1,12 @@
 use crate::host::error_codes::match_result_code;
 
-use crate::core::types::account_id::AccountID;
+use crate::core::types::account_id::{AccountID, R_ADDRESS_MAX_SIZE};
 use crate::core::types::amount::Amount;
+use crate::core::types::blob::Blob;
+use crate::core::types::currency::{CURRENCY_CODE_MAX_SIZE, Currency};
+use crate::core::types::mpt_id::MptId;
+use crate::core::types::opaque_float::OpaqueFloat;
+use crate::core::types::uint::{UINT256_SIZE, UInt};
 use crate::host;
 use crate::host::Result;
 
@@ -14,6 +19,50 @@ pub enum DataRepr {
     AsHex = 1,
 }
 
+/// How much is written to the xrpld trace log, selected at compile time with cargo features.
+///
+/// Every trace costs gas and the formatting code adds to the binary size, so a production escrow
+/// can turn tracing down or off without touching its code:
+///
+/// * `trace-off`: nothing is traced.
+/// * `trace-error`: only error diagnostics are traced, i.e. [`trace_error_num`], `trace_err`,
+///   `unwrap_or_panic` and `#[finish(trace_errors)]`.
+/// * `trace-debug`: everything is traced. This is the default when no level is selected.
+///
+/// When several levels are enabled the most verbose one wins, so a crate can depend on the library
+/// with `trace-off` and still offer its own `debug` feature that enables `trace-debug`.
+///
+/// Traces above the selected level return `Ok(0)` without calling the host, and the compiler
+/// removes them together with their arguments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TraceLevel {
+    /// Nothing is traced.
+    Off = 0,
+    /// Only error diagnostics are traced.
+    Error = 1,
+    /// Everything is traced.
+    Debug = 2,
+}
+
+/// The trace level this crate was compiled with. See [`TraceLevel`].
+pub const TRACE_LEVEL: TraceLevel = if cfg!(feature = "trace-debug") {
+    TraceLevel::Debug
+} else if cfg!(feature = "trace-error") {
+    TraceLevel::Error
+} else if cfg!(feature = "trace-off") {
+    TraceLevel::Off
+} else {
+    TraceLevel::Debug
+};
+
+impl TraceLevel {
+    /// Returns `true` if traces at this level are written with the current [`TRACE_LEVEL`].
+    #[inline(always)]
+    pub const fn is_enabled(self) -> bool {
+        self as u8 <= TRACE_LEVEL as u8
+    }
+}
+
 /// Write the contents of a message to the xrpld trace log.
 ///
 /// # Parameters
@@ -26,6 +75,14 @@ pub enum DataRepr {
 /// an error (e.g., incorrect buffer sizes).
 #[inline(always)] // <-- Inline because this function is very small
 pub fn trace(msg: &str) -> Result<i32> {
+    if !TraceLevel::Debug.is_enabled() {
+        return Ok(0);
+    }
+    write_msg(msg)
+}
+
+#[inline(always)]
+fn write_msg(msg: &str) -> Result<i32> {
     // Use an empty slice's pointer instead of null to satisfy Rust's safety requirements
     // Even for zero-length slices, `slice::from_raw_parts` requires a non-null, aligned pointer
     let empty_data: &[u8] = &[];
@@ -55,6 +112,9 @@ pub fn trace(msg: &str) -> Result<i32> {
 /// an error (e.g., incorrect buffer sizes).
 #[inline(always)] // <-- Inline because this function is very small
 pub fn trace_data(msg: &str, data: &[u8], data_repr: DataRepr) -> Result<i32> {
+    if !TraceLevel::Debug.is_enabled() {
+        return Ok(0);
+    }
     let result_code = unsafe {
         let data_ptr = data.as_ptr();
         let data_len = data.len();
@@ -77,12 +137,33 @@ pub fn trace_data(msg: &str, data: &[u8], data_repr: DataRepr) -> Result<i32> {
 /// an error (e.g., incorrect buffer sizes).
 #[inline(always)]
 pub fn trace_num(msg: &str, number: i64) -> Result<i32> {
+    if !TraceLevel::Debug.is_enabled() {
+        return Ok(0);
+    }
+    write_num(msg, number)
+}
+
+/// Like [`trace_num`], but written at [`TraceLevel::Error`] so it is kept by the `trace-error`
+/// feature. Use it to report why an escrow is failing, typically with an error code.
+#[inline(always)]
+pub fn trace_error_num(msg: &str, number: i64) -> Result<i32> {
+    if !TraceLevel::Error.is_enabled() {
+        return Ok(0);
+    }
+    write_num(msg, number)
+}
+
+#[inline(always)]
+fn write_num(msg: &str, number: i64) -> Result<i32> {
     let result_code = unsafe { host::trace_num(msg.as_ptr(), msg.len(), number) };
     match_result_code(result_code, || result_code)
 }
 
 #[inline(always)]
 pub fn trace_account_buf(msg: &str, account_id: &[u8; 20]) -> Result<i32> {
+    if !TraceLevel::Debug.is_enabled() {
+        return Ok(0);
+    }
     let result_code = unsafe {
         host::trace_account(
             msg.as_ptr(),
@@ -96,6 +177,9 @@ pub fn trace_account_buf(msg: &str, account_id: &[u8; 20]) -> Result<i32> {
 
 #[inline(always)]
 pub fn trace_account(msg: &str, account_id: &AccountID) -> Result<i32> {
+    if !TraceLevel::Debug.is_enabled() {
+        return Ok(0);
+    }
     let result_code = unsafe {
         host::trace_account(
             msg.as_ptr(),
@@ -109,6 +193,9 @@ pub fn trace_account(msg: &str, account_id: &AccountID) -> Result<i32> {
 
 #[inline(always)]
 pub fn trace_amount(msg: &str, amount: &Amount) -> Result<i32> {
+    if !TraceLevel::Debug.is_enabled() {
+        return Ok(0);
+    }
     // Convert Amount to the STAmount format expected by the host trace function
     let (amount_bytes, len) = amount.to_stamount_bytes();
 
@@ -121,10 +208,382 @@ pub fn trace_amount(msg: &str, amount: &Amount) -> Result<i32> {
 /// Write a float to the XRPLD trace log
 #[inline(always)]
 pub fn trace_float(msg: &str, f: &[u8; 8]) -> Result<i32> {
+    if !TraceLevel::Debug.is_enabled() {
+        return Ok(0);
+    }
     let result_code = unsafe { host::trace_opaque_float(msg.as_ptr(), msg.len(), f.as_ptr(), 8) };
     match_result_code(result_code, || result_code)
 }
 
+/// Size of the stack buffer that [`trace!`](crate::trace) formats into.
+pub const TRACE_BUFFER_SIZE: usize = 256;
+
+/// Appended to a message that did not fit in the buffer.
+const ELLIPSIS: &str = "...";
+
+/// Formats a message with `{}` placeholders into a fixed stack buffer and writes it to the xrpld
+/// trace log with a single host call.
+///
+/// Arguments may be any [`TraceArg`]: integers, `bool`, `&str`, `AccountID` (as an r-address),
+/// `UInt<N>` hashes and blobs (as hex), `Currency`, `OpaqueFloat` and `Amount`. Use `{{` and `}}`
+/// for literal braces. The number of placeholders is checked at compile time, and messages longer
+/// than [`TRACE_BUFFER_SIZE`] bytes are truncated and end in `...`.
+///
+/// # Example
+///
+/// ```no_run
+/// use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+/// use xrpl_wasm_stdlib::trace;
+///
+/// let price = 95u64;
+/// let threshold = 100u64;
+/// let account = AccountID([0u8; 20]);
+/// let _ = trace!("price {} below threshold {} for {}", price, threshold, account);
+/// ```
+///
+/// A placeholder without an argument is a compile error:
+///
+/// ```compile_fail
+/// let _ = xrpl_wasm_stdlib::trace!("{} and {}", 1u8);
+/// ```
+#[macro_export]
+macro_rules! trace {
+    ($fmt:literal $(, $arg:expr)* $(,)?) => {{
+        const _: () = ::core::assert!(
+            $crate::host::trace::count_placeholders($fmt)
+                == <[&str]>::len(&[$(::core::stringify!($arg)),*]),
+            "the number of `{{}}` placeholders does not match the number of arguments",
+        );
+        $crate::host::trace::trace_fmt($fmt, &[$(&$arg as &dyn $crate::host::trace::TraceArg),*])
+    }};
+}
+
+/// Formats `fmt` with `args` (see [`trace!`](crate::trace)) and writes it to the trace log.
+///
+/// Placeholders without a matching argument are written as `{}`; extra arguments are ignored.
+/// Nothing is formatted unless [`TraceLevel::Debug`] is enabled.
+pub fn trace_fmt(fmt: &str, args: &[&dyn TraceArg]) -> Result<i32> {
+    if !TraceLevel::Debug.is_enabled() {
+        return Ok(0);
+    }
+    let mut buf = TraceBuffer::new();
+    buf.write_fmt(fmt, args);
+    trace(buf.as_str())
+}
+
+/// Traces where and why the contract panicked, at [`TraceLevel::Error`]. Called by the panic
+/// handler when the `panic-trace` feature is enabled.
+#[cfg(all(target_arch = "wasm32", feature = "panic-trace"))]
+pub(crate) fn trace_panic(info: &core::panic::PanicInfo) {
+    if !TraceLevel::Error.is_enabled() {
+        return;
+    }
+    let mut buf = TraceBuffer::new();
+    buf.write_str("panicked at ");
+    if let Some(location) = info.location() {
+        buf.write_location(location);
+        buf.write_str(": ");
+    }
+    let _ = core::fmt::Write::write_fmt(&mut buf, format_args!("{}", info.message()));
+    let _ = write_msg(buf.as_str());
+}
+
+/// Counts the `{}` placeholders in a format string, skipping `{{` and `}}` escapes.
+#[doc(hidden)]
+pub const fn count_placeholders(fmt: &str) -> usize {
+    let bytes = fmt.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if i + 1 < bytes.len() {
+            match (bytes[i], bytes[i + 1]) {
+                (b'{', b'}') => count += 1,
+                (b'{', b'{') | (b'}', b'}') => {}
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            }
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    count
+}
+
+/// A fixed-size UTF-8 buffer that trace messages are formatted into.
+///
+/// Writes past the end are dropped and the message is marked as truncated; the buffer always holds
+/// valid UTF-8.
+pub struct TraceBuffer {
+    buf: [u8; TRACE_BUFFER_SIZE],
+    len: usize,
+    truncated: bool,
+}
+
+impl TraceBuffer {
+    pub const fn new() -> Self {
+        TraceBuffer {
+            buf: [0; TRACE_BUFFER_SIZE],
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    /// The formatted message.
+    pub fn as_str(&self) -> &str {
+        // Only whole `&str`s (or prefixes cut at a char boundary) are ever copied in.
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+
+    /// Returns `true` if some output was dropped because the buffer was full.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    pub fn write_str(&mut self, s: &str) {
+        if self.truncated {
+            return;
+        }
+        if s.len() <= TRACE_BUFFER_SIZE - self.len {
+            self.buf[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+            self.len += s.len();
+            return;
+        }
+
+        // Keep as much as fits in front of the ellipsis, cutting on a char boundary.
+        let limit = TRACE_BUFFER_SIZE - ELLIPSIS.len();
+        if self.len > limit {
+            self.len = limit;
+            while self.buf[self.len] & 0xC0 == 0x80 {
+                self.len -= 1;
+            }
+        } else {
+            let mut n = limit - self.len;
+            while !s.is_char_boundary(n) {
+                n -= 1;
+            }
+            self.buf[self.len..self.len + n].copy_from_slice(&s.as_bytes()[..n]);
+            self.len += n;
+        }
+        self.buf[self.len..self.len + ELLIPSIS.len()].copy_from_slice(ELLIPSIS.as_bytes());
+        self.len += ELLIPSIS.len();
+        self.truncated = true;
+    }
+
+    pub fn write_u64(&mut self, mut value: u64) {
+        let mut digits = [0u8; 20];
+        let mut start = digits.len();
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (value % 10) as u8;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        self.write_ascii(&digits[start..]);
+    }
+
+    pub fn write_i64(&mut self, value: i64) {
+        if value < 0 {
+            self.write_str("-");
+        }
+        self.write_u64(value.unsigned_abs());
+    }
+
+    /// Writes `bytes` as uppercase hex.
+    pub fn write_hex(&mut self, bytes: &[u8]) {
+        let mut chunk = [0u8; 32];
+        for bytes in bytes.chunks(chunk.len() / 2) {
+            crate::encode_hex_upper(bytes, &mut chunk);
+            self.write_ascii(&chunk[..bytes.len() * 2]);
+        }
+    }
+
+    /// Writes `fmt`, replacing each `{}` with the next argument.
+    pub fn write_fmt(&mut self, fmt: &str, args: &[&dyn TraceArg]) {
+        let mut args = args.iter();
+        let mut rest = fmt;
+        while let Some(pos) = rest.find(['{', '}']) {
+            self.write_str(&rest[..pos]);
+            let tail = &rest[pos..];
+            if let Some(after) = tail.strip_prefix("{}") {
+                match args.next() {
+                    Some(arg) => arg.write_trace(self),
+                    None => self.write_str("{}"),
+                }
+                rest = after;
+            } else if tail.starts_with("{{") || tail.starts_with("}}") {
+                self.write_str(&tail[..1]);
+                rest = &tail[2..];
+            } else {
+                self.write_str(&tail[..1]);
+                rest = &tail[1..];
+            }
+        }
+        self.write_str(rest);
+    }
+
+    /// Writes a source location as `file:line:column`.
+    pub fn write_location(&mut self, location: &core::panic::Location) {
+        self.write_str(location.file());
+        self.write_str(":");
+        self.write_u64(location.line() as u64);
+        self.write_str(":");
+        self.write_u64(location.column() as u64);
+    }
+
+    fn write_ascii(&mut self, bytes: &[u8]) {
+        // Callers only pass ASCII digits and letters.
+        self.write_str(unsafe { core::str::from_utf8_unchecked(bytes) });
+    }
+}
+
+/// Allows `core::fmt` output, e.g. a panic message, to be written into the buffer. It never fails;
+/// output that does not fit is truncated.
+impl core::fmt::Write for TraceBuffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        TraceBuffer::write_str(self, s);
+        Ok(())
+    }
+}
+
+impl Default for TraceBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A value that can be formatted by [`trace!`](crate::trace).
+pub trait TraceArg {
+    fn write_trace(&self, buf: &mut TraceBuffer);
+}
+
+impl<T: TraceArg + ?Sized> TraceArg for &T {
+    fn write_trace(&self, buf: &mut TraceBuffer) {
+        (**self).write_trace(buf)
+    }
+}
+
+macro_rules! impl_trace_arg_for_int {
+    ($method:ident as $wide:ty: $($t:ty),*) => {
+        $(
+            impl TraceArg for $t {
+                fn write_trace(&self, buf: &mut TraceBuffer) {
+                    buf.$method(*self as $wide)
+                }
+            }
+        )*
+    };
+}
+
+impl_trace_arg_for_int!(write_u64 as u64: u8, u16, u32, u64, usize);
+impl_trace_arg_for_int!(write_i64 as i64: i8, i16, i32, i64, isize);
+
+impl TraceArg for bool {
+    fn write_trace(&self, buf: &mut TraceBuffer) {
+        buf.write_str(if *self { "true" } else { "false" })
+    }
+}
+
+impl TraceArg for str {
+    fn write_trace(&self, buf: &mut TraceBuffer) {
+        buf.write_str(self)
+    }
+}
+
+impl TraceArg for AccountID {
+    fn write_trace(&self, buf: &mut TraceBuffer) {
+        let mut address = [0u8; R_ADDRESS_MAX_SIZE];
+        buf.write_str(self.to_r_address(&mut address));
+    }
+}
+
+impl<const N: usize> TraceArg for UInt<N> {
+    fn write_trace(&self, buf: &mut TraceBuffer) {
+        let mut hex = [0u8; 2 * UINT256_SIZE];
+        match self.write_hex_upper(&mut hex) {
+            Result::Ok(hex) => buf.write_str(hex),
+            // Wider than any XRPL hash
+            Result::Err(_) => buf.write_hex(&self.0),
+        }
+    }
+}
+
+impl<const N: usize> TraceArg for Blob<N> {
+    fn write_trace(&self, buf: &mut TraceBuffer) {
+        buf.write_hex(self.as_slice())
+    }
+}
+
+impl TraceArg for MptId {
+    fn write_trace(&self, buf: &mut TraceBuffer) {
+        buf.write_hex(self.as_bytes())
+    }
+}
+
+/// Written as `XRP`, a standard three-letter code, or 40 hex digits (see [`Currency::write_code`]).
+impl TraceArg for Currency {
+    fn write_trace(&self, buf: &mut TraceBuffer) {
+        let mut code = [0u8; CURRENCY_CODE_MAX_SIZE];
+        buf.write_str(self.write_code(&mut code));
+    }
+}
+
+/// Written as `<mantissa>e<exponent>`, e.g. `1500000000000000e-15` for 1.5.
+impl TraceArg for OpaqueFloat {
+    fn write_trace(&self, buf: &mut TraceBuffer) {
+        let bits = u64::from_be_bytes(self.0);
+        let mantissa = bits & ((1 << 54) - 1);
+        if mantissa == 0 {
+            return buf.write_str("0");
+        }
+        if bits & (1 << 62) == 0 {
+            buf.write_str("-");
+        }
+        buf.write_u64(mantissa);
+        buf.write_str("e");
+        buf.write_i64(((bits >> 54) & 0xFF) as i64 - 97);
+    }
+}
+
+/// XRP is written in drops, IOUs as `<value> <currency>/<issuer>` and MPTs as `<units> MPT/<id>`.
+impl TraceArg for Amount {
+    fn write_trace(&self, buf: &mut TraceBuffer) {
+        match self {
+            Amount::XRP { num_drops } => {
+                buf.write_i64(*num_drops);
+                buf.write_str(" drops");
+            }
+            Amount::IOU {
+                amount,
+                issuer,
+                currency,
+            } => {
+                amount.write_trace(buf);
+                buf.write_str(" ");
+                currency.write_trace(buf);
+                buf.write_str("/");
+                issuer.write_trace(buf);
+            }
+            Amount::MPT {
+                num_units,
+                is_positive,
+                mpt_id,
+            } => {
+                if !is_positive {
+                    buf.write_str("-");
+                }
+                buf.write_u64(*num_units);
+                buf.write_str(" MPT/");
+                mpt_id.write_trace(buf);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -273,4 +732,162 @@ mod tests {
         assert_eq!(bytes[0], 0b_0110_0000); // Positive MPT prefix
         assert_eq!(&bytes[1..9], &VALUE.to_be_bytes()); // Amount bytes
     }
+
+    fn format(fmt: &str, args: &[&dyn TraceArg]) -> TraceBuffer {
+        let mut buf = TraceBuffer::new();
+        buf.write_fmt(fmt, args);
+        buf
+    }
+
+    #[test]
+    fn test_trace_fmt_integers_and_escapes() {
+        let buf = format(
+            "{{price}} {} < {} ok={} {}",
+            &[&95u64, &-7i32, &true, &"done"],
+        );
+        assert_eq!(buf.as_str(), "{price} 95 < -7 ok=true done");
+        assert!(!buf.is_truncated());
+
+        let buf = format("{} {} {}", &[&u64::MAX, &i64::MIN, &0u8]);
+        assert_eq!(buf.as_str(), "18446744073709551615 -9223372036854775808 0");
+
+        // Missing arguments leave the placeholder in place
+        assert_eq!(format("a={} b={}", &[&1u8]).as_str(), "a=1 b={}");
+    }
+
+    #[test]
+    fn test_trace_fmt_account_as_r_address() {
+        let account = AccountID(crate::r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"));
+        let buf = format("for {}", &[&account]);
+        assert_eq!(buf.as_str(), "for rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+
+        let zero = AccountID([0u8; 20]);
+        assert_eq!(
+            format("{}", &[&zero]).as_str(),
+            "rrrrrrrrrrrrrrrrrrrrrhoLvTp"
+        );
+    }
+
+    #[test]
+    fn test_trace_fmt_hashes_currencies_and_amounts() {
+        let hash = UInt::<4>([0xDE, 0xAD, 0x00, 0x0F]);
+        assert_eq!(format("{}", &[&hash]).as_str(), "DEAD000F");
+
+        let mut usd = [0u8; 20];
+        usd[12..15].copy_from_slice(b"USD");
+        assert_eq!(format("{}", &[&Currency(usd)]).as_str(), "USD");
+        assert_eq!(
+            format("{}", &[&Currency([2u8; 20])]).as_str(),
+            "0202020202020202020202020202020202020202"
+        );
+
+        use crate::core::types::opaque_float::{FLOAT_NEGATIVE_ONE, FLOAT_ONE};
+        assert_eq!(
+            format("{}", &[&OpaqueFloat(FLOAT_ONE)]).as_str(),
+            "1000000000000000e-15"
+        );
+        assert_eq!(
+            format("{}", &[&OpaqueFloat(FLOAT_NEGATIVE_ONE)]).as_str(),
+            "-1000000000000000e-15"
+        );
+
+        let xrp = Amount::XRP { num_drops: -10 };
+        assert_eq!(format("{}", &[&xrp]).as_str(), "-10 drops");
+
+        let iou = Amount::IOU {
+            amount: OpaqueFloat(FLOAT_ONE),
+            issuer: AccountID([0u8; 20]),
+            currency: Currency(usd),
+        };
+        assert_eq!(
+            format("{}", &[&iou]).as_str(),
+            "1000000000000000e-15 USD/rrrrrrrrrrrrrrrrrrrrrhoLvTp"
+        );
+    }
+
+    #[test]
+    fn test_trace_fmt_truncates_on_char_boundary() {
+        let long = [b'x'; TRACE_BUFFER_SIZE];
+        let long = core::str::from_utf8(&long).unwrap();
+        let buf = format("{}{}", &[&long, &1u8]);
+        assert!(buf.is_truncated());
+        assert_eq!(buf.as_str().len(), TRACE_BUFFER_SIZE);
+        assert!(buf.as_str().ends_with("x..."));
+
+        // A multi-byte char straddling the limit is dropped entirely
+        let mut buf = TraceBuffer::new();
+        buf.write_str(&long[..TRACE_BUFFER_SIZE - ELLIPSIS.len() - 1]);
+        buf.write_str("é and more");
+        assert!(buf.is_truncated());
+        assert!(buf.as_str().ends_with("x..."));
+        assert_eq!(buf.as_str().len(), TRACE_BUFFER_SIZE - 1);
+
+        // Exactly filling the buffer is not a truncation
+        let mut buf = TraceBuffer::new();
+        buf.write_str(long);
+        assert!(!buf.is_truncated());
+        buf.write_str("");
+        assert!(!buf.is_truncated());
+    }
+
+    #[test]
+    fn test_count_placeholders() {
+        assert_eq!(count_placeholders(""), 0);
+        assert_eq!(count_placeholders("{} and {}"), 2);
+        assert_eq!(count_placeholders("{{}} {{{}}}"), 1);
+        assert_eq!(count_placeholders("{ } }{"), 0);
+    }
+
+    #[test]
+    fn test_trace_macro() {
+        let account = AccountID([0u8; 20]);
+        assert!(crate::trace!("price {} < {} for {}", 1u64, 2u64, account).is_ok());
+        assert!(crate::trace!("no args").is_ok());
+    }
+
+    #[test]
+    fn test_trace_level_follows_features() {
+        let expected = if cfg!(feature = "trace-debug") {
+            TraceLevel::Debug
+        } else if cfg!(feature = "trace-error") {
+            TraceLevel::Error
+        } else if cfg!(feature = "trace-off") {
+            TraceLevel::Off
+        } else {
+            TraceLevel::Debug
+        };
+        assert_eq!(TRACE_LEVEL, expected);
+        assert!(TraceLevel::Off.is_enabled());
+        assert_eq!(
+            TraceLevel::Error.is_enabled(),
+            expected >= TraceLevel::Error
+        );
+        assert_eq!(
+            TraceLevel::Debug.is_enabled(),
+            expected == TraceLevel::Debug
+        );
+        assert!(trace_error_num("error", -1).is_ok());
+    }
+
+    #[test]
+    fn test_trace_buffer_location_and_fmt_write() {
+        let location = core::panic::Location::caller();
+        let mut buf = TraceBuffer::new();
+        buf.write_str("panicked at ");
+        buf.write_location(location);
+        buf.write_str(": ");
+        core::fmt::Write::write_fmt(&mut buf, format_args!("index {} out of range", 7)).unwrap();
+
+        let message = buf.as_str();
+        assert!(message.starts_with("panicked at xrpl-wasm-stdlib/src/host/trace.rs:"));
+        assert!(message.ends_with(": index 7 out of range"));
+
+        // Formatted output is truncated like any other write
+        let mut buf = TraceBuffer::new();
+        let long = [b'y'; TRACE_BUFFER_SIZE];
+        let long = core::str::from_utf8(&long).unwrap();
+        core::fmt::Write::write_fmt(&mut buf, format_args!("{}{}", long, long)).unwrap();
+        assert!(buf.is_truncated());
+        assert_eq!(buf.as_str().len(), TRACE_BUFFER_SIZE);
+    }
 }
@@ -1,8 +1,14 @@
 #![doc = include_str!("../../README.md")]
 #![no_std]
 
-// Re-export the r_address macro for convenient access
-pub use xrpl_address_macro::r_address;
+// Re-export the proc macros for convenient access
+pub use xrpl_address_macro::{
+    currency, finish, hash256, locator, mpt_id, nft_id, public_key, r_address, x_address,
+    xrpl_object,
+};
+
+// Lets the proc macros refer to `::xrpl_wasm_stdlib` from inside this crate as well.
+extern crate self as xrpl_wasm_stdlib;
 
 pub mod core;
 pub mod host;
@@ -21,7 +27,7 @@ pub mod guide {}
 
 /// This function is called on panic but only in the WASM architecture. In non-WASM (e.g., in the
 /// Host Simulator) the standard lib is available, which includes a panic handler.
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", not(feature = "panic-trace")))]
 #[panic_handler]
 fn panic(_info: &::core::panic::PanicInfo) -> ! {
     // This instruction will halt execution of the WASM module.
@@ -29,6 +35,29 @@ fn panic(_info: &::core::panic::PanicInfo) -> ! {
     ::core::arch::wasm32::unreachable();
 }
 
+/// With the `panic-trace` feature, the panic location and message are traced before trapping.
+/// This keeps the panic messages and `core::fmt` in the binary, so it is meant for debug builds.
+#[cfg(all(target_arch = "wasm32", feature = "panic-trace"))]
+#[panic_handler]
+fn panic(info: &::core::panic::PanicInfo) -> ! {
+    host::trace::trace_panic(info);
+    ::core::arch::wasm32::unreachable();
+}
+
+/// Uppercase hex digits, shared by every hex encoder in the crate.
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Writes `bytes` into `out` as uppercase hex, two digits per byte.
+///
+/// Stops at the end of the shorter of `bytes` and `out`.
+#[inline]
+pub(crate) fn encode_hex_upper(bytes: &[u8], out: &mut [u8]) {
+    for (byte, digits) in bytes.iter().zip(out.chunks_exact_mut(2)) {
+        digits[0] = HEX_DIGITS[(byte >> 4) as usize];
+        digits[1] = HEX_DIGITS[(byte & 0x0F) as usize];
+    }
+}
+
 #[inline(always)]
 fn hex_char_to_nibble(c: u8) -> Option<u8> {
     // WASM-optimized hex decoding with branch conditions for better performance
@@ -61,7 +90,8 @@ fn hex_char_to_nibble(c: u8) -> Option<u8> {
 /// Decode a 64-hex-character string into a 32-byte array.
 ///
 /// The input must be exactly 64 hexadecimal ASCII bytes (lower- or upper-case).
-/// Returns `None` if any character is not a valid hex digit.
+/// Returns `None` if any character is not a valid hex digit. For literals, prefer the
+/// [`hash256!`] macro, which decodes at compile time.
 ///
 /// Example:
 /// ```
@@ -167,3 +197,63 @@ pub fn decode_hex_20(hex: &[u8; 40]) -> Option<[u8; 20]> {
 
     Some(out)
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::host::Error::FieldNotFound;
+    use crate::host::Result;
+
+    #[crate::finish(trace_errors)]
+    fn finish() -> Result<bool> {
+        Result::Err(FieldNotFound)
+    }
+
+    #[test]
+    fn test_finish_entry_maps_result_to_exit_code() {
+        assert!(finish().is_err());
+        assert_eq!(finish_entry(), FieldNotFound.code());
+    }
+
+    #[test]
+    fn test_literal_macros() {
+        use crate::core::types::account_id::AccountID;
+        use crate::core::types::currency::Currency;
+        use crate::core::types::mpt_id::MptId;
+        use crate::core::types::nft::NFToken;
+        use crate::core::types::public_key::PublicKey;
+        use crate::core::types::uint::Hash256;
+
+        const HASH: Hash256 =
+            crate::hash256!("6a1f0a2a8db7b0b4eaa38ade4ed9e0f1cf17b5e5f7be8c1c0d20f70f4f8c2e51");
+        assert_eq!(
+            HASH.0,
+            crate::decode_hex_32(
+                b"6A1F0A2A8DB7B0B4EAA38ADE4ED9E0F1CF17B5E5F7BE8C1C0D20F70F4F8C2E51"
+            )
+            .unwrap()
+        );
+
+        const USD: Currency = crate::currency!("USD");
+        assert_eq!(USD, Currency::from(*b"USD"));
+        const RLUSD: Currency = crate::currency!("524C555344000000000000000000000000000000");
+        assert_eq!(&RLUSD.0[..5], b"RLUSD");
+
+        const MPT: MptId = crate::mpt_id!("00000001AE123A8556F3CF91154711376AFB0F894F832B3D");
+        assert_eq!(MPT.get_sequence_num(), 1);
+        assert_eq!(
+            MPT.get_issuer(),
+            AccountID(crate::decode_hex_20(b"AE123A8556F3CF91154711376AFB0F894F832B3D").unwrap())
+        );
+
+        const NFT: NFToken =
+            crate::nft_id!("000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65");
+        assert_eq!(u16::from_be_bytes([NFT.0[2], NFT.0[3]]), 314); // transfer fee
+        assert_eq!(&NFT.0[28..], &[0x00, 0x00, 0x0D, 0x65]); // sequence
+
+        const KEY: PublicKey = crate::public_key!(
+            "ED5F5AC8B98974A3CA843326D9B88CEBD0560177B973EE0B149F782CFAA06DC66A"
+        );
+        assert_eq!(KEY.0[0], 0xED);
+        assert_eq!(KEY.0[32], 0x6A);
+    }
+}
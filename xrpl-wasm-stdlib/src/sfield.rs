@@ -1,5 +1,37 @@
 #![allow(non_upper_case_globals)]
 
+// Serialized type codes (the upper 16 bits of every field code)
+pub const STI_UNKNOWN: i32 = -2;
+pub const STI_NOTPRESENT: i32 = 0;
+pub const STI_UINT16: i32 = 1;
+pub const STI_UINT32: i32 = 2;
+pub const STI_UINT64: i32 = 3;
+pub const STI_UINT128: i32 = 4;
+pub const STI_UINT256: i32 = 5;
+pub const STI_AMOUNT: i32 = 6;
+pub const STI_VL: i32 = 7;
+pub const STI_ACCOUNT: i32 = 8;
+pub const STI_NUMBER: i32 = 9;
+pub const STI_INT32: i32 = 10;
+pub const STI_INT64: i32 = 11;
+pub const STI_OBJECT: i32 = 14;
+pub const STI_ARRAY: i32 = 15;
+pub const STI_UINT8: i32 = 16;
+pub const STI_UINT160: i32 = 17;
+pub const STI_PATHSET: i32 = 18;
+pub const STI_VECTOR256: i32 = 19;
+pub const STI_UINT96: i32 = 20;
+pub const STI_UINT192: i32 = 21;
+pub const STI_UINT384: i32 = 22;
+pub const STI_UINT512: i32 = 23;
+pub const STI_ISSUE: i32 = 24;
+pub const STI_XCHAIN_BRIDGE: i32 = 25;
+pub const STI_CURRENCY: i32 = 26;
+pub const STI_TRANSACTION: i32 = 10001;
+pub const STI_LEDGERENTRY: i32 = 10002;
+pub const STI_VALIDATION: i32 = 10003;
+pub const STI_METADATA: i32 = 10004;
+
 pub const Invalid: i32 = -1;
 pub const Generic: i32 = 0;
 pub const LedgerEntryType: i32 = 65537;
@@ -0,0 +1,97 @@
+//! Expansion for `#[derive(ContractState)]`.
+//!
+//! The derive implements `StateField` by encoding every field in declaration order, and
+//! `ContractState` with the version given by `#[contract_state(version = N)]` (default 1).
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Index, LitInt};
+
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let version = parse_version(&input)?;
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "ContractState can only be derived for structs",
+        ));
+    };
+
+    let state = quote! { ::xrpl_wasm_stdlib::core::state };
+    let result = quote! { ::xrpl_wasm_stdlib::host::Result };
+
+    let (encode_fields, decode_body) = match &data.fields {
+        Fields::Named(fields) => {
+            let names: Vec<_> = fields.named.iter().map(|f| &f.ident).collect();
+            let encode = names.iter().map(|name| quote! { &self.#name });
+            let decode = names.iter().map(|name| {
+                quote! { let #name = decoder.read()?; }
+            });
+            let encode: Vec<_> = encode.collect();
+            (
+                encode,
+                quote! { #(#decode)* #result::Ok(Self { #(#names),* }) },
+            )
+        }
+        Fields::Unnamed(fields) => {
+            let indices: Vec<_> = (0..fields.unnamed.len()).map(Index::from).collect();
+            let bindings: Vec<_> = (0..fields.unnamed.len())
+                .map(|i| quote::format_ident!("field_{}", i))
+                .collect();
+            let encode = indices
+                .iter()
+                .map(|index| quote! { &self.#index })
+                .collect();
+            let decode = bindings.iter().map(|binding| {
+                quote! { let #binding = decoder.read()?; }
+            });
+            (
+                encode,
+                quote! { #(#decode)* #result::Ok(Self(#(#bindings),*)) },
+            )
+        }
+        Fields::Unit => (Vec::new(), quote! { #result::Ok(Self) }),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #state::StateField for #name #ty_generics #where_clause {
+            fn encode_state(&self, encoder: &mut #state::StateEncoder) -> #result<()> {
+                #( encoder.write(#encode_fields)?; )*
+                #result::Ok(())
+            }
+
+            fn decode_state(decoder: &mut #state::StateDecoder) -> #result<Self> {
+                #decode_body
+            }
+        }
+
+        impl #impl_generics #state::ContractState for #name #ty_generics #where_clause {
+            const VERSION: u8 = #version;
+        }
+    })
+}
+
+/// Reads `#[contract_state(version = N)]`, defaulting to version 1.
+fn parse_version(input: &DeriveInput) -> syn::Result<u8> {
+    let mut version = 1u8;
+    for attr in &input.attrs {
+        if !attr.path().is_ident("contract_state") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("version") {
+                let lit: LitInt = meta.value()?.parse()?;
+                version = lit.base10_parse::<u8>().map_err(|_| {
+                    syn::Error::new(lit.span(), "version must be between 0 and 255")
+                })?;
+                Ok(())
+            } else {
+                Err(meta.error("expected `version = <number>`"))
+            }
+        })?;
+    }
+    Ok(version)
+}
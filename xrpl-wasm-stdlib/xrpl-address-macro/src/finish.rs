@@ -0,0 +1,102 @@
+//! Expansion for the `#[finish]` attribute.
+//!
+//! The annotated function is kept unchanged and a `finish_entry` wrapper is exported under the
+//! `finish` symbol that the host calls:
+//!
+//! ```text
+//! #[finish(trace_errors)]
+//! fn finish() -> Result<bool> { ... }
+//!
+//! #[unsafe(export_name = "finish")]
+//! pub extern "C" fn finish_entry() -> i32 {
+//!     match finish() {
+//!         Result::Ok(true) => 1,
+//!         Result::Ok(false) => 0,
+//!         Result::Err(e) => { /* trace when requested */ e.code() /* if negative, else -1 */ }
+//!     }
+//! }
+//! ```
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::meta::ParseNestedMeta;
+use syn::{ItemFn, ReturnType};
+
+#[derive(Default)]
+pub(crate) struct FinishArgs {
+    trace_errors: bool,
+}
+
+impl FinishArgs {
+    pub(crate) fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("trace_errors") {
+            self.trace_errors = true;
+            Ok(())
+        } else {
+            Err(meta.error("expected `trace_errors`"))
+        }
+    }
+}
+
+pub(crate) fn expand(args: FinishArgs, item: ItemFn) -> syn::Result<TokenStream> {
+    let sig = &item.sig;
+    if !sig.inputs.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &sig.inputs,
+            "a #[finish] function takes no arguments",
+        ));
+    }
+    if sig.asyncness.is_some() || !sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            sig,
+            "a #[finish] function cannot be async or generic",
+        ));
+    }
+    if let ReturnType::Default = sig.output {
+        return Err(syn::Error::new_spanned(
+            &sig.ident,
+            "a #[finish] function must return `Result<bool>`",
+        ));
+    }
+
+    let name = &sig.ident;
+    let result = quote! { ::xrpl_wasm_stdlib::host::Result };
+    let on_error = if args.trace_errors {
+        // `file!()` and `line!()` resolve at the annotated function.
+        quote_spanned! {name.span()=>
+            let _ = ::xrpl_wasm_stdlib::host::trace::trace_error_num(
+                concat!("finish failed in ", file!(), ":", line!(), " with error code"),
+                e.code() as i64,
+            );
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    Ok(quote! {
+        #item
+
+        /// The `finish` entry point called by the host, generated by `#[finish]`.
+        ///
+        /// Returns 1 to release the escrow, 0 to keep it locked, or a negative error code. An error
+        /// whose code is not negative is reported as `InternalError`, so that an `Err` can never
+        /// release the escrow. It can also be called from native tests to check the exit code.
+        #[unsafe(export_name = "finish")]
+        pub extern "C" fn finish_entry() -> i32 {
+            match #name() {
+                #result::Ok(true) => 1,
+                #result::Ok(false) => 0,
+                #result::Err(e) => {
+                    #on_error
+                    // A non-negative exit code would release the escrow
+                    let code = e.code();
+                    if code < 0 {
+                        code
+                    } else {
+                        ::xrpl_wasm_stdlib::host::Error::InternalError.code()
+                    }
+                }
+            }
+        }
+    })
+}
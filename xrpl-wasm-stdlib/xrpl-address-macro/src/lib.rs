@@ -20,7 +20,236 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{LitStr, parse_macro_input};
+use syn::{DeriveInput, ItemFn, ItemTrait, LitStr, Path, parse_macro_input};
+
+mod contract_state;
+mod finish;
+mod literals;
+mod locator;
+mod x_address;
+mod xrpl_object;
+
+/// Derives `ContractState` (and `StateField`) for a struct stored in an escrow's `Data` field.
+///
+/// Fields are encoded in declaration order and must themselves implement `StateField`. Set the
+/// schema version with `#[contract_state(version = N)]`; it defaults to 1.
+///
+/// # Example
+/// ```shell
+/// use xrpl_wasm_stdlib::core::state::ContractState;
+/// use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+///
+/// #[derive(ContractState)]
+/// #[contract_state(version = 2)]
+/// struct Approvals {
+///     approver: AccountID,
+///     count: u32,
+/// }
+/// ```
+#[proc_macro_derive(ContractState, attributes(contract_state))]
+pub fn derive_contract_state(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    contract_state::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Builds a `Locator` for a nested field path at compile time.
+///
+/// Field names are resolved against `xrpl_wasm_stdlib::sfield`, array indices must be integer
+/// literals, and the packed path is checked against `LOCATOR_BUFFER_SIZE` during compilation.
+/// Prefix the path with `slot = N,` to pack a slot number for cached ledger objects.
+///
+/// # Example
+/// ```shell
+/// use xrpl_wasm_stdlib::core::locator::Locator;
+/// use xrpl_wasm_stdlib::locator;
+///
+/// const MEMO_DATA: Locator = locator!(Memos[0].MemoData);
+/// const ASSET_PRICE: Locator = locator!(slot = 1, PriceDataSeries[0].AssetPrice);
+/// ```
+#[proc_macro]
+pub fn locator(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as locator::LocatorInput);
+    locator::expand(input).into()
+}
+
+/// Generates field-accessor traits from a list of field declarations.
+///
+/// Each method is annotated with `#[field(Name)]` (an `sfield` name) and has no body; a return
+/// type of `Result<Option<T>>` makes the field optional, any other `Result<T>` makes it required.
+/// For `LedgerEntryType::X` both the slot-based trait and its `Current`-prefixed twin for the
+/// current ledger object are generated; for `TransactionType::X` (or bare `TransactionType`) a
+/// single trait reading the current transaction is generated. Methods with a body are copied
+/// unchanged, and `#[current]` / `#[slot]` restrict them to one of the two ledger traits.
+///
+/// # Example
+/// ```shell
+/// use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+/// use xrpl_wasm_stdlib::host::Result;
+/// use xrpl_wasm_stdlib::xrpl_object;
+///
+/// #[xrpl_object(LedgerEntryType::Oracle)]
+/// pub trait OracleFields {
+///     /// The account that owns this Oracle.
+///     #[field(Owner)]
+///     fn get_owner(&self) -> Result<AccountID>;
+///
+///     /// The time the data was last updated, in seconds since the Unix epoch.
+///     #[field(LastUpdateTime)]
+///     fn get_last_update_time(&self) -> Result<Option<u32>>;
+/// }
+/// // Generates `OracleFields: LedgerObjectCommonFields` and
+/// // `CurrentOracleFields: CurrentLedgerObjectCommonFields`.
+/// ```
+#[proc_macro_attribute]
+pub fn xrpl_object(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = parse_macro_input!(attr as Path);
+    let item = parse_macro_input!(item as ItemTrait);
+    xrpl_object::expand(attr, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Exports a `fn() -> Result<bool>` as the escrow's `finish` entry point.
+///
+/// `Ok(true)` releases the escrow (returns 1), `Ok(false)` keeps it locked (returns 0) and
+/// `Err(e)` returns `e.code()`. With `#[finish(trace_errors)]` the error code is also traced
+/// together with the file and line of the annotated function. The function itself is left
+/// unchanged, and the generated `finish_entry()` wrapper can be called from native tests.
+///
+/// # Example
+/// ```shell
+/// use xrpl_wasm_stdlib::core::current_tx::escrow_finish;
+/// use xrpl_wasm_stdlib::core::current_tx::traits::TransactionCommonFields;
+/// use xrpl_wasm_stdlib::host::Result;
+///
+/// #[xrpl_wasm_stdlib::finish(trace_errors)]
+/// fn finish() -> Result<bool> {
+///     let sequence = escrow_finish::get_current_escrow_finish().get_sequence()?;
+///     Ok(sequence > 10)
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn finish(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut args = finish::FinishArgs::default();
+    let parser = syn::meta::parser(|meta| args.parse(meta));
+    parse_macro_input!(attr with parser);
+    let item = parse_macro_input!(item as ItemFn);
+    finish::expand(args, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generates a `#[proc_macro]` for a typed literal that expands through `literals::$name`.
+macro_rules! literal_macro {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[proc_macro]
+        pub fn $name(input: TokenStream) -> TokenStream {
+            let lit = parse_macro_input!(input as LitStr);
+            literals::$name(lit)
+                .unwrap_or_else(syn::Error::into_compile_error)
+                .into()
+        }
+    };
+}
+
+literal_macro! {
+    /// Decodes 64 hex digits at compile time into a `Hash256`, e.g. a ledger object ID or a
+    /// transaction hash.
+    ///
+    /// # Example
+    /// ```shell
+    /// use xrpl_wasm_stdlib::core::types::uint::Hash256;
+    /// use xrpl_wasm_stdlib::hash256;
+    ///
+    /// const ORACLE_ID: Hash256 =
+    ///     hash256!("6A1F0A2A8DB7B0B4EAA38ADE4ED9E0F1CF17B5E5F7BE8C1C0D20F70F4F8C2E51");
+    /// ```
+    hash256
+}
+
+literal_macro! {
+    /// Builds a `Currency` at compile time from a three-character code such as `"USD"`, or from
+    /// 40 hex digits for a non-standard code.
+    ///
+    /// `"XRP"` is rejected because XRP is the native asset, not an issued currency, and so are hex
+    /// codes starting with `00`, which is reserved for the three-character format.
+    ///
+    /// # Example
+    /// ```shell
+    /// use xrpl_wasm_stdlib::core::types::currency::Currency;
+    /// use xrpl_wasm_stdlib::currency;
+    ///
+    /// const USD: Currency = currency!("USD");
+    /// const RLUSD: Currency = currency!("524C555344000000000000000000000000000000");
+    /// ```
+    currency
+}
+
+literal_macro! {
+    /// Decodes 48 hex digits at compile time into an `MptId` (an MPTokenIssuanceID).
+    ///
+    /// # Example
+    /// ```shell
+    /// use xrpl_wasm_stdlib::core::types::mpt_id::MptId;
+    /// use xrpl_wasm_stdlib::mpt_id;
+    ///
+    /// const MPT: MptId = mpt_id!("00000001AE123A8556F3CF91154711376AFB0F894F832B3D");
+    /// ```
+    mpt_id
+}
+
+literal_macro! {
+    /// Decodes 64 hex digits at compile time into an `NFToken` ID.
+    ///
+    /// # Example
+    /// ```shell
+    /// use xrpl_wasm_stdlib::core::types::nft::NFToken;
+    /// use xrpl_wasm_stdlib::nft_id;
+    ///
+    /// const NFT: NFToken =
+    ///     nft_id!("000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D65");
+    /// ```
+    nft_id
+}
+
+literal_macro! {
+    /// Decodes 66 hex digits at compile time into a `PublicKey`. The key must start with `ED`
+    /// (ed25519), or `02` or `03` (compressed secp256k1).
+    ///
+    /// # Example
+    /// ```shell
+    /// use xrpl_wasm_stdlib::core::types::public_key::PublicKey;
+    /// use xrpl_wasm_stdlib::public_key;
+    ///
+    /// const NOTARY_KEY: PublicKey =
+    ///     public_key!("ED5F5AC8B98974A3CA843326D9B88CEBD0560177B973EE0B149F782CFAA06DC66A");
+    /// ```
+    public_key
+}
+
+/// Decodes an X-address literal at compile time into `(AccountID, Option<u32>)`.
+///
+/// Both mainnet (`X...`) and testnet (`T...`) addresses are accepted; use
+/// `XAddress::from_x_address` to parse them at runtime or to check the network.
+///
+/// # Example
+/// ```shell
+/// use xrpl_wasm_stdlib::core::types::x_address::XAddress;
+/// use xrpl_wasm_stdlib::x_address;
+///
+/// const DESTINATION: XAddress = x_address!("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC");
+/// // XAddress::new(AccountID(r_address!("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf")), Some(1), false)
+/// ```
+#[proc_macro]
+pub fn x_address(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+    x_address::expand(lit)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
 #[proc_macro]
 pub fn r_address(input: TokenStream) -> TokenStream {
@@ -55,17 +284,28 @@ fn decode_classic_address_to_20bytes(addr: &str) -> Option<Vec<u8>> {
     if !addr.starts_with('r') {
         return None;
     }
+    let payload = decode_base58check(addr)?;
+    // Version byte should be 0x00 for classic AccountID
+    if payload.first() != Some(&0x00) {
+        return None;
+    }
+    // Payload is version (1) + 20 bytes account id
+    if payload.len() != 1 + 20 {
+        return None;
+    }
+    Some(payload[1..].to_vec())
+}
+
+/// Decodes a base58check string with the XRPL alphabet and returns the payload without its
+/// checksum.
+fn decode_base58check(encoded: &str) -> Option<Vec<u8>> {
     let alphabet =
         bs58::Alphabet::new(b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz").ok()?;
-    let full = bs58::decode(addr)
+    let full = bs58::decode(encoded)
         .with_alphabet(&alphabet)
         .into_vec()
         .ok()?;
-    if full.len() < 1 + 20 + 4 {
-        return None;
-    }
-    // Version byte should be 0x00 for classic AccountID
-    if full[0] != 0x00 {
+    if full.len() < 4 {
         return None;
     }
     // Split payload and checksum
@@ -77,9 +317,5 @@ fn decode_classic_address_to_20bytes(addr: &str) -> Option<Vec<u8>> {
     if &second[0..4] != checksum {
         return None;
     }
-    // Payload is version (1) + 20 bytes account id
-    if payload.len() != 1 + 20 {
-        return None;
-    }
-    Some(payload[1..].to_vec())
+    Some(payload.to_vec())
 }
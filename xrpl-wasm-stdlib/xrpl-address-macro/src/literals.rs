@@ -0,0 +1,153 @@
+//! Expansion for the typed literal macros: `hash256!`, `currency!`, `mpt_id!`, `nft_id!` and
+//! `public_key!`.
+//!
+//! Each one validates its string literal and expands to a constant expression of the matching
+//! `xrpl_wasm_stdlib` type, e.g.
+//!
+//! ```text
+//! currency!("USD")
+//!
+//! ::xrpl_wasm_stdlib::core::types::currency::Currency([0, 0, ..., 0x55, 0x53, 0x44, 0, ...])
+//! ```
+//!
+//! Malformed literals are rejected at compile time. Hex must be valid and exactly the type's
+//! length:
+//!
+//! ```compile_fail
+//! const HASH: xrpl_wasm_stdlib::core::types::uint::Hash256 = xrpl_wasm_stdlib::hash256!(
+//!     "6A1F0A2A8DB7B0B4EAA38ADE4ED9E0F1CF17B5E5F7BE8C1C0D20F70F4F8C2E5Z"
+//! );
+//! ```
+//!
+//! ```compile_fail
+//! const HASH: xrpl_wasm_stdlib::core::types::uint::Hash256 = xrpl_wasm_stdlib::hash256!(
+//!     "6A1F0A2A8DB7B0B4EAA38ADE4ED9E0F1CF17B5E5F7BE8C1C0D20F70F4F8C2E"
+//! );
+//! ```
+//!
+//! ```compile_fail
+//! const NFT: xrpl_wasm_stdlib::core::types::nft::NFToken = xrpl_wasm_stdlib::nft_id!(
+//!     "000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000DG5"
+//! );
+//! ```
+//!
+//! ```compile_fail
+//! const NFT: xrpl_wasm_stdlib::core::types::nft::NFToken = xrpl_wasm_stdlib::nft_id!(
+//!     "000B013A95F14B0044F78A264E41713C64B5F89242540EE208C3098E00000D"
+//! );
+//! ```
+//!
+//! ```compile_fail
+//! const MPT: xrpl_wasm_stdlib::core::types::mpt_id::MptId =
+//!     xrpl_wasm_stdlib::mpt_id!("00000001AE123A8556F3CF91154711376AFB0F894F832B3X");
+//! ```
+//!
+//! ```compile_fail
+//! const MPT: xrpl_wasm_stdlib::core::types::mpt_id::MptId =
+//!     xrpl_wasm_stdlib::mpt_id!("00000001AE123A8556F3CF91154711376AFB0F894F832B");
+//! ```
+//!
+//! A public key must start with `ED`, `02` or `03`:
+//!
+//! ```compile_fail
+//! const KEY: xrpl_wasm_stdlib::core::types::public_key::PublicKey = xrpl_wasm_stdlib::public_key!(
+//!     "04A3C1F2D5E6B7C8A9B0C1D2E3F4A5B6C7D8E9F0A1B2C3D4E5F6A7B8C9D0E1F2A3"
+//! );
+//! ```
+//!
+//! A hex currency code must not start with `00`; standard codes are written as three characters:
+//!
+//! ```compile_fail
+//! const USD: xrpl_wasm_stdlib::core::types::currency::Currency =
+//!     xrpl_wasm_stdlib::currency!("0000000000000000000000005553440000000000");
+//! ```
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+/// Characters allowed in a three-character currency code besides ASCII letters and digits.
+const CURRENCY_SYMBOLS: &str = "?!@#$%^&*<>(){}[]|";
+
+pub(crate) fn hash256(lit: LitStr) -> syn::Result<TokenStream> {
+    let bytes = decode_hex::<32>(&lit, "a 256-bit hash")?;
+    Ok(quote! { ::xrpl_wasm_stdlib::core::types::uint::UInt::<32>([#(#bytes),*]) })
+}
+
+pub(crate) fn currency(lit: LitStr) -> syn::Result<TokenStream> {
+    let code = lit.value();
+    let bytes = if code.len() == 3 {
+        if code == "XRP" {
+            return Err(syn::Error::new(
+                lit.span(),
+                "\"XRP\" is not a valid issued currency code; XRP is the native asset",
+            ));
+        }
+        if !code
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || CURRENCY_SYMBOLS.contains(c))
+        {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!(
+                    "invalid currency code {code:?}: use ASCII letters, digits or {CURRENCY_SYMBOLS}"
+                ),
+            ));
+        }
+        let mut bytes = [0u8; 20];
+        bytes[12..15].copy_from_slice(code.as_bytes());
+        bytes
+    } else {
+        let bytes = decode_hex::<20>(&lit, "a three-character currency code or 40 hex digits")?;
+        // A leading zero byte marks the standard format, which only the three-character form
+        // produces canonically (and all zeros is XRP).
+        if bytes[0] == 0 {
+            return Err(syn::Error::new(
+                lit.span(),
+                "a hex currency code must not start with 00; write standard codes as three characters",
+            ));
+        }
+        bytes
+    };
+    Ok(quote! { ::xrpl_wasm_stdlib::core::types::currency::Currency([#(#bytes),*]) })
+}
+
+pub(crate) fn mpt_id(lit: LitStr) -> syn::Result<TokenStream> {
+    let bytes = decode_hex::<24>(&lit, "an MPTokenIssuanceID")?;
+    Ok(quote! { ::xrpl_wasm_stdlib::core::types::mpt_id::MptId::from_bytes([#(#bytes),*]) })
+}
+
+pub(crate) fn nft_id(lit: LitStr) -> syn::Result<TokenStream> {
+    let bytes = decode_hex::<32>(&lit, "an NFTokenID")?;
+    Ok(quote! { ::xrpl_wasm_stdlib::core::types::nft::NFToken([#(#bytes),*]) })
+}
+
+pub(crate) fn public_key(lit: LitStr) -> syn::Result<TokenStream> {
+    let bytes = decode_hex::<33>(&lit, "a public key")?;
+    if !matches!(bytes[0], 0xED | 0x02 | 0x03) {
+        return Err(syn::Error::new(
+            lit.span(),
+            "a public key must start with ED (ed25519), or 02 or 03 (compressed secp256k1)",
+        ));
+    }
+    Ok(quote! { ::xrpl_wasm_stdlib::core::types::public_key::PublicKey([#(#bytes),*]) })
+}
+
+/// Decodes exactly `N` bytes of upper- or lowercase hex.
+fn decode_hex<const N: usize>(lit: &LitStr, what: &str) -> syn::Result<[u8; N]> {
+    let hex = lit.value();
+    let error = |detail: String| syn::Error::new(lit.span(), format!("expected {what}: {detail}"));
+
+    if let Some(c) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(error(format!("{c:?} is not a hex digit")));
+    }
+    if hex.len() != N * 2 {
+        return Err(error(format!("{} hex digits, found {}", N * 2, hex.len())));
+    }
+    let mut bytes = [0u8; N];
+    for (byte, pair) in bytes.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        // Only ASCII hex digits remain, so this can't fail.
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).unwrap(), 16).unwrap();
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,96 @@
+//! Parsing and expansion for the `locator!` macro.
+//!
+//! A path is a `.`-separated list of sfield names, each optionally followed by one or more
+//! `[index]` array indices, e.g. `Memos[0].MemoData`. An optional `slot = N,` prefix packs a
+//! slot number ahead of the path for use with cached ledger objects.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitInt, Token, bracketed};
+
+/// A single packed element of a locator path.
+enum PathElement {
+    Field(Ident),
+    Index(LitInt),
+}
+
+pub(crate) struct LocatorInput {
+    slot: Option<LitInt>,
+    path: Vec<PathElement>,
+}
+
+impl Parse for LocatorInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut slot = None;
+        if input.peek(Ident) && input.peek2(Token![=]) {
+            let key: Ident = input.parse()?;
+            if key != "slot" {
+                return Err(syn::Error::new(key.span(), "expected `slot = <number>,`"));
+            }
+            input.parse::<Token![=]>()?;
+            let slot_lit: LitInt = input.parse()?;
+            slot_lit.base10_parse::<u8>().map_err(|_| {
+                syn::Error::new(slot_lit.span(), "slot number must be between 0 and 255")
+            })?;
+            input.parse::<Token![,]>()?;
+            slot = Some(slot_lit);
+        }
+
+        let mut path = Vec::new();
+        loop {
+            path.push(PathElement::Field(input.parse()?));
+            while input.peek(syn::token::Bracket) {
+                let content;
+                bracketed!(content in input);
+                let index: LitInt = content.parse()?;
+                match index.base10_parse::<i32>() {
+                    Ok(value) if value >= 0 => {}
+                    _ => {
+                        return Err(syn::Error::new(
+                            index.span(),
+                            "array index must be between 0 and i32::MAX",
+                        ));
+                    }
+                }
+                path.push(PathElement::Index(index));
+            }
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![.]>()?;
+        }
+
+        Ok(Self { slot, path })
+    }
+}
+
+pub(crate) fn expand(input: LocatorInput) -> TokenStream {
+    let elements = input.path.iter().map(|element| match element {
+        // Keep the span of the field name so that a misspelled field is reported against the
+        // macro input rather than the expansion.
+        PathElement::Field(ident) => quote! { ::xrpl_wasm_stdlib::sfield::#ident },
+        PathElement::Index(index) => {
+            let value = index.base10_parse::<i32>().unwrap();
+            quote! { #value }
+        }
+    });
+
+    let constructor = match input.slot {
+        Some(slot) => {
+            let slot = slot.base10_parse::<u8>().unwrap();
+            quote! { from_path_with_slot(#slot, &[#(#elements),*]) }
+        }
+        None => quote! { from_path(&[#(#elements),*]) },
+    };
+
+    // Evaluating through a `const` item forces the packing (and the depth check against
+    // `LOCATOR_BUFFER_SIZE`) to happen at compile time.
+    quote! {
+        {
+            const LOCATOR: ::xrpl_wasm_stdlib::core::locator::Locator =
+                ::xrpl_wasm_stdlib::core::locator::Locator::#constructor;
+            LOCATOR
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Expansion for `x_address!`: decodes an X-address literal to an `XAddress`.
+//!
+//! ```text
+//! x_address!("XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC")
+//!
+//! XAddress::new(AccountID([0xaa, 0x06, ...]), Some(1u32), false)
+//! ```
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+const MAINNET_PREFIX: [u8; 2] = [0x05, 0x44];
+const TESTNET_PREFIX: [u8; 2] = [0x04, 0x93];
+
+pub(crate) fn expand(lit: LitStr) -> syn::Result<TokenStream> {
+    let address = lit.value();
+    let (account, tag, is_test) = decode(&address)
+        .ok_or_else(|| syn::Error::new(lit.span(), format!("Invalid X-address: {address}")))?;
+
+    let tag = match tag {
+        Some(tag) => quote! { ::core::option::Option::Some(#tag) },
+        None => quote! { ::core::option::Option::<u32>::None },
+    };
+    Ok(quote! {
+        ::xrpl_wasm_stdlib::core::types::x_address::XAddress::new(
+            ::xrpl_wasm_stdlib::core::types::account_id::AccountID([#(#account),*]),
+            #tag,
+            #is_test,
+        )
+    })
+}
+
+/// Decodes an X-address of either network into its account, tag and testnet flag.
+fn decode(address: &str) -> Option<([u8; 20], Option<u32>, bool)> {
+    let full = crate::decode_base58check(address)?;
+    // Prefix (2) + AccountID (20) + flag (1) + tag (8)
+    if full.len() != 31 {
+        return None;
+    }
+    let is_test = match [full[0], full[1]] {
+        MAINNET_PREFIX => false,
+        TESTNET_PREFIX => true,
+        _ => return None,
+    };
+    let account: [u8; 20] = full[2..22].try_into().ok()?;
+    let tag = u32::from_le_bytes(full[23..27].try_into().ok()?);
+    if full[27..] != [0; 4] {
+        return None;
+    }
+    match (full[22], tag) {
+        (0, 0) => Some((account, None, is_test)),
+        (1, tag) => Some((account, Some(tag), is_test)),
+        // 64-bit tags are reserved and not supported by the XRPL
+        _ => None,
+    }
+}
@@ -0,0 +1,276 @@
+//! Expansion for the `#[xrpl_object(...)]` attribute.
+//!
+//! The attribute is placed on a trait whose methods are field declarations:
+//!
+//! ```text
+//! #[xrpl_object(LedgerEntryType::Escrow)]
+//! pub trait EscrowFields {
+//!     #[field(Destination)]
+//!     fn get_destination(&self) -> Result<AccountID>;
+//!     #[field(FinishAfter)]
+//!     fn get_finish_after(&self) -> Result<Option<u32>>;
+//! }
+//! ```
+//!
+//! `Result<Option<T>>` declares an optional field and anything else a required one. For a ledger
+//! entry type two traits are emitted: the slot-based trait (`EscrowFields: LedgerObjectCommonFields`)
+//! and its current-object twin (`CurrentEscrowFields: CurrentLedgerObjectCommonFields`). For a
+//! transaction type a single trait reading from the current transaction is emitted.
+//!
+//! Methods with a body are copied as-is; mark them `#[current]` or `#[slot]` to keep them in only
+//! one of the two ledger traits.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, GenericArgument, ItemTrait, Path, PathArguments, ReturnType, TraitItem, TraitItemFn,
+    Type,
+};
+
+enum ObjectKind {
+    /// `LedgerEntryType::X`
+    LedgerEntry(Path),
+    /// `TransactionType::X`, or bare `TransactionType` for fields common to all transactions.
+    Transaction(Option<Path>),
+}
+
+/// Which of the generated traits a method belongs to.
+#[derive(Clone, Copy, PartialEq)]
+enum Target {
+    Slot,
+    Current,
+    Transaction,
+}
+
+pub(crate) fn expand(attr: Path, item: ItemTrait) -> syn::Result<TokenStream> {
+    let kind = parse_kind(attr)?;
+    match kind {
+        ObjectKind::LedgerEntry(entry_type) => {
+            if !item.supertraits.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    &item.supertraits,
+                    "ledger object traits get their common-fields supertrait from #[xrpl_object]",
+                ));
+            }
+            let ledger_objects = quote! { ::xrpl_wasm_stdlib::core::ledger_objects };
+            let entry_type = quote! {
+                /// The ledger entry type these fields belong to.
+                const LEDGER_ENTRY_TYPE: ::xrpl_wasm_stdlib::core::types::ledger_entry_type::LedgerEntryType =
+                    ::xrpl_wasm_stdlib::core::types::ledger_entry_type::#entry_type;
+            };
+
+            let mut slot = item.clone();
+            slot.supertraits
+                .push(syn::parse_quote!(#ledger_objects::traits::LedgerObjectCommonFields));
+            let slot = emit_trait(slot, Target::Slot, &entry_type)?;
+
+            let mut current = item;
+            current.ident = format_ident!("Current{}", current.ident);
+            current.supertraits.push(syn::parse_quote!(
+                #ledger_objects::traits::CurrentLedgerObjectCommonFields
+            ));
+            let current = emit_trait(current, Target::Current, &entry_type)?;
+
+            Ok(quote! { #slot #current })
+        }
+        ObjectKind::Transaction(transaction_type) => {
+            let transaction_type = match transaction_type {
+                Some(transaction_type) => quote! {
+                    /// The transaction type these fields belong to.
+                    const TRANSACTION_TYPE: ::xrpl_wasm_stdlib::core::types::transaction_type::TransactionType =
+                        ::xrpl_wasm_stdlib::core::types::transaction_type::#transaction_type;
+                },
+                None => TokenStream::new(),
+            };
+            emit_trait(item, Target::Transaction, &transaction_type)
+        }
+    }
+}
+
+fn parse_kind(attr: Path) -> syn::Result<ObjectKind> {
+    let segments: Vec<_> = attr.segments.iter().map(|s| s.ident.to_string()).collect();
+    match segments
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["LedgerEntryType", _] => Ok(ObjectKind::LedgerEntry(attr)),
+        ["TransactionType", _] => Ok(ObjectKind::Transaction(Some(attr))),
+        ["TransactionType"] => Ok(ObjectKind::Transaction(None)),
+        _ => Err(syn::Error::new_spanned(
+            attr,
+            "expected `LedgerEntryType::<Type>`, `TransactionType::<Type>` or `TransactionType`",
+        )),
+    }
+}
+
+fn emit_trait(
+    mut item: ItemTrait,
+    target: Target,
+    associated_const: &TokenStream,
+) -> syn::Result<TokenStream> {
+    let mut items = Vec::with_capacity(item.items.len());
+    for trait_item in item.items {
+        match trait_item {
+            TraitItem::Fn(method) => {
+                if let Some(method) = emit_method(method, target)? {
+                    items.push(TraitItem::Fn(method));
+                }
+            }
+            other => items.push(other),
+        }
+    }
+    item.items = items;
+
+    let ItemTrait {
+        attrs,
+        vis,
+        unsafety,
+        trait_token,
+        ident,
+        generics,
+        colon_token,
+        supertraits,
+        items,
+        ..
+    } = item;
+    let colon_token = colon_token.or_else(|| (!supertraits.is_empty()).then(Default::default));
+    Ok(quote! {
+        #(#attrs)*
+        #vis #unsafety #trait_token #ident #generics #colon_token #supertraits {
+            #associated_const
+            #(#items)*
+        }
+    })
+}
+
+fn emit_method(mut method: TraitItemFn, target: Target) -> syn::Result<Option<TraitItemFn>> {
+    let field = take_field_attr(&mut method.attrs)?;
+    let only = take_target_attr(&mut method.attrs)?;
+
+    if let Some(only) = only {
+        if target == Target::Transaction {
+            return Err(syn::Error::new_spanned(
+                &method.sig.ident,
+                "#[current] and #[slot] only apply to ledger object traits",
+            ));
+        }
+        if only != target {
+            return Ok(None);
+        }
+    }
+
+    match (field, &method.default) {
+        (Some(field), None) => {
+            let getter = field_getter(target, returns_option(&method.sig.output), &field);
+            method.default = Some(syn::parse_quote!({ #getter }));
+            Ok(Some(method))
+        }
+        (Some(_), Some(_)) => Err(syn::Error::new_spanned(
+            &method.sig.ident,
+            "a #[field] declaration must not have a body",
+        )),
+        (None, Some(_)) => Ok(Some(method)),
+        (None, None) => Err(syn::Error::new_spanned(
+            &method.sig.ident,
+            "expected #[field(Name)] or a default body",
+        )),
+    }
+}
+
+fn field_getter(target: Target, optional: bool, field: &Path) -> TokenStream {
+    let field = if field.get_ident().is_some() {
+        quote! { ::xrpl_wasm_stdlib::sfield::#field }
+    } else {
+        quote! { #field }
+    };
+    let getter = if optional {
+        quote! { get_field_optional }
+    } else {
+        quote! { get_field }
+    };
+    match target {
+        Target::Slot => quote! {
+            ::xrpl_wasm_stdlib::core::ledger_objects::ledger_object::#getter(self.get_slot_num(), #field)
+        },
+        Target::Current => quote! {
+            ::xrpl_wasm_stdlib::core::ledger_objects::current_ledger_object::#getter(#field)
+        },
+        Target::Transaction => quote! {
+            ::xrpl_wasm_stdlib::core::current_tx::#getter(#field)
+        },
+    }
+}
+
+/// Removes `#[field(Name)]` from a method, returning the field path.
+fn take_field_attr(attrs: &mut Vec<Attribute>) -> syn::Result<Option<Path>> {
+    let mut field = None;
+    let mut result = Ok(());
+    attrs.retain(|attr| {
+        if !attr.path().is_ident("field") {
+            return true;
+        }
+        match attr.parse_args::<Path>() {
+            Ok(path) if field.is_none() => field = Some(path),
+            Ok(_) => result = Err(syn::Error::new_spanned(attr, "duplicate #[field]")),
+            Err(e) => result = Err(e),
+        }
+        false
+    });
+    result.map(|()| field)
+}
+
+/// Removes `#[current]` / `#[slot]` from a method.
+fn take_target_attr(attrs: &mut Vec<Attribute>) -> syn::Result<Option<Target>> {
+    let mut target = None;
+    let mut result = Ok(());
+    attrs.retain(|attr| {
+        let this = if attr.path().is_ident("current") {
+            Target::Current
+        } else if attr.path().is_ident("slot") {
+            Target::Slot
+        } else {
+            return true;
+        };
+        if let Err(e) = attr.meta.require_path_only() {
+            result = Err(e);
+        } else if target.is_some() {
+            result = Err(syn::Error::new_spanned(
+                attr,
+                "use only one of #[current] or #[slot]",
+            ));
+        }
+        target = Some(this);
+        false
+    });
+    result.map(|()| target)
+}
+
+/// Returns true for a return type of the form `Result<Option<T>>`.
+fn returns_option(output: &ReturnType) -> bool {
+    let ReturnType::Type(_, ty) = output else {
+        return false;
+    };
+    let Some(inner) = last_generic_arg(ty, "Result") else {
+        return false;
+    };
+    last_generic_arg(inner, "Option").is_some()
+}
+
+fn last_generic_arg<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}